@@ -0,0 +1,635 @@
+//! A node arena shared by many doubly linked lists.
+//!
+//! Every [`List`] owns its own storage; hence, moving an element from one list to another requires
+//! removing it from the first and pushing it to the second, which creates a new node with a new index.
+//!
+//! [`ListArena`] instead owns a single storage of nodes and hands out lightweight [`ArenaList`] handles,
+//! each of which is nothing but the front and back of a doubly linked list living in the arena.
+//! Since all lists share the same nodes, an element can be transferred from one list to another
+//! in constant time by relinking its node, and its [`ArenaIdx`] remains valid.
+//! This is convenient when keeping many small lists, such as buckets or per-priority queues.
+//!
+//! Similar to [`DoublyListLazy`], the arena never reorganizes its nodes implicitly;
+//! the indices remain valid until their elements are removed, or until the closed nodes are explicitly
+//! reclaimed by [`ListArena::reclaim_closed_nodes`].
+//!
+//! [`List`]: crate::List
+//! [`DoublyListLazy`]: crate::DoublyListLazy
+//!
+//! # Examples
+//!
+//! ```rust
+//! use orx_linked_list::arena::ListArena;
+//!
+//! let mut arena = ListArena::new();
+//! let high = arena.new_list();
+//! let low = arena.new_list();
+//!
+//! let a = arena.push_back(high, 'a');
+//! arena.push_back(high, 'b');
+//! arena.push_back(low, 'c');
+//! assert!(arena.iter(high).eq(&['a', 'b']));
+//! assert!(arena.iter(low).eq(&['c']));
+//!
+//! // O(1) transfer keeping the index
+//! arena.move_to_front(a, low);
+//! assert!(arena.iter(high).eq(&['b']));
+//! assert!(arena.iter(low).eq(&['a', 'c']));
+//! assert_eq!(arena.list_of(a), Some(low));
+//! assert_eq!(arena.get(a), Some(&'a'));
+//!
+//! assert_eq!(arena.pop_back(low), Some('c'));
+//! assert_eq!(arena.remove(a), 'a');
+//! assert!(arena.is_list_empty(low));
+//! assert_eq!(arena.len(), 1);
+//! ```
+
+use crate::{
+    Doubly,
+    iter::{DoublyIter, DoublyIterMut, DoublyIterPtr},
+    memory::{DoublyReclaimer, node_ptr_at_pos},
+    type_aliases::{BACK_IDX, DefaultPinVec, FRONT_IDX, IDX_ERR},
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::Debug;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{
+    MemoryReclaimNever, MemoryState, NodeIdx, NodeIdxError, NodePtr, Refs, SelfRefCol, Utilization,
+    Variant,
+};
+
+/// Element of the arena together with the position of the list it belongs to.
+struct Entry<T> {
+    list: usize,
+    value: T,
+}
+
+type V<T> = Doubly<Entry<T>>;
+
+const LIST_ERR: &str = "list does not belong to the arena";
+
+/// Handle of a doubly linked list living in a [`ListArena`].
+///
+/// The handle belongs to the arena that created it, and methods of any other arena panic when called with it.
+/// Since lists are never removed from the arena, the handle remains valid as long as the arena lives,
+/// including after [`ListArena::clear`].
+/// The arena is identified by an address it owns; therefore, a handle that outlives its arena might be
+/// accepted by a new arena created at the same address, which is not detected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ArenaList {
+    arena: usize,
+    position: usize,
+}
+
+/// Index of an element in a [`ListArena`].
+///
+/// The index remains valid while the element is transferred between the lists of the arena.
+/// It is invalidated when the element is removed, or when the memory state of the arena changes
+/// due to an explicit [`ListArena::reclaim_closed_nodes`] call.
+pub struct ArenaIdx<T>(NodeIdx<V<T>>);
+
+impl<T> Clone for ArenaIdx<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ArenaIdx<T> {}
+
+impl<T> PartialEq for ArenaIdx<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for ArenaIdx<T> {}
+
+impl<T> core::hash::Hash for ArenaIdx<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T> Debug for ArenaIdx<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ArenaIdx").field(&self.0).finish()
+    }
+}
+
+/// Ends and length of a list living in the arena.
+struct ListEnds<T> {
+    ends: <V<T> as Variant>::Ends,
+    len: usize,
+}
+
+impl<T> ListEnds<T> {
+    fn new() -> Self {
+        Self {
+            ends: Refs::empty(),
+            len: 0,
+        }
+    }
+}
+
+/// A single storage of nodes shared by many doubly linked lists, allowing constant time transfer
+/// of elements between the lists without changing their indices.
+///
+/// See the [module documentation](crate::arena) for details.
+pub struct ListArena<T> {
+    col: SelfRefCol<V<T>, MemoryReclaimNever, DefaultPinVec<V<T>>>,
+    lists: Vec<ListEnds<T>>,
+    /// Allocation owned by the arena during its entire lifetime, whose address identifies the arena in its list handles.
+    id: Box<u8>,
+}
+
+impl<T> Default for ListArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ListArena<T> {
+    /// Creates an empty arena without any lists.
+    pub fn new() -> Self {
+        Self {
+            col: SelfRefCol::new(),
+            lists: Vec::new(),
+            id: Box::new(0),
+        }
+    }
+
+    /// Creates a new empty list in the arena and returns its handle.
+    pub fn new_list(&mut self) -> ArenaList {
+        self.lists.push(ListEnds::new());
+        self.handle(self.lists.len() - 1)
+    }
+
+    /// Returns the number of lists created in the arena.
+    pub fn num_lists(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// Returns an iterator of the handles of all lists in the arena.
+    pub fn lists(&self) -> impl ExactSizeIterator<Item = ArenaList> {
+        (0..self.lists.len()).map(|position| self.handle(position))
+    }
+
+    /// ***O(1)*** Returns the total number of elements in all lists of the arena.
+    pub fn len(&self) -> usize {
+        self.col.len()
+    }
+
+    /// Returns true if none of the lists of the arena has any element.
+    pub fn is_empty(&self) -> bool {
+        self.col.is_empty()
+    }
+
+    /// ***O(1)*** Returns the number of elements in the `list`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn list_len(&self, list: ArenaList) -> usize {
+        self.list(list).len
+    }
+
+    /// Returns true if the `list` is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn is_list_empty(&self, list: ArenaList) -> bool {
+        self.list_len(list) == 0
+    }
+
+    /// Returns the memory state of the arena; indices obtained in a different state are invalid.
+    pub fn memory_state(&self) -> MemoryState {
+        self.col.memory_state()
+    }
+
+    /// Returns the node utilization of the underlying storage shared by all lists.
+    pub fn node_utilization(&self) -> Utilization {
+        self.col.utilization()
+    }
+
+    // get
+
+    /// Returns a reference to the element at the `idx`; None if the index is invalid.
+    pub fn get(&self, idx: ArenaIdx<T>) -> Option<&T> {
+        self.col
+            .node_from_idx(idx.0)
+            .and_then(|n| n.data())
+            .map(|x| &x.value)
+    }
+
+    /// Returns a mutable reference to the element at the `idx`; None if the index is invalid.
+    pub fn get_mut(&mut self, idx: ArenaIdx<T>) -> Option<&mut T> {
+        self.col
+            .node_mut_from_idx(idx.0)
+            .and_then(|n| n.data_mut())
+            .map(|x| &mut x.value)
+    }
+
+    /// Returns the handle of the list that the element at the `idx` currently belongs to;
+    /// None if the index is invalid.
+    pub fn list_of(&self, idx: ArenaIdx<T>) -> Option<ArenaList> {
+        self.col
+            .node_from_idx(idx.0)
+            .and_then(|n| n.data())
+            .map(|x| self.handle(x.list))
+    }
+
+    /// Returns a reference to the front element of the `list`; None if it is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn front(&self, list: ArenaList) -> Option<&T> {
+        self.end(list, FRONT_IDX).map(|p| self.value(p))
+    }
+
+    /// Returns a reference to the back element of the `list`; None if it is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn back(&self, list: ArenaList) -> Option<&T> {
+        self.end(list, BACK_IDX).map(|p| self.value(p))
+    }
+
+    /// Returns the index of the front element of the `list`; None if it is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn idx_of_front(&self, list: ArenaList) -> Option<ArenaIdx<T>> {
+        self.end(list, FRONT_IDX).map(|p| self.idx(p))
+    }
+
+    /// Returns the index of the back element of the `list`; None if it is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn idx_of_back(&self, list: ArenaList) -> Option<ArenaIdx<T>> {
+        self.end(list, BACK_IDX).map(|p| self.idx(p))
+    }
+
+    /// Returns a double-ended iterator of the elements of the `list` from front to back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn iter(&self, list: ArenaList) -> impl DoubleEndedIterator<Item = &T> {
+        let [front, back] = [self.end(list, FRONT_IDX), self.end(list, BACK_IDX)];
+        DoublyIter::new(&self.col, front, back).map(|x| &x.value)
+    }
+
+    /// Returns a double-ended iterator of mutable references to the elements of the `list` from front to back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn iter_mut(&mut self, list: ArenaList) -> impl DoubleEndedIterator<Item = &mut T> {
+        let [front, back] = [self.end(list, FRONT_IDX), self.end(list, BACK_IDX)];
+        DoublyIterMut::new(&mut self.col, front, back).map(|x| &mut x.value)
+    }
+
+    /// Returns a double-ended iterator of the indices of the elements of the `list` from front to back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn indices(&self, list: ArenaList) -> impl DoubleEndedIterator<Item = ArenaIdx<T>> {
+        let [front, back] = [self.end(list, FRONT_IDX), self.end(list, BACK_IDX)];
+        let state = self.col.memory_state();
+        DoublyIterPtr::new(&self.col, front, back).map(move |p| ArenaIdx(NodeIdx::new(state, p)))
+    }
+
+    // mut
+
+    /// ***O(1)*** Pushes the `value` to the front of the `list` and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn push_front(&mut self, list: ArenaList, value: T) -> ArenaIdx<T> {
+        let front = self.end(list, FRONT_IDX);
+        let ptr = self.col.push(Entry {
+            list: list.position,
+            value,
+        });
+        self.link_between(list, None, ptr, front);
+        self.idx(ptr)
+    }
+
+    /// ***O(1)*** Pushes the `value` to the back of the `list` and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn push_back(&mut self, list: ArenaList, value: T) -> ArenaIdx<T> {
+        let back = self.end(list, BACK_IDX);
+        let ptr = self.col.push(Entry {
+            list: list.position,
+            value,
+        });
+        self.link_between(list, back, ptr, None);
+        self.idx(ptr)
+    }
+
+    /// ***O(1)*** Removes and returns the front element of the `list`; None if it is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn pop_front(&mut self, list: ArenaList) -> Option<T> {
+        self.end(list, FRONT_IDX).map(|p| self.remove_ptr(p))
+    }
+
+    /// ***O(1)*** Removes and returns the back element of the `list`; None if it is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn pop_back(&mut self, list: ArenaList) -> Option<T> {
+        self.end(list, BACK_IDX).map(|p| self.remove_ptr(p))
+    }
+
+    /// ***O(1)*** Removes the element at the `idx` from the list it belongs to, and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx` is invalid.
+    pub fn remove(&mut self, idx: ArenaIdx<T>) -> T {
+        let ptr = self.col.try_get_ptr(idx.0).expect(IDX_ERR);
+        self.remove_ptr(ptr)
+    }
+
+    /// ***O(1)*** Removes the element at the `idx` from the list it belongs to, and returns it;
+    /// returns the error if the `idx` is invalid.
+    pub fn try_remove(&mut self, idx: ArenaIdx<T>) -> Result<T, NodeIdxError> {
+        let ptr = self.col.try_get_ptr(idx.0)?;
+        Ok(self.remove_ptr(ptr))
+    }
+
+    /// ***O(1)*** Transfers the element at the `idx` to the front of the `list`, which might be the list
+    /// it currently belongs to. The index of the element remains valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx` is invalid, or if the `list` does not belong to this arena.
+    pub fn move_to_front(&mut self, idx: ArenaIdx<T>, list: ArenaList) {
+        let ptr = self.col.try_get_ptr(idx.0).expect(IDX_ERR);
+        if self.end(list, FRONT_IDX) != Some(ptr) {
+            self.unlink(ptr);
+            self.link_front(list, ptr);
+        }
+    }
+
+    /// ***O(1)*** Transfers the element at the `idx` to the back of the `list`, which might be the list
+    /// it currently belongs to. The index of the element remains valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx` is invalid, or if the `list` does not belong to this arena.
+    pub fn move_to_back(&mut self, idx: ArenaIdx<T>, list: ArenaList) {
+        let ptr = self.col.try_get_ptr(idx.0).expect(IDX_ERR);
+        if self.end(list, BACK_IDX) != Some(ptr) {
+            self.unlink(ptr);
+            self.link_back(list, ptr);
+        }
+    }
+
+    /// ***O(1)*** Transfers the element at the `idx` to the position immediately after the element
+    /// at the `idx_anchor`, in the list that the anchor belongs to. The index of the element remains valid.
+    ///
+    /// Does nothing if both indices are the same.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the indices is invalid.
+    pub fn move_next_to(&mut self, idx: ArenaIdx<T>, idx_anchor: ArenaIdx<T>) {
+        let ptr = self.col.try_get_ptr(idx.0).expect(IDX_ERR);
+        let anchor = self.col.try_get_ptr(idx_anchor.0).expect(IDX_ERR);
+        if ptr != anchor {
+            self.unlink(ptr);
+            let next = self.col.node(anchor).next().get();
+            self.link_between(self.list_of_ptr(anchor), Some(anchor), ptr, next);
+        }
+    }
+
+    /// ***O(1)*** Transfers the element at the `idx` to the position immediately before the element
+    /// at the `idx_anchor`, in the list that the anchor belongs to. The index of the element remains valid.
+    ///
+    /// Does nothing if both indices are the same.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the indices is invalid.
+    pub fn move_prev_to(&mut self, idx: ArenaIdx<T>, idx_anchor: ArenaIdx<T>) {
+        let ptr = self.col.try_get_ptr(idx.0).expect(IDX_ERR);
+        let anchor = self.col.try_get_ptr(idx_anchor.0).expect(IDX_ERR);
+        if ptr != anchor {
+            self.unlink(ptr);
+            let prev = self.col.node(anchor).prev().get();
+            self.link_between(self.list_of_ptr(anchor), prev, ptr, Some(anchor));
+        }
+    }
+
+    /// ***O(n)*** Transfers all elements of the `from` list to the back of the `to` list, preserving their order,
+    /// where n is the length of the `from` list. Indices of the elements remain valid.
+    ///
+    /// Does nothing if both handles are the same.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the lists does not belong to this arena.
+    pub fn append_back(&mut self, to: ArenaList, from: ArenaList) {
+        let old_back = self.end(to, BACK_IDX);
+        if to == from {
+            return;
+        }
+
+        let mut current = self.end(from, FRONT_IDX);
+        while let Some(ptr) = current {
+            current = self.col.node(ptr).next().get();
+            self.entry_mut(ptr).list = to.position;
+        }
+
+        let source = core::mem::replace(self.list_mut(from), ListEnds::new());
+        if let (Some(front), Some(back)) = (source.ends.get(FRONT_IDX), source.ends.get(BACK_IDX)) {
+            let target = self.list_mut(to);
+            target.ends.set_some(BACK_IDX, back);
+            target.len += source.len;
+            match old_back {
+                Some(old_back) => {
+                    self.col.node_mut(old_back).next_mut().set_some(front);
+                    self.col.node_mut(front).prev_mut().set_some(old_back);
+                }
+                None => self.list_mut(to).ends.set_some(FRONT_IDX, front),
+            }
+        }
+    }
+
+    /// Removes all elements of the `list`; the list itself remains in the arena as an empty list.
+    ///
+    /// Memory of the removed elements is not reclaimed until [`reclaim_closed_nodes`] is called.
+    ///
+    /// [`reclaim_closed_nodes`]: crate::arena::ListArena::reclaim_closed_nodes
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `list` does not belong to this arena.
+    pub fn clear_list(&mut self, list: ArenaList) {
+        while self.pop_front(list).is_some() {}
+    }
+
+    /// Removes all elements of all lists; the lists themselves remain in the arena as empty lists,
+    /// and their handles remain valid.
+    pub fn clear(&mut self) {
+        self.col.clear();
+        for list in self.lists.iter_mut() {
+            *list = ListEnds::new();
+        }
+    }
+
+    /// Manually reclaims the closed nodes of removed elements, and returns the memory states of the
+    /// arena before and after the reclaim.
+    ///
+    /// The lists and their handles are not affected; however, memory reclaim might lead to reorganization
+    /// of the nodes which invalidates the indices obtained before the process.
+    pub fn reclaim_closed_nodes(&mut self) -> (MemoryState, MemoryState) {
+        let num_active_nodes = self.col.len();
+        let old = self.col.memory_state();
+
+        let state_changed = DoublyReclaimer::reclaim(&mut self.col);
+        self.col.nodes_mut().truncate(num_active_nodes);
+        self.col.update_state(state_changed);
+
+        for list in self.lists.iter_mut() {
+            list.ends.clear();
+        }
+        for position in 0..num_active_nodes {
+            let ptr = node_ptr_at_pos(&self.col, position);
+            let node = self.col.node(ptr);
+            let [is_front, is_back] = [node.prev().get().is_none(), node.next().get().is_none()];
+            let list = self.list_mut(self.list_of_ptr(ptr));
+            if is_front {
+                list.ends.set_some(FRONT_IDX, ptr);
+            }
+            if is_back {
+                list.ends.set_some(BACK_IDX, ptr);
+            }
+        }
+
+        (old, self.col.memory_state())
+    }
+
+    // helpers
+
+    /// Returns the ends and length of the `list`; all accesses to the lists go through this method or
+    /// [`Self::list_mut`], which panic if the `list` does not belong to this arena.
+    fn list(&self, list: ArenaList) -> &ListEnds<T> {
+        assert_eq!(list.arena, self.arena_id(), "{}", LIST_ERR);
+        self.lists.get(list.position).expect(LIST_ERR)
+    }
+
+    fn list_mut(&mut self, list: ArenaList) -> &mut ListEnds<T> {
+        assert_eq!(list.arena, self.arena_id(), "{}", LIST_ERR);
+        self.lists.get_mut(list.position).expect(LIST_ERR)
+    }
+
+    fn arena_id(&self) -> usize {
+        &*self.id as *const u8 as usize
+    }
+
+    fn handle(&self, position: usize) -> ArenaList {
+        ArenaList {
+            arena: self.arena_id(),
+            position,
+        }
+    }
+
+    fn end(&self, list: ArenaList, end: usize) -> Option<NodePtr<V<T>>> {
+        self.list(list).ends.get(end)
+    }
+
+    fn idx(&self, ptr: NodePtr<V<T>>) -> ArenaIdx<T> {
+        ArenaIdx(NodeIdx::new(self.col.memory_state(), ptr))
+    }
+
+    fn value(&self, ptr: NodePtr<V<T>>) -> &T {
+        &self.col.node(ptr).data().expect("active node").value
+    }
+
+    fn list_of_ptr(&self, ptr: NodePtr<V<T>>) -> ArenaList {
+        self.handle(self.col.node(ptr).data().expect("active node").list)
+    }
+
+    fn entry_mut(&mut self, ptr: NodePtr<V<T>>) -> &mut Entry<T> {
+        self.col.node_mut(ptr).data_mut().expect("active node")
+    }
+
+    fn remove_ptr(&mut self, ptr: NodePtr<V<T>>) -> T {
+        self.unlink(ptr);
+        self.col.close_and_reclaim(ptr).value
+    }
+
+    /// Detaches the node from its list, leaving its own links dangling.
+    fn unlink(&mut self, ptr: NodePtr<V<T>>) {
+        let list = self.list_of_ptr(ptr);
+        let [prev, next] = {
+            let node = self.col.node(ptr);
+            [node.prev().get(), node.next().get()]
+        };
+
+        match prev {
+            Some(prev) => self.col.node_mut(prev).next_mut().set(next),
+            None => self.list_mut(list).ends.set(FRONT_IDX, next),
+        }
+
+        match next {
+            Some(next) => self.col.node_mut(next).prev_mut().set(prev),
+            None => self.list_mut(list).ends.set(BACK_IDX, prev),
+        }
+
+        self.list_mut(list).len -= 1;
+    }
+
+    fn link_front(&mut self, list: ArenaList, ptr: NodePtr<V<T>>) {
+        let front = self.end(list, FRONT_IDX);
+        self.link_between(list, None, ptr, front);
+    }
+
+    fn link_back(&mut self, list: ArenaList, ptr: NodePtr<V<T>>) {
+        let back = self.end(list, BACK_IDX);
+        self.link_between(list, back, ptr, None);
+    }
+
+    /// Links the detached node between `prev` and `next`, which must be consecutive nodes of the `list`,
+    /// or its ends when None.
+    fn link_between(
+        &mut self,
+        list: ArenaList,
+        prev: Option<NodePtr<V<T>>>,
+        ptr: NodePtr<V<T>>,
+        next: Option<NodePtr<V<T>>>,
+    ) {
+        self.entry_mut(ptr).list = list.position;
+        self.col.node_mut(ptr).prev_mut().set(prev);
+        self.col.node_mut(ptr).next_mut().set(next);
+
+        match prev {
+            Some(prev) => self.col.node_mut(prev).next_mut().set_some(ptr),
+            None => self.list_mut(list).ends.set_some(FRONT_IDX, ptr),
+        }
+
+        match next {
+            Some(next) => self.col.node_mut(next).prev_mut().set_some(ptr),
+            None => self.list_mut(list).ends.set_some(BACK_IDX, ptr),
+        }
+
+        self.list_mut(list).len += 1;
+    }
+}
@@ -0,0 +1,263 @@
+//! A least-recently-used cache built on the node indices of a doubly linked list.
+//!
+//! The cache stores its entries in a [`DoublyListLazy`] ordered by recency, the most recently used
+//! entry being at the front, and keeps a map from keys to the [`DoublyIdx`] of the entries.
+//! Accessing an entry is then a hash map lookup followed by a constant time `move_to_front`;
+//! and evicting the least recently used entry is a constant time operation on the back of the list.
+//!
+//! When the cache is full, the node of the evicted entry is reused for the new entry; hence, a cache
+//! which only receives `get` and `put` calls never leaves a gap in its storage. The gaps are created by
+//! `remove`, `pop_lru` and shrinking `resize` calls; and they are compacted once they outnumber the entries.
+//! The indices are internal to the cache, which keeps its map in sync with the compacted storage.
+//!
+//! The module is available with the `std` feature, using `std::collections::HashMap`;
+//! or with the `hashbrown` feature, using `hashbrown::HashMap`, which also works in `no_std` environments.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use orx_linked_list::cache::LruCache;
+//!
+//! let mut cache = LruCache::new(2);
+//!
+//! cache.put("a", 1);
+//! cache.put("b", 2);
+//! assert_eq!(cache.get(&"a"), Some(&1)); // a becomes the most recently used
+//!
+//! cache.put("c", 3); // evicts b
+//! assert_eq!(cache.peek(&"b"), None);
+//!
+//! let entries: Vec<_> = cache.iter().collect();
+//! assert_eq!(entries, [(&"c", &3), (&"a", &1)]);
+//!
+//! assert_eq!(cache.pop_lru(), Some(("a", 1)));
+//! assert_eq!(cache.len(), 1);
+//! ```
+
+use crate::{DoublyEnds, DoublyEndsMut, DoublyIdx, DoublyIterable, DoublyListLazy};
+use core::{
+    borrow::Borrow,
+    hash::{BuildHasher, Hash},
+};
+#[cfg(feature = "hashbrown")]
+use hashbrown::{DefaultHashBuilder as RandomState, HashMap};
+#[cfg(not(feature = "hashbrown"))]
+use std::collections::{HashMap, hash_map::RandomState};
+
+/// A least-recently-used cache with a fixed capacity.
+///
+/// Entries are kept in a doubly linked list in the order of recency, and located by their
+/// [`DoublyIdx`] stored in a hash map.
+/// See the [module documentation](crate::cache) for details.
+pub struct LruCache<K, V, S = RandomState> {
+    list: DoublyListLazy<(K, V)>,
+    map: HashMap<K, DoublyIdx<(K, V)>, S>,
+    capacity: usize,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    /// Creates an empty cache which can hold at most `capacity` entries.
+    ///
+    /// A cache with zero capacity never holds any entry.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_hasher(capacity, RandomState::default())
+    }
+}
+
+impl<K, V, S> LruCache<K, V, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    /// Creates an empty cache which can hold at most `capacity` entries, and uses the given
+    /// `hash_builder` to hash the keys.
+    pub fn with_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self {
+            list: DoublyListLazy::new(),
+            map: HashMap::with_hasher(hash_builder),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of entries that the cache can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the cache does not contain any entry.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns true if the cache contains an entry for the `key`, without updating its recency.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// ***O(1)*** Returns a reference to the value of the `key` and marks the entry as the most recently used;
+    /// returns None if the cache does not contain the `key`.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = *self.map.get(key)?;
+        self.list.move_to_front(idx);
+        self.list.get(idx).map(|(_, v)| v)
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the value of the `key` and marks the entry as the most
+    /// recently used; returns None if the cache does not contain the `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = *self.map.get(key)?;
+        self.list.move_to_front(idx);
+        self.list.get_mut(idx).map(|(_, v)| v)
+    }
+
+    /// ***O(1)*** Returns a reference to the value of the `key` without updating its recency;
+    /// returns None if the cache does not contain the `key`.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = *self.map.get(key)?;
+        self.list.get(idx).map(|(_, v)| v)
+    }
+
+    /// ***O(1)*** Returns the least recently used entry without updating its recency;
+    /// returns None if the cache is empty.
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.list.back().map(|(k, v)| (k, v))
+    }
+
+    /// ***O(1)*** Inserts the `value` for the `key` as the most recently used entry.
+    ///
+    /// * If the cache already contains the `key`, its value is replaced and the old value is returned.
+    /// * Otherwise, None is returned; and if the cache is full, the least recently used entry is evicted.
+    ///   The node of the evicted entry is reused for the new entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::cache::LruCache;
+    ///
+    /// let mut cache = LruCache::new(2);
+    ///
+    /// assert_eq!(cache.put('a', 1), None);
+    /// assert_eq!(cache.put('b', 2), None);
+    /// assert_eq!(cache.put('a', 10), Some(1));
+    ///
+    /// assert_eq!(cache.put('c', 3), None); // evicts b
+    /// assert!(!cache.contains(&'b'));
+    /// assert_eq!(cache.peek(&'a'), Some(&10));
+    /// ```
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&idx) = self.map.get(&key) {
+            self.list.move_to_front(idx);
+            return self
+                .list
+                .get_mut(idx)
+                .map(|entry| core::mem::replace(&mut entry.1, value));
+        }
+
+        match (self.capacity, self.list.len() < self.capacity) {
+            (0, _) => {}
+            (_, true) => {
+                let idx = self.list.push_front((key.clone(), value));
+                self.map.insert(key, idx);
+            }
+            (_, false) => {
+                if let Some(lru) = self.list.back_mut() {
+                    let (lru_key, _) = core::mem::replace(lru, (key.clone(), value));
+                    if let Some(idx) = self.map.remove(&lru_key) {
+                        self.list.move_to_front(idx);
+                        self.map.insert(key, idx);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// ***O(1)*** Removes and returns the entry of the `key`; returns None if the cache does not contain the `key`.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = self.map.remove(key)?;
+        let (_, value) = self.list.remove(idx);
+        self.reclaim_if_sparse();
+        Some(value)
+    }
+
+    /// ***O(1)*** Removes and returns the least recently used entry; returns None if the cache is empty.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.list.pop_back()?;
+        self.map.remove(&key);
+        self.reclaim_if_sparse();
+        Some((key, value))
+    }
+
+    /// Sets the capacity of the cache to `capacity`, evicting the least recently used entries
+    /// if the cache holds more entries than the new capacity.
+    pub fn resize(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.list.len() > capacity {
+            if let Some((key, _)) = self.list.pop_back() {
+                self.map.remove(&key);
+            }
+        }
+        self.reclaim_if_sparse();
+    }
+
+    /// Removes all entries of the cache.
+    pub fn clear(&mut self) {
+        self.list.clear();
+        self.map.clear();
+    }
+
+    /// Returns an iterator over the entries of the cache in the order of recency,
+    /// starting from the most recently used entry.
+    ///
+    /// The iterator is double-ended; hence, `iter().rev()` yields the entries starting from the least recently used.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> {
+        self.list.iter().map(|(k, v)| (k, v))
+    }
+
+    /// Returns an iterator over the keys of the cache in the order of recency,
+    /// starting from the most recently used entry.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> {
+        self.list.iter().map(|(k, _)| k)
+    }
+
+    /// Reclaims the closed nodes left behind by removed entries once they outnumber the entries,
+    /// and remaps the indices in the map to the new positions of the entries.
+    fn reclaim_if_sparse(&mut self) {
+        if let Some(idx_map) = self.list.reclaim_if_sparse() {
+            for idx in self.map.values_mut() {
+                if let Some(new_idx) = idx_map.get(*idx) {
+                    *idx = new_idx;
+                }
+            }
+        }
+    }
+}
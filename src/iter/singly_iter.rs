@@ -15,8 +15,12 @@ impl<'a, T, P> SinglyIter<'a, T, P>
 where
     P: PinnedVec<Node<Singly<T>>>,
 {
-    pub(crate) fn new(col: &'a CoreCol<Singly<T>, P>, current: Option<NodePtr<Singly<T>>>) -> Self {
-        Self(SinglyIterPtr::new(col, current))
+    pub(crate) fn new(
+        col: &'a CoreCol<Singly<T>, P>,
+        current: Option<NodePtr<Singly<T>>>,
+        last: Option<NodePtr<Singly<T>>>,
+    ) -> Self {
+        Self(SinglyIterPtr::new(col, current, last))
     }
 }
 
@@ -12,6 +12,7 @@ where
 {
     pub(crate) col: &'a mut CoreCol<Singly<T>, P>,
     current: Option<NodePtr<Singly<T>>>,
+    last: Option<NodePtr<Singly<T>>>,
 }
 
 impl<'a, T, P> SinglyIterMut<'a, T, P>
@@ -20,14 +21,19 @@ where
 {
     pub(crate) fn new_old(col: &'a mut CoreCol<Singly<T>, P>) -> Self {
         let current = col.ends().get();
-        Self { col, current }
+        Self {
+            col,
+            current,
+            last: None,
+        }
     }
 
     pub(crate) fn new(
         col: &'a mut CoreCol<Singly<T>, P>,
         current: Option<NodePtr<Singly<T>>>,
+        last: Option<NodePtr<Singly<T>>>,
     ) -> Self {
-        Self { col, current }
+        Self { col, current, last }
     }
 }
 
@@ -42,7 +48,10 @@ where
             Some(p) => {
                 // SAFETY: collection as alive as guaranteed by the `col` field.
                 let ptr = unsafe { p.ptr_mut() };
-                self.current = self.col.node(p).next().get();
+                self.current = match self.last == Some(p) {
+                    false => self.col.node(p).next().get(),
+                    true => None,
+                };
                 unsafe { &mut *ptr }.data_mut()
             }
             None => None,
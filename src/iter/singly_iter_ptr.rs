@@ -12,14 +12,21 @@ where
 {
     pub(crate) col: &'a CoreCol<Singly<T>, P>,
     current: Option<NodePtr<Singly<T>>>,
+    last: Option<NodePtr<Singly<T>>>,
 }
 
 impl<'a, T, P> SinglyIterPtr<'a, T, P>
 where
     P: PinnedVec<Node<Singly<T>>>,
 {
-    pub(crate) fn new(col: &'a CoreCol<Singly<T>, P>, current: Option<NodePtr<Singly<T>>>) -> Self {
-        Self { col, current }
+    /// Creates an iterator starting at `current` and ending at `last`;
+    /// or continuing until the end of the list if `last` is None.
+    pub(crate) fn new(
+        col: &'a CoreCol<Singly<T>, P>,
+        current: Option<NodePtr<Singly<T>>>,
+        last: Option<NodePtr<Singly<T>>>,
+    ) -> Self {
+        Self { col, current, last }
     }
 }
 
@@ -33,7 +40,10 @@ where
         match self.current {
             Some(p) => {
                 let ptr = Some(p);
-                self.current = self.col.node(p).next().get();
+                self.current = match self.last == ptr {
+                    false => self.col.node(p).next().get(),
+                    true => None,
+                };
                 ptr
             }
             None => None,
@@ -51,6 +61,7 @@ where
         Self {
             col: self.col,
             current: self.current,
+            last: self.last,
         }
     }
 }
@@ -15,30 +15,46 @@
 #[cfg(any(test, feature = "validation"))]
 mod tests;
 
-#[cfg(any(test, feature = "validation"))]
+#[cfg(any(test, feature = "validation", feature = "std"))]
 extern crate std;
 
 extern crate alloc;
 
+pub mod arena;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
+pub mod cache;
 /// Module containing iterators from the list.
 pub mod iter;
 mod list;
 mod memory;
 /// Module providing access to the pointers of the linked list nodes.
 pub mod pointers;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod type_aliases;
 mod variant;
 
 pub use list::List;
+pub use list::branded::{BrandedIdx, BrandedList};
+pub use list::capacity_error::CapacityError;
 pub use list::ends_traits::*;
+pub use list::idx_map::IdxMap;
+pub use list::insert_error::InsertError;
 pub use list::iter_traits::*;
-pub use list::slice::{ListSlice, ListSliceMut};
+pub use list::iterable_x::{IterX, IterableX};
+pub use list::keyed::{KeyIndex, KeyedDoublyList, KeyedListError};
+pub use list::memory_report::MemoryReport;
+pub use list::slice::{ListChunksMut, ListSlice, ListSliceMut, SliceError};
+pub use memory::MemoryReclaimIncremental;
 pub use orx_selfref_col::{MemoryPolicy, NodeIdx, NodeIdxError};
 pub use type_aliases::{
-    DoublyIdx, DoublyList, DoublyListLazy, DoublyListSlice, DoublyListSliceLazy,
-    DoublyListSliceMut, DoublyListSliceMutLazy, DoublyListThreshold, SinglyIdx, SinglyList,
-    SinglyListLazy, SinglyListSlice, SinglyListSliceLazy, SinglyListSliceMut,
-    SinglyListSliceMutLazy, SinglyListThreshold,
+    DoublyIdx, DoublyList, DoublyListIncremental, DoublyListLazy, DoublyListSlice,
+    DoublyListSliceLazy, DoublyListSliceMut, DoublyListSliceMutLazy, DoublyListThreshold,
+    DoublyToSinglyIdxMap, SinglyIdx, SinglyList, SinglyListLazy, SinglyListSlice,
+    SinglyListSliceLazy, SinglyListSliceMut, SinglyListSliceMutLazy, SinglyListThreshold,
+    SinglyToDoublyIdxMap,
 };
 pub use variant::{Doubly, Singly};
 
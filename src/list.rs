@@ -7,25 +7,43 @@ use helper_traits::{
     HasCol, HasColMut, HasDoublyEnds, HasDoublyEndsMut, HasSinglyEnds, HasSinglyEndsMut,
 };
 use orx_pinned_vec::PinnedVec;
-use orx_selfref_col::{MemoryPolicy, Node, SelfRefCol};
+use orx_selfref_col::{MemoryPolicy, Node, NodePtr, SelfRefCol};
 
+pub(crate) mod capacity_error;
 pub(crate) mod ends_traits;
 pub(crate) mod helper_traits;
+pub(crate) mod insert_error;
 pub(crate) mod iter_traits;
+pub(crate) mod memory_report;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+pub(crate) mod branded;
+mod capacity;
 mod common_traits;
+mod compact;
 mod consuming;
 mod get;
 mod get_doubly;
+mod get_singly;
 mod idx_doubly;
+pub(crate) mod idx_map;
 mod idx_singly;
+mod into_pinned_vec;
+mod into_variant;
+pub(crate) mod iterable_x;
+pub(crate) mod keyed;
 mod linear;
 mod linear_eq;
 mod mut_doubly;
 mod mut_doubly_recursive;
+mod mut_fixed;
 mod mut_singly;
+mod mut_singly_recursive;
 mod mutate;
 mod new;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub(crate) mod random_ops;
 mod reclaim;
 pub(crate) mod slice;
 
@@ -95,8 +113,12 @@ where
     M: MemoryPolicy<Singly<T>>,
     P: PinnedVec<Node<Singly<T>>>,
 {
-    fn ends(&self) -> &<Singly<T> as orx_selfref_col::Variant>::Ends {
-        self.0.ends()
+    fn front_end(&self) -> Option<NodePtr<Singly<T>>> {
+        self.0.ends().get()
+    }
+
+    fn back_end(&self) -> Option<NodePtr<Singly<T>>> {
+        None
     }
 }
 
@@ -105,7 +127,9 @@ where
     M: MemoryPolicy<Singly<T>>,
     P: PinnedVec<Node<Singly<T>>>,
 {
-    fn ends_mut(&mut self) -> &mut <Singly<T> as orx_selfref_col::Variant>::Ends {
-        self.0.ends_mut()
+    fn set_front_end(&mut self, front: Option<NodePtr<Singly<T>>>) {
+        self.0.ends_mut().set(front);
     }
+
+    fn set_back_end(&mut self, _: Option<NodePtr<Singly<T>>>) {}
 }
@@ -0,0 +1,70 @@
+use super::random_ops::{DoublyOp, SinglyOp};
+use crate::{Doubly, List, Singly};
+use arbitrary::{Arbitrary, Result, Unstructured};
+use orx_selfref_col::MemoryPolicy;
+
+impl<'a, T> Arbitrary<'a> for DoublyOp<T>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0u8..=8)? {
+            0 => Self::PushFront(u.arbitrary()?),
+            1 => Self::PushBack(u.arbitrary()?),
+            2 => Self::InsertNextTo(u.arbitrary()?, u.arbitrary()?),
+            3 => Self::InsertPrevTo(u.arbitrary()?, u.arbitrary()?),
+            4 => Self::Remove(u.arbitrary()?),
+            5 => Self::MoveToFront(u.arbitrary()?),
+            6 => Self::MoveToBack(u.arbitrary()?),
+            7 => Self::MoveNextTo(u.arbitrary()?, u.arbitrary()?),
+            _ => Self::MovePrevTo(u.arbitrary()?, u.arbitrary()?),
+        })
+    }
+}
+
+impl<'a, T> Arbitrary<'a> for SinglyOp<T>
+where
+    T: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0u8..=3)? {
+            0 => Self::PushFront(u.arbitrary()?),
+            1 => Self::InsertAt(u.arbitrary()?, u.arbitrary()?),
+            2 => Self::RemoveAt(u.arbitrary()?),
+            _ => Self::PopFront,
+        })
+    }
+}
+
+/// Generates a doubly linked list by applying a random sequence of `push_front`,
+/// `push_back`, `insert_next_to`, `insert_prev_to`, `remove` and `move_*` operations
+/// on an empty list.
+impl<'a, T, M> Arbitrary<'a> for List<Doubly<T>, M>
+where
+    T: Arbitrary<'a>,
+    M: MemoryPolicy<Doubly<T>>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut list = Self::with_recursive_growth();
+        for op in u.arbitrary_iter::<DoublyOp<T>>()? {
+            op?.apply(&mut list);
+        }
+        Ok(list)
+    }
+}
+
+/// Generates a singly linked list by applying a random sequence of `push_front`,
+/// `insert_at`, `remove_at` and `pop_front` operations on an empty list.
+impl<'a, T, M> Arbitrary<'a> for List<Singly<T>, M>
+where
+    T: Arbitrary<'a>,
+    M: MemoryPolicy<Singly<T>>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut list = Self::with_recursive_growth();
+        for op in u.arbitrary_iter::<SinglyOp<T>>()? {
+            op?.apply(&mut list);
+        }
+        Ok(list)
+    }
+}
@@ -0,0 +1,302 @@
+use super::helper_traits::HasDoublyEndsMut;
+use crate::{
+    Doubly, DoublyIdx, DoublyIterable, List,
+    type_aliases::{BACK_IDX, FRONT_IDX},
+};
+use core::{fmt::Debug, marker::PhantomData};
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node, NodeIdx, NodePtr};
+
+/// Invariant lifetime marker tying branded indices to the scope of a single list.
+type Brand<'id> = PhantomData<fn(&'id ()) -> &'id ()>;
+
+/// Index of an element of a [`BrandedList`], branded with the unique lifetime `'id` of its scope.
+///
+/// A branded index can only be created by, and used with, the branded list of the same scope.
+/// Passing it to the branded list of another scope fails to compile; and it cannot escape its scope.
+/// Since the elements of a branded list cannot be removed within the scope, a branded index is
+/// guaranteed to be valid; hence, the branded list accesses its element without any runtime validity checks.
+///
+/// See [`List::scope`] for details.
+pub struct BrandedIdx<'id, T> {
+    ptr: NodePtr<Doubly<T>>,
+    brand: Brand<'id>,
+}
+
+impl<T> BrandedIdx<'_, T> {
+    fn new(ptr: NodePtr<Doubly<T>>) -> Self {
+        Self {
+            ptr,
+            brand: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for BrandedIdx<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BrandedIdx<'_, T> {}
+
+impl<T> PartialEq for BrandedIdx<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+}
+
+impl<T> Eq for BrandedIdx<'_, T> {}
+
+impl<T> core::hash::Hash for BrandedIdx<'_, T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // SAFETY: the pointer is only used as an address, it is not dereferenced
+        (unsafe { self.ptr.ptr() } as usize).hash(state);
+    }
+}
+
+impl<T> Debug for BrandedIdx<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BrandedIdx")
+            .field("ptr", &self.ptr)
+            .finish()
+    }
+}
+
+/// A doubly linked list borrowed mutably within a [`List::scope`], handing out [`BrandedIdx`]
+/// indices which are statically tied to this list.
+///
+/// Within the scope, elements can be accessed, inserted and moved; however, they cannot be removed,
+/// which could invalidate the branded indices. Removals can be performed on the list after the scope ends.
+pub struct BrandedList<'id, 'a, T, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    list: &'a mut List<Doubly<T>, M, P>,
+    brand: Brand<'id>,
+}
+
+impl<T, M, P> List<Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    /// Calls `f` with a [`BrandedList`] borrowing this list, and returns its result.
+    ///
+    /// The branded list hands out [`BrandedIdx`] indices which carry the unique invariant lifetime `'id`
+    /// of this scope. Therefore:
+    /// * an index of another list cannot be used with this list, which is detected at compile time
+    ///   rather than at runtime by [`idx_err`];
+    /// * indices cannot escape the scope;
+    /// * since elements cannot be removed and memory cannot be reclaimed within the scope,
+    ///   branded indices are always valid, and the branded `get`, `get_mut` and `move_*` methods
+    ///   skip the runtime validity checks.
+    ///
+    /// [`idx_err`]: crate::DoublyEnds::idx_err
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..5).collect();
+    ///
+    /// let sum_of_ends = list.scope(|mut list| {
+    ///     let front = list.front_idx().unwrap();
+    ///     let back = list.back_idx().unwrap();
+    ///
+    ///     let x = list.insert_next_to(front, 10);
+    ///     list.move_to_back(x);
+    ///     list.swap(front, back);
+    ///     *list.get_mut(front) += 100;
+    ///
+    ///     list.get(list.front_idx().unwrap()) + list.get(list.back_idx().unwrap())
+    /// });
+    ///
+    /// assert_eq!(sum_of_ends, 14);
+    /// assert!(list.eq_to_iter_vals([4, 1, 2, 3, 100, 10]));
+    /// ```
+    ///
+    /// Indices of one list cannot be used with another list:
+    ///
+    /// ```rust compile_fail
+    /// use orx_linked_list::*;
+    ///
+    /// let mut a: DoublyList<_> = (0..5).collect();
+    /// let mut b: DoublyList<_> = (0..5).collect();
+    ///
+    /// a.scope(|a| {
+    ///     b.scope(|mut b| {
+    ///         let idx = a.front_idx().unwrap();
+    ///         b.move_to_back(idx);
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// and they cannot escape their scope:
+    ///
+    /// ```rust compile_fail
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..5).collect();
+    /// let idx = list.scope(|list| list.front_idx().unwrap());
+    /// ```
+    pub fn scope<F, R>(&mut self, f: F) -> R
+    where
+        F: for<'id> FnOnce(BrandedList<'id, '_, T, M, P>) -> R,
+    {
+        f(BrandedList {
+            list: self,
+            brand: PhantomData,
+        })
+    }
+}
+
+impl<'id, T, M, P> BrandedList<'id, '_, T, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    /// Returns a reference to the underlying list, providing all of its read only methods.
+    pub fn list(&self) -> &List<Doubly<T>, M, P> {
+        self.list
+    }
+
+    /// ***O(1)*** Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns an iterator of the elements of the list from front to back.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.list.iter()
+    }
+
+    /// Returns an iterator of the branded indices of the elements of the list from front to back.
+    pub fn indices(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = BrandedIdx<'id, T>> + use<'_, 'id, T, M, P> {
+        self.list.iter_ptr().map(BrandedIdx::new)
+    }
+
+    /// Brands the `idx` obtained outside of the scope; returns None if the index is not valid for this list.
+    ///
+    /// This is the only method of the branded list which validates an index at runtime.
+    pub fn brand(&self, idx: DoublyIdx<T>) -> Option<BrandedIdx<'id, T>> {
+        self.list.0.try_get_ptr(idx).ok().map(BrandedIdx::new)
+    }
+
+    /// Converts the branded `idx` into a regular index which can be used after the scope ends.
+    pub fn unbrand(&self, idx: BrandedIdx<'id, T>) -> DoublyIdx<T> {
+        NodeIdx::new(self.list.memory_state(), idx.ptr)
+    }
+
+    /// ***O(1)*** Returns the index of the front element; None if the list is empty.
+    pub fn front_idx(&self) -> Option<BrandedIdx<'id, T>> {
+        self.list.0.ends().get(FRONT_IDX).map(BrandedIdx::new)
+    }
+
+    /// ***O(1)*** Returns the index of the back element; None if the list is empty.
+    pub fn back_idx(&self) -> Option<BrandedIdx<'id, T>> {
+        self.list.0.ends().get(BACK_IDX).map(BrandedIdx::new)
+    }
+
+    /// ***O(1)*** Returns the index of the element next to the one at the `idx`; None if it is the back.
+    pub fn next_idx_of(&self, idx: BrandedIdx<'id, T>) -> Option<BrandedIdx<'id, T>> {
+        self.list.0.node(idx.ptr).next().get().map(BrandedIdx::new)
+    }
+
+    /// ***O(1)*** Returns the index of the element prior to the one at the `idx`; None if it is the front.
+    pub fn prev_idx_of(&self, idx: BrandedIdx<'id, T>) -> Option<BrandedIdx<'id, T>> {
+        self.list.0.node(idx.ptr).prev().get().map(BrandedIdx::new)
+    }
+
+    /// ***O(1)*** Returns a reference to the element at the `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node at the `idx` is not active, which cannot happen since nodes cannot be removed within the scope.
+    pub fn get(&self, idx: BrandedIdx<'id, T>) -> &T {
+        self.list
+            .0
+            .node(idx.ptr)
+            .data()
+            .expect("branded node is active")
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the element at the `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node at the `idx` is not active, which cannot happen since nodes cannot be removed within the scope.
+    pub fn get_mut(&mut self, idx: BrandedIdx<'id, T>) -> &mut T {
+        self.list
+            .0
+            .node_mut(idx.ptr)
+            .data_mut()
+            .expect("branded node is active")
+    }
+
+    /// ***O(1)*** Pushes the `value` to the front of the list and returns its branded index.
+    pub fn push_front(&mut self, value: T) -> BrandedIdx<'id, T> {
+        let idx = self.list.push_front(value);
+        BrandedIdx::new(idx.node_ptr())
+    }
+
+    /// ***O(1)*** Pushes the `value` to the back of the list and returns its branded index.
+    pub fn push_back(&mut self, value: T) -> BrandedIdx<'id, T> {
+        let idx = self.list.push_back(value);
+        BrandedIdx::new(idx.node_ptr())
+    }
+
+    /// ***O(1)*** Inserts the `value` as the next of the element at the `idx` and returns its branded index.
+    pub fn insert_next_to(&mut self, idx: BrandedIdx<'id, T>, value: T) -> BrandedIdx<'id, T> {
+        BrandedIdx::new(self.list.insert_next_to_ptr(idx.ptr, value))
+    }
+
+    /// ***O(1)*** Inserts the `value` as the prev of the element at the `idx` and returns its branded index.
+    pub fn insert_prev_to(&mut self, idx: BrandedIdx<'id, T>, value: T) -> BrandedIdx<'id, T> {
+        BrandedIdx::new(self.list.insert_prev_to_ptr(idx.ptr, value))
+    }
+
+    /// ***O(1)*** Moves the element at the `idx` to the front of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is empty, which cannot happen since the list contains the element at the `idx`.
+    pub fn move_to_front(&mut self, idx: BrandedIdx<'id, T>) {
+        let front = self.list.0.ends().get(FRONT_IDX).expect("nonempty list");
+        self.list.move_prev_to_ptr(idx.ptr, front);
+    }
+
+    /// ***O(1)*** Moves the element at the `idx` to the back of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is empty, which cannot happen since the list contains the element at the `idx`.
+    pub fn move_to_back(&mut self, idx: BrandedIdx<'id, T>) {
+        let back = self.list.0.ends().get(BACK_IDX).expect("nonempty list");
+        self.list.move_next_to_ptr(idx.ptr, back);
+    }
+
+    /// ***O(1)*** Moves the element at the `idx` immediately after the element at the `idx_target`.
+    pub fn move_next_to(&mut self, idx: BrandedIdx<'id, T>, idx_target: BrandedIdx<'id, T>) {
+        self.list.move_next_to_ptr(idx.ptr, idx_target.ptr);
+    }
+
+    /// ***O(1)*** Moves the element at the `idx` immediately before the element at the `idx_target`.
+    pub fn move_prev_to(&mut self, idx: BrandedIdx<'id, T>, idx_target: BrandedIdx<'id, T>) {
+        self.list.move_prev_to_ptr(idx.ptr, idx_target.ptr);
+    }
+
+    /// ***O(1)*** Swaps the elements at indices `idx_a` and `idx_b`.
+    pub fn swap(&mut self, idx_a: BrandedIdx<'id, T>, idx_b: BrandedIdx<'id, T>) {
+        self.list.swap_ptr(idx_a.ptr, idx_b.ptr);
+    }
+}
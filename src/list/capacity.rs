@@ -0,0 +1,319 @@
+use crate::{List, variant::ListVariant};
+use alloc::{collections::TryReserveError, vec::Vec};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, MemoryState, Node, NodePtr};
+use orx_split_vec::{Growth, Recursive, SplitVec};
+
+impl<V, M, P> List<V, M, P>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// ***O(1)*** Returns the number of nodes that the underlying storage can hold without allocating.
+    ///
+    /// Note that removed nodes which are not yet reclaimed still occupy positions in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: DoublyList<char, _> = DoublyList::with_fixed_capacity(8);
+    /// assert_eq!(list.capacity(), 8);
+    ///
+    /// let list: DoublyList<char> = DoublyList::with_capacity(100);
+    /// assert!(list.capacity() >= 100);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.0.nodes().capacity()
+    }
+}
+
+impl<V, M> List<V, M, FixedVec<Node<V>>>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+{
+    /// ***O(1)*** Returns the number of elements that can be added to the list before its fixed capacity is exhausted.
+    ///
+    /// Note that removed nodes which are not yet reclaimed still occupy positions in the storage.
+    /// Their positions can be made available by [`reclaim_closed_nodes`], at the cost of invalidating indices.
+    ///
+    /// [`reclaim_closed_nodes`]: crate::List::reclaim_closed_nodes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = DoublyList::with_fixed_capacity(4);
+    /// assert_eq!(list.remaining_capacity(), 4);
+    ///
+    /// list.push_back('a');
+    /// list.push_back('b');
+    /// assert_eq!(list.remaining_capacity(), 2);
+    ///
+    /// list.push_back('c');
+    /// list.push_back('d');
+    /// assert_eq!(list.remaining_capacity(), 0);
+    /// assert_eq!(list.try_push_back('e'), Err(CapacityError('e')));
+    /// ```
+    pub fn remaining_capacity(&self) -> usize {
+        let nodes = self.0.nodes();
+        nodes.capacity() - nodes.len()
+    }
+
+    #[inline(always)]
+    pub(crate) fn has_capacity_for_one(&self) -> bool {
+        self.remaining_capacity() > 0
+    }
+
+    /// ***O(n)*** Reclaims the closed nodes so that their positions become available for new elements;
+    /// and returns the memory states before and after the operation.
+    ///
+    /// The fixed capacity of the list is never released; hence, unlike lists with a `SplitVec` storage,
+    /// the capacity is not changed and the list can still grow up to its fixed capacity.
+    ///
+    /// Indices obtained before this call are invalidated whenever the returned memory states are different.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = DoublyListLazy::with_fixed_capacity(4);
+    /// for i in 0..4 {
+    ///     list.push_back(i);
+    /// }
+    /// list.pop_front();
+    /// assert_eq!(list.remaining_capacity(), 0);
+    ///
+    /// let (old, new) = list.shrink_to_fit();
+    /// assert_ne!(old, new);
+    /// assert_eq!(list.capacity(), 4);
+    /// assert_eq!(list.remaining_capacity(), 1);
+    ///
+    /// list.push_back(4);
+    /// assert!(list.eq_to_iter_vals(1..5));
+    /// ```
+    pub fn shrink_to_fit(&mut self) -> (MemoryState, MemoryState) {
+        self.reclaim_closed_nodes()
+    }
+}
+
+impl<V, M, G> List<V, M, SplitVec<Node<V>, G>>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    G: Growth,
+{
+    /// ***O(n)*** Reclaims the closed nodes and releases the unused capacity of the underlying `SplitVec`;
+    /// and returns the memory states before and after the operation.
+    ///
+    /// Closed nodes are compacted by [`reclaim_closed_nodes`], which truncates the storage to the active nodes.
+    /// Then, the fragments at the end of the storage which do not hold any node are released.
+    /// Finally, if the last fragment is larger than the fragment that the growth strategy would allocate next,
+    /// as is the case for a list created by `with_capacity`, its nodes are moved into a new fragment which is
+    /// just large enough to hold them, or has the capacity that the growth strategy would allocate, whichever is larger.
+    ///
+    /// Indices obtained before this call are invalidated whenever the returned memory states are different,
+    /// which is the case if the reclaim moves nodes or truncates closed nodes, or if the nodes of the last fragment are moved.
+    /// Releasing empty fragments alone does not change the memory state.
+    ///
+    /// [`reclaim_closed_nodes`]: crate::List::reclaim_closed_nodes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = SinglyListLazy::new();
+    /// for i in 0..1000 {
+    ///     list.push_front(i);
+    /// }
+    /// for _ in 0..990 {
+    ///     list.pop_front();
+    /// }
+    /// let idx = list.idx_of(&5).unwrap();
+    ///
+    /// let (old, new) = list.shrink_to_fit();
+    /// assert_ne!(old, new);
+    /// assert!(list.capacity() < 20);
+    /// assert!(list.eq_to_iter_vals((0..10).rev()));
+    /// assert!(list.idx_err(idx).is_some());
+    ///
+    /// // only unused capacity to release
+    /// let mut list = DoublyList::new();
+    /// let idx = list.push_back('a');
+    /// list.reserve(1000);
+    ///
+    /// let (old, new) = list.shrink_to_fit();
+    /// assert_eq!(old, new);
+    /// assert!(list.capacity() < 1000);
+    /// assert_eq!(list.get(idx), Some(&'a'));
+    ///
+    /// // nodes of an oversized last fragment are moved
+    /// let mut list = DoublyList::with_capacity(1000);
+    /// let idx = list.push_back('a');
+    ///
+    /// let (old, new) = list.shrink_to_fit();
+    /// assert_ne!(old, new);
+    /// assert!(list.capacity() < 1000);
+    /// assert!(list.idx_err(idx).is_some());
+    /// assert!(list.eq_to_iter_vals(['a']));
+    /// ```
+    pub fn shrink_to_fit(&mut self) -> (MemoryState, MemoryState) {
+        let (old, _) = self.reclaim_closed_nodes();
+
+        // SAFETY: removing empty fragments at the end does not affect positions of the nodes
+        let fragments = unsafe { self.0.nodes_mut().fragments_mut() };
+        while fragments.last().is_some_and(|f| f.is_empty()) {
+            _ = fragments.pop();
+        }
+
+        let nodes = self.0.nodes();
+        if let Some((last, previous)) = nodes.fragments().split_last() {
+            let growth_capacity = nodes
+                .growth()
+                .new_fragment_capacity_from(previous.iter().map(|f| f.capacity()));
+            let capacity = last.len().max(growth_capacity);
+            if capacity < last.capacity() {
+                self.relocate_last_fragment(capacity);
+                self.0.update_state(true);
+            }
+        }
+
+        (old, self.0.memory_state())
+    }
+
+    /// Moves the nodes of the last fragment into a new fragment with the given `capacity`,
+    /// and updates all references to the moved nodes.
+    fn relocate_last_fragment(&mut self, capacity: usize) {
+        // SAFETY: the last fragment is replaced by a fragment holding the same nodes at the same positions
+        let fragments = unsafe { self.0.nodes_mut().fragments_mut() };
+        let last = fragments.last_mut().expect("last fragment exists");
+
+        let old_range = last.as_ptr_range();
+        let mut fragment = Vec::with_capacity(capacity);
+        fragment.extend(last.drain(..));
+        *last = fragment.into();
+        let new_begin = last.as_ptr();
+
+        let relocate = |p: NodePtr<V>| {
+            // SAFETY: the pointer is only compared to, and used to compute, addresses; it is not dereferenced
+            let ptr = unsafe { p.ptr() };
+            match old_range.contains(&ptr) {
+                true => {
+                    let position = (ptr as usize - old_range.start as usize) / size_of::<Node<V>>();
+                    NodePtr::new(new_begin.wrapping_add(position))
+                }
+                false => p,
+            }
+        };
+
+        for node in fragments.iter_mut().flat_map(|f| f.iter_mut()) {
+            V::relocate_node_refs(node, relocate);
+        }
+        V::relocate_end_refs(self.0.ends_mut(), relocate);
+    }
+}
+
+impl<V, M> List<V, M, SplitVec<Node<V>, Recursive>>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+{
+    /// Reserves capacity for at least `additional` more elements to be added to the list.
+    ///
+    /// Elements are only pushed to the last fragment of the underlying `SplitVec`, and nothing is allocated
+    /// if its room is sufficient for `additional` elements. Otherwise, since the `Recursive` growth accepts
+    /// fragments of any capacity, a new fragment with a capacity of `additional` is appended;
+    /// and the remaining room of the previous fragment is left unused.
+    ///
+    /// Positions of the existing elements are not affected; hence, indices remain valid.
+    ///
+    /// Note that reserving is only available for the `Recursive` growth, which is the default storage of the lists.
+    /// Capacities of the fragments of other growth strategies, such as `Doubling` or `Linear`, are determined by
+    /// the growth, and a new fragment can only be used once the last fragment is full.
+    /// Therefore, they cannot guarantee to reserve an arbitrary `additional` capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocation fails; see [`try_reserve`] for the fallible version.
+    ///
+    /// [`try_reserve`]: crate::List::try_reserve
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = DoublyList::new();
+    /// let a = list.push_back('a');
+    ///
+    /// list.reserve(1000);
+    /// assert!(list.capacity() >= 1001);
+    ///
+    /// for _ in 0..1000 {
+    ///     list.push_back('x');
+    /// }
+    ///
+    /// assert_eq!(list.len(), 1001);
+    /// assert_eq!(list.get(a), Some(&'a'));
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).expect("allocation failed")
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be added to the list;
+    /// returns an error if the allocation fails.
+    ///
+    /// See [`reserve`] for details.
+    ///
+    /// [`reserve`]: crate::List::reserve
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = SinglyList::new();
+    /// let a = list.push_front('a');
+    ///
+    /// assert!(list.try_reserve(1000).is_ok());
+    /// assert!(list.capacity() >= 1001);
+    ///
+    /// for _ in 0..1000 {
+    ///     list.push_front('x');
+    /// }
+    ///
+    /// assert_eq!(list.len(), 1001);
+    /// assert_eq!(list.get(a), Some(&'a'));
+    ///
+    /// assert!(list.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let last = self.0.nodes().fragments().last();
+        if last.is_some_and(|f| f.room() >= additional) {
+            return Ok(());
+        }
+
+        let replace_last = last.is_some_and(|f| f.is_empty());
+
+        let mut fragment: Vec<Node<V>> = Vec::new();
+        fragment.try_reserve_exact(additional)?;
+
+        // SAFETY: the new fragment is appended after the last fragment, and the Recursive growth accepts
+        // fragments of any capacity; an empty last fragment is replaced, it would otherwise be left in the
+        // middle of the nodes.
+        let fragments = unsafe { self.0.nodes_mut().fragments_mut() };
+        if replace_last {
+            _ = fragments.pop();
+        }
+        fragments.push(fragment.into());
+
+        Ok(())
+    }
+}
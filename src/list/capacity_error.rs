@@ -0,0 +1,24 @@
+use core::fmt::{Debug, Display};
+
+/// Error of fallible insertions to a list with a fixed capacity, such as `try_push_back` or `try_insert_at`,
+/// which is returned when the underlying storage has no room for the new element.
+///
+/// The value that could not be inserted is given back to the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T>(pub T);
+
+impl<T> CapacityError<T> {
+    /// Consumes the error and returns the value that could not be inserted.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Display for CapacityError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "CapacityError: the list does not have room for a new element"
+        )
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{Doubly, DoublyIterable, List, ListSlice, ListSliceMut, Singly, SinglyIterable};
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+/// Implements equality of the list type `$list` with arrays, slices and vectors in both directions,
+/// where the list is equal to the sequence if its elements from front to back are equal to the sequence.
+macro_rules! impl_eq_std {
+    ([$($lt:lifetime)?] $list:ty, $variant:ident) => {
+        impl<$($lt,)? T, M, P, const N: usize> PartialEq<[T; N]> for $list
+        where
+            T: PartialEq,
+            M: MemoryPolicy<$variant<T>>,
+            P: PinnedVec<Node<$variant<T>>>,
+        {
+            fn eq(&self, other: &[T; N]) -> bool {
+                self.eq_to_iter_refs(other.iter())
+            }
+        }
+
+        impl<$($lt,)? T, M, P> PartialEq<[T]> for $list
+        where
+            T: PartialEq,
+            M: MemoryPolicy<$variant<T>>,
+            P: PinnedVec<Node<$variant<T>>>,
+        {
+            fn eq(&self, other: &[T]) -> bool {
+                self.eq_to_iter_refs(other.iter())
+            }
+        }
+
+        impl<$($lt,)? T, M, P> PartialEq<&[T]> for $list
+        where
+            T: PartialEq,
+            M: MemoryPolicy<$variant<T>>,
+            P: PinnedVec<Node<$variant<T>>>,
+        {
+            fn eq(&self, other: &&[T]) -> bool {
+                self.eq_to_iter_refs(other.iter())
+            }
+        }
+
+        impl<$($lt,)? T, M, P> PartialEq<Vec<T>> for $list
+        where
+            T: PartialEq,
+            M: MemoryPolicy<$variant<T>>,
+            P: PinnedVec<Node<$variant<T>>>,
+        {
+            fn eq(&self, other: &Vec<T>) -> bool {
+                self.eq_to_iter_refs(other.iter())
+            }
+        }
+
+        impl<$($lt,)? T, M, P, const N: usize> PartialEq<$list> for [T; N]
+        where
+            T: PartialEq,
+            M: MemoryPolicy<$variant<T>>,
+            P: PinnedVec<Node<$variant<T>>>,
+        {
+            fn eq(&self, other: &$list) -> bool {
+                other.eq_to_iter_refs(self.iter())
+            }
+        }
+
+        impl<$($lt,)? T, M, P> PartialEq<$list> for [T]
+        where
+            T: PartialEq,
+            M: MemoryPolicy<$variant<T>>,
+            P: PinnedVec<Node<$variant<T>>>,
+        {
+            fn eq(&self, other: &$list) -> bool {
+                other.eq_to_iter_refs(self.iter())
+            }
+        }
+
+        impl<$($lt,)? T, M, P> PartialEq<$list> for &[T]
+        where
+            T: PartialEq,
+            M: MemoryPolicy<$variant<T>>,
+            P: PinnedVec<Node<$variant<T>>>,
+        {
+            fn eq(&self, other: &$list) -> bool {
+                other.eq_to_iter_refs(self.iter())
+            }
+        }
+
+        impl<$($lt,)? T, M, P> PartialEq<$list> for Vec<T>
+        where
+            T: PartialEq,
+            M: MemoryPolicy<$variant<T>>,
+            P: PinnedVec<Node<$variant<T>>>,
+        {
+            fn eq(&self, other: &$list) -> bool {
+                other.eq_to_iter_refs(self.iter())
+            }
+        }
+    };
+}
+
+// singly
+
+impl_eq_std!([] List<Singly<T>, M, P>, Singly);
+impl_eq_std!(['a] ListSlice<'a, Singly<T>, M, P>, Singly);
+impl_eq_std!(['a] ListSliceMut<'a, Singly<T>, M, P>, Singly);
+
+// doubly
+
+impl_eq_std!([] List<Doubly<T>, M, P>, Doubly);
+impl_eq_std!(['a] ListSlice<'a, Doubly<T>, M, P>, Doubly);
+impl_eq_std!(['a] ListSliceMut<'a, Doubly<T>, M, P>, Doubly);
@@ -1,4 +1,7 @@
-use crate::{List, variant::Doubly};
+use crate::{
+    List,
+    variant::{Doubly, Singly},
+};
 use orx_pinned_vec::PinnedVec;
 use orx_selfref_col::{MemoryPolicy, Node};
 
@@ -25,3 +28,25 @@ where
         }
     }
 }
+
+impl<T, M, P> Extend<T> for List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let back = self.back_ptr();
+        self.insert_iter_after(back, iter);
+    }
+}
+
+impl<'a, T: Clone, M, P> Extend<&'a T> for List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        let back = self.back_ptr();
+        self.insert_iter_after(back, iter.into_iter().cloned());
+    }
+}
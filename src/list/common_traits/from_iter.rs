@@ -1,34 +1,89 @@
 use crate::{
-    List, Singly,
+    DoublyIdx, DoublyIterable, List, Singly, SinglyIdx, SinglyIterable,
     type_aliases::{BACK_IDX, FRONT_IDX},
     variant::Doubly,
 };
+use alloc::vec::Vec;
 use orx_pinned_vec::PinnedVec;
-use orx_selfref_col::{MemoryPolicy, Node, NodePtr, SelfRefCol};
+use orx_selfref_col::{MemoryPolicy, Node, NodePtr, Refs, SelfRefCol};
 
 // singly
 
-impl<T, M> FromIterator<T> for List<Singly<T>, M>
+impl<T, M, P> FromIterator<T> for List<Singly<T>, M, P>
 where
     M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>> + Default,
 {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         singly_from_iter(iter)
     }
 }
 
+impl<T, M> List<Singly<T>, M>
+where
+    M: MemoryPolicy<Singly<T>>,
+{
+    /// Creates a list from the elements of the `iter`, as `collect` would;
+    /// and returns it together with the indices of its nodes from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let (list, idx) = SinglyList::from_iter_with_indices(['a', 'b', 'c']);
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c']));
+    /// assert_eq!(idx.len(), 3);
+    /// assert_eq!(list.get(idx[1]), Some(&'b'));
+    /// ```
+    pub fn from_iter_with_indices<I>(iter: I) -> (Self, Vec<SinglyIdx<T>>)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let list: Self = singly_from_iter(iter);
+        let indices = list.indices().collect();
+        (list, indices)
+    }
+}
+
 pub(crate) fn singly_from_iter<T, M, P, I>(iter: I) -> List<Singly<T>, M, P>
 where
     M: MemoryPolicy<Singly<T>>,
     P: PinnedVec<Node<Singly<T>>> + Default,
     I: IntoIterator<Item = T>,
+{
+    let mut nodes = P::default();
+    for x in iter {
+        nodes.push(Node::new_free_node(x));
+    }
+    singly_from_nodes(nodes)
+}
+
+/// Creates a list from the elements of the `iter` by first collecting the nodes into a `Vec`,
+/// which allows to create lists with pinned vectors that cannot be default-constructed, such as `FixedVec`.
+pub(crate) fn singly_from_vec<T, M, P, I>(iter: I) -> List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>> + From<Vec<Node<Singly<T>>>>,
+    I: IntoIterator<Item = T>,
+{
+    let nodes: Vec<_> = iter.into_iter().map(Node::new_free_node).collect();
+    singly_from_nodes(P::from(nodes))
+}
+
+/// Creates a list by linking the free `nodes` in the order they are positioned in the storage.
+pub(crate) fn singly_from_nodes<T, M, P>(nodes: P) -> List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
 {
     #[inline(always)]
     fn node_ptr<T>(p: *const Node<Singly<T>>) -> Option<NodePtr<Singly<T>>> {
         Some(NodePtr::new(p as *mut Node<Singly<T>>))
     }
 
-    let mut col: SelfRefCol<Singly<T>, M, P> = SelfRefCol::from_iter(iter);
+    let mut col: SelfRefCol<Singly<T>, M, P> = SelfRefCol::from((nodes, Refs::empty()));
 
     // SAFETY: lifetime of the `forward` iterator is limited to this method
     // which is shorter than the lifetime of the `col`
@@ -50,27 +105,81 @@ where
 
 // doubly
 
-impl<T, M> FromIterator<T> for List<Doubly<T>, M>
+impl<T, M, P> FromIterator<T> for List<Doubly<T>, M, P>
 where
     M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>> + Default,
 {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         doubly_from_iter(iter)
     }
 }
 
+impl<T, M> List<Doubly<T>, M>
+where
+    M: MemoryPolicy<Doubly<T>>,
+{
+    /// Creates a list from the elements of the `iter`, as `collect` would;
+    /// and returns it together with the indices of its nodes from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let (list, idx) = DoublyList::from_iter_with_indices(['a', 'b', 'c']);
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c']));
+    /// assert_eq!(idx.len(), 3);
+    /// assert_eq!(list.get(idx[1]), Some(&'b'));
+    /// ```
+    pub fn from_iter_with_indices<I>(iter: I) -> (Self, Vec<DoublyIdx<T>>)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let list: Self = doubly_from_iter(iter);
+        let indices = list.indices().collect();
+        (list, indices)
+    }
+}
+
 pub(crate) fn doubly_from_iter<T, M, P, I>(iter: I) -> List<Doubly<T>, M, P>
 where
     M: MemoryPolicy<Doubly<T>>,
     P: PinnedVec<Node<Doubly<T>>> + Default,
     I: IntoIterator<Item = T>,
+{
+    let mut nodes = P::default();
+    for x in iter {
+        nodes.push(Node::new_free_node(x));
+    }
+    doubly_from_nodes(nodes)
+}
+
+/// Creates a list from the elements of the `iter` by first collecting the nodes into a `Vec`,
+/// which allows to create lists with pinned vectors that cannot be default-constructed, such as `FixedVec`.
+pub(crate) fn doubly_from_vec<T, M, P, I>(iter: I) -> List<Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>> + From<Vec<Node<Doubly<T>>>>,
+    I: IntoIterator<Item = T>,
+{
+    let nodes: Vec<_> = iter.into_iter().map(Node::new_free_node).collect();
+    doubly_from_nodes(P::from(nodes))
+}
+
+/// Creates a list by linking the free `nodes` in the order they are positioned in the storage.
+pub(crate) fn doubly_from_nodes<T, M, P>(nodes: P) -> List<Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
 {
     #[inline(always)]
     fn node_ptr<T>(p: *const Node<Doubly<T>>) -> Option<NodePtr<Doubly<T>>> {
         Some(NodePtr::new(p as *mut Node<Doubly<T>>))
     }
 
-    let mut col: SelfRefCol<Doubly<T>, M, P> = SelfRefCol::from_iter(iter);
+    let mut col: SelfRefCol<Doubly<T>, M, P> = SelfRefCol::from((nodes, Refs::empty()));
 
     // SAFETY: lifetime of the `forward` iterator is limited to this method
     // which is shorter than the lifetime of the `col`
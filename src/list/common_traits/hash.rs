@@ -0,0 +1,36 @@
+use crate::{Doubly, DoublyIterable, List, Singly, SinglyIterable};
+use core::hash::{Hash, Hasher};
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+// singly
+
+impl<T, M, P> Hash for List<Singly<T>, M, P>
+where
+    T: Hash,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for x in self.iter() {
+            x.hash(state);
+        }
+    }
+}
+
+// doubly
+
+impl<T, M, P> Hash for List<Doubly<T>, M, P>
+where
+    T: Hash,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for x in self.iter() {
+            x.hash(state);
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
-    List, Singly,
-    iter::{DoublyIterOwned, SinglyIterOwned},
+    DoublyIterable, DoublyIterableMut, List, Singly, SinglyIterable,
+    iter::{
+        DoublyIter, DoublyIterMut, DoublyIterOwned, SinglyIter, SinglyIterMut, SinglyIterOwned,
+    },
     variant::Doubly,
 };
 use orx_pinned_vec::PinnedVec;
@@ -75,3 +77,63 @@ where
         Self::IntoIter::new(self.0.into_inner().0)
     }
 }
+
+impl<'a, T, M, P> IntoIterator for &'a List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    type Item = &'a T;
+
+    type IntoIter = SinglyIter<'a, T, P>;
+
+    /// Returns a forward iterator to references of elements of the list from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, M, P> IntoIterator for &'a mut List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = SinglyIterMut<'a, T, P>;
+
+    /// Returns a forward iterator to mutable references of elements of the list from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T, M, P> IntoIterator for &'a List<Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    type Item = &'a T;
+
+    type IntoIter = DoublyIter<'a, T, P>;
+
+    /// Returns a forward iterator to references of elements of the list from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, M, P> IntoIterator for &'a mut List<Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = DoublyIterMut<'a, T, P>;
+
+    /// Returns a forward iterator to mutable references of elements of the list from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
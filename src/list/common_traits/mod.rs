@@ -1,8 +1,11 @@
 mod clone;
 mod debug;
 mod eq;
+mod eq_std;
 mod extend;
-mod from_iter;
+pub(crate) mod from_iter;
+mod hash;
 mod index;
 mod into;
 mod into_iter;
+mod ord;
@@ -0,0 +1,52 @@
+use crate::{Doubly, DoublyIterable, List, Singly, SinglyIterable};
+use core::cmp::Ordering;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+// singly
+
+impl<T, M, P> PartialOrd for List<Singly<T>, M, P>
+where
+    T: PartialOrd,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T, M, P> Ord for List<Singly<T>, M, P>
+where
+    T: Ord,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+// doubly
+
+impl<T, M, P> PartialOrd for List<Doubly<T>, M, P>
+where
+    T: PartialOrd,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T, M, P> Ord for List<Doubly<T>, M, P>
+where
+    T: Ord,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
@@ -0,0 +1,171 @@
+use super::List;
+use crate::{
+    Doubly, DoublyIterable, Singly, SinglyIterable,
+    memory::node_ptr_at_pos,
+    type_aliases::{BACK_IDX, FRONT_IDX},
+};
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, MemoryState, Node, NodePtr};
+
+impl<T, M, P> List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    /// ***O(n)*** Physically reorders the nodes so that the storage order matches the front-to-back order of the list,
+    /// and removes the closed nodes from the storage;
+    /// returns the memory states before and after the operation.
+    ///
+    /// After this call:
+    /// * `iter` is a linear scan over the underlying storage, and
+    /// * `iter_x` yields the elements in the same order as `iter`.
+    ///
+    /// The capacity of the underlying storage is not increased; the elements are rearranged within the same storage.
+    ///
+    /// If the nodes are already compact and in list order, the list is not changed.
+    /// Otherwise, the memory state changes and all indices obtained before this call are invalidated,
+    /// which can be detected by `idx_err`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = SinglyList::new();
+    /// list.push_front('c');
+    /// list.push_front('b');
+    /// let a = list.push_front('a');
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c']));
+    /// assert!(list.iter_x().copied().eq(['c', 'b', 'a']));
+    ///
+    /// let (old, new) = list.compact_in_list_order();
+    /// assert_ne!(old, new);
+    /// assert!(list.idx_err(a).is_some());
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c']));
+    /// assert!(list.iter_x().copied().eq(['a', 'b', 'c']));
+    ///
+    /// let (old, new) = list.compact_in_list_order();
+    /// assert_eq!(old, new);
+    /// ```
+    pub fn compact_in_list_order(&mut self) -> (MemoryState, MemoryState) {
+        let old = self.0.memory_state();
+        if self.is_compact_in_list_order() {
+            return (old, old);
+        }
+
+        let values = self.take_values_in_list_order();
+        let n = values.len();
+        self.0.nodes_mut().truncate(n);
+
+        let ptrs: Vec<_> = (0..n).map(|i| node_ptr_at_pos(&self.0, i)).collect();
+        for (i, value) in values.into_iter().enumerate() {
+            let node = self.0.node_mut(ptrs[i]);
+            *node = Node::new_free_node(value);
+            node.next_mut().set(ptrs.get(i + 1).copied());
+        }
+
+        self.0.ends_mut().set(ptrs.first().copied());
+        self.0.update_state(true);
+
+        (old, self.0.memory_state())
+    }
+
+    fn is_compact_in_list_order(&self) -> bool {
+        self.0.nodes().len() == self.len() && self.iter_ptr().eq(self.storage_ptrs())
+    }
+
+    /// Takes out the values of the active nodes in list order, leaving all nodes closed.
+    pub(crate) fn take_values_in_list_order(&mut self) -> Vec<T> {
+        let ptrs: Vec<_> = self.iter_ptr().collect();
+        ptrs.into_iter()
+            .map(|p| self.0.node_mut(p).take_data().expect("active node"))
+            .collect()
+    }
+
+    fn storage_ptrs(&self) -> impl Iterator<Item = NodePtr<Singly<T>>> + '_ {
+        (0..self.0.nodes().len()).map(|i| node_ptr_at_pos(&self.0, i))
+    }
+}
+
+impl<T, M, P> List<Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    /// ***O(n)*** Physically reorders the nodes so that the storage order matches the front-to-back order of the list,
+    /// and removes the closed nodes from the storage;
+    /// returns the memory states before and after the operation.
+    ///
+    /// After this call:
+    /// * `iter` is a linear scan over the underlying storage, and
+    /// * `iter_x` yields the elements in the same order as `iter`.
+    ///
+    /// The capacity of the underlying storage is not increased; the elements are rearranged within the same storage.
+    ///
+    /// If the nodes are already compact and in list order, the list is not changed.
+    /// Otherwise, the memory state changes and all indices obtained before this call are invalidated,
+    /// which can be detected by `idx_err`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..5).collect();
+    /// let idx = list.idx_of(&4).unwrap();
+    /// list.move_to_front(idx);
+    ///
+    /// assert!(list.eq_to_iter_vals([4, 0, 1, 2, 3]));
+    /// assert!(list.iter_x().copied().eq([0, 1, 2, 3, 4]));
+    ///
+    /// let (old, new) = list.compact_in_list_order();
+    /// assert_ne!(old, new);
+    /// assert!(list.idx_err(idx).is_some());
+    ///
+    /// assert!(list.eq_to_iter_vals([4, 0, 1, 2, 3]));
+    /// assert!(list.iter_x().copied().eq([4, 0, 1, 2, 3]));
+    /// ```
+    pub fn compact_in_list_order(&mut self) -> (MemoryState, MemoryState) {
+        let old = self.0.memory_state();
+        if self.is_compact_in_list_order() {
+            return (old, old);
+        }
+
+        let values = self.take_values_in_list_order();
+        let n = values.len();
+        self.0.nodes_mut().truncate(n);
+
+        let ptrs: Vec<_> = (0..n).map(|i| node_ptr_at_pos(&self.0, i)).collect();
+        for (i, value) in values.into_iter().enumerate() {
+            let node = self.0.node_mut(ptrs[i]);
+            *node = Node::new_free_node(value);
+            node.next_mut().set(ptrs.get(i + 1).copied());
+            node.prev_mut().set(i.checked_sub(1).map(|p| ptrs[p]));
+        }
+
+        self.0.ends_mut().set(FRONT_IDX, ptrs.first().copied());
+        self.0.ends_mut().set(BACK_IDX, ptrs.last().copied());
+        self.0.update_state(true);
+
+        (old, self.0.memory_state())
+    }
+
+    fn is_compact_in_list_order(&self) -> bool {
+        self.0.nodes().len() == self.len() && self.iter_ptr().eq(self.storage_ptrs())
+    }
+
+    /// Takes out the values of the active nodes in list order, leaving all nodes closed.
+    pub(crate) fn take_values_in_list_order(&mut self) -> Vec<T> {
+        let ptrs: Vec<_> = self.iter_ptr().collect();
+        ptrs.into_iter()
+            .map(|p| self.0.node_mut(p).take_data().expect("active node"))
+            .collect()
+    }
+
+    fn storage_ptrs(&self) -> impl Iterator<Item = NodePtr<Doubly<T>>> + '_ {
+        (0..self.0.nodes().len()).map(|i| node_ptr_at_pos(&self.0, i))
+    }
+}
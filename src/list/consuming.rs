@@ -60,7 +60,7 @@ where
     /// ```
     /// use orx_linked_list::*;
     ///
-    /// let new_list = || DoublyList::from_iter(0..1024);
+    /// let new_list = || (0..1024).collect::<DoublyList<_>>();
     ///
     /// let expected: usize = new_list().iter_x().sum();
     ///
@@ -1,3 +1,4 @@
+use super::{IntegrityError, check_integrity};
 use crate::{
     Doubly, DoublyIdx,
     list::helper_traits::HasDoublyEnds,
@@ -630,6 +631,65 @@ where
     {
         self.prev_idx_of(idx).and_then(|i| self.get(i))
     }
+
+    /// ***O(n)*** Checks the structural integrity of the list or slice;
+    /// returns an [`IntegrityError`] describing the first problem found, if any.
+    ///
+    /// The following are checked:
+    /// * front and back are either both set or both unset, and point to active nodes;
+    /// * each node reachable from the front is active and its next links back to it as the previous;
+    /// * the back is reachable from the front without visiting any node twice;
+    /// * in addition, for a list; the front has no previous, the back has no next,
+    ///   and the length of the list is equal to both the number of active nodes and
+    ///   the number of nodes reachable from the front.
+    ///
+    /// A list can never be in an invalid state unless the unsafe api, such as [`add_link`], [`remove_link`],
+    /// [`set_front`] or [`set_back`], is used; this method allows to verify the result of such
+    /// a series of unsafe operations.
+    ///
+    /// [`IntegrityError`]: crate::IntegrityError
+    /// [`add_link`]: crate::DoublyEndsMut::add_link
+    /// [`remove_link`]: crate::DoublyEndsMut::remove_link
+    /// [`set_front`]: crate::DoublyEndsMut::set_front
+    /// [`set_back`]: crate::DoublyEndsMut::set_back
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..8).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// assert_eq!(list.check_integrity(), Ok(()));
+    /// assert_eq!(list.slice(idx[2]..idx[5]).check_integrity(), Ok(()));
+    ///
+    /// unsafe {
+    ///     list.remove_link(idx[0], idx[1]);
+    ///     list.remove_link(idx[2], idx[3]);
+    ///     list.add_link(idx[0], idx[3]);
+    ///     list.add_link(idx[7], idx[1]);
+    /// }
+    ///
+    /// // forgot to set the back to idx[2]
+    /// assert!(matches!(
+    ///     list.check_integrity(),
+    ///     Err(IntegrityError::EndsMismatch { .. })
+    /// ));
+    ///
+    /// unsafe { list.set_back(idx[2]) };
+    /// assert_eq!(list.check_integrity(), Ok(()));
+    /// ```
+    fn check_integrity(&self) -> Result<(), IntegrityError> {
+        check_integrity(
+            self.col(),
+            self.ends().get(FRONT_IDX),
+            self.ends().get(BACK_IDX),
+            core::ptr::eq(self.ends(), self.col().ends()),
+            |node| node.next().get(),
+            Some(|node| node.prev().get()),
+        )
+    }
 }
 
 impl<L, T, M, P> DoublyEnds<T, M, P> for L
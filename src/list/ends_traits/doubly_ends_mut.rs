@@ -1,5 +1,5 @@
 use crate::{
-    Doubly, DoublyEnds, DoublyIdx,
+    Doubly, DoublyEnds, DoublyIdx, MoveError,
     list::helper_traits::HasDoublyEndsMut,
     type_aliases::{BACK_IDX, FRONT_IDX, IDX_ERR, OOB},
 };
@@ -487,78 +487,56 @@ where
     fn move_next_to(&mut self, idx: DoublyIdx<T>, idx_target: DoublyIdx<T>) {
         let mid = self.col().try_get_ptr(idx).expect(IDX_ERR);
         let prev = self.col().try_get_ptr(idx_target).expect(IDX_ERR);
+        self.move_next_to_ptr(mid, prev);
+    }
 
-        if mid == prev {
-            return;
-        }
-
-        let next = self.col().node(prev).next().get();
-        let old_next = self.col().node(mid).next().get();
-        let old_prev = self.col().node(mid).prev().get();
-
-        // update the gap
-        match (old_prev, old_next) {
-            (Some(old_prev), _) if old_prev == prev => return,
-            (Some(old_prev), Some(old_next)) => self.link(old_prev, old_next),
-            (Some(old_prev), None) => {
-                // idx must be col.back
-                self.col_mut().node_mut(old_prev).next_mut().set_none();
-
-                self.col_mut().ends_mut().set_some(BACK_IDX, old_prev);
-            }
-            (None, Some(old_next)) => {
-                // idx must be col.front
-                self.col_mut().node_mut(old_next).prev_mut().set_none();
-
-                self.col_mut().ends_mut().set_some(FRONT_IDX, old_next);
-            }
-            (None, None) => return,
-        }
-
-        // update the fill
-        match next {
-            Some(next) => self.link(mid, next),
-            None => self.col_mut().node_mut(mid).next_mut().set_none(),
-        }
-        self.link(prev, mid);
-
-        // custom ends
-        let old_front = self.ends().get(FRONT_IDX);
-        let old_back = self.ends().get(BACK_IDX);
-
-        if let Some(old_back) = old_back {
-            match old_back == prev {
-                true => {
-                    // new node placed in front
-                    self.ends_mut().set_some(BACK_IDX, mid)
-                }
-                false => {
-                    if old_back == mid {
-                        // old front is moved away
-                        let old_front = old_front.expect("exists");
-                        match mid == old_front {
-                            false => {
-                                let new_back = old_prev.expect("exists");
-                                self.ends_mut().set_some(BACK_IDX, new_back);
-                            }
-                            true => { /* singleton, no update */ }
-                        }
-                    }
-                }
-            }
-        }
-
-        if let Some(old_front) = old_front
-            && old_front == mid
-        {
-            // old back is moved away
-            let old_back = old_back.expect("exists");
-            match old_front == old_back {
-                false => {
-                    let new_front = old_next.expect("exists");
-                    self.ends_mut().set_some(FRONT_IDX, new_front);
-                }
-                true => { /* singleton, no update */ }
+    /// Moves the element with the given `idx` immediately after the target element with the given `idx_target`;
+    /// returns an error and leaves the list unchanged if the move is not possible.
+    ///
+    /// ***O(1)*** when called on a list; ***O(n)*** when called on a slice, since the nodes are
+    /// additionally validated to belong to the slice.
+    ///
+    /// The move fails if:
+    /// * either of the indices is invalid, see [`MoveError::InvalidIdx`];
+    /// * `idx` and `idx_target` point to the same node, see [`MoveError::IdenticalNodes`];
+    /// * either of the nodes does not belong to the slice, see [`MoveError::OutOfSlice`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..6).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// assert_eq!(list.try_move_next_to(idx[4], idx[1]), Ok(()));
+    /// assert!(list.eq_to_iter_vals([0, 1, 4, 2, 3, 5]));
+    ///
+    /// assert_eq!(list.try_move_next_to(idx[4], idx[4]), Err(MoveError::IdenticalNodes));
+    ///
+    /// let mut slice = list.slice_mut(idx[4]..=idx[3]);
+    /// assert!(slice.eq_to_iter_vals([4, 2, 3]));
+    /// assert_eq!(slice.try_move_next_to(idx[2], idx[5]), Err(MoveError::OutOfSlice));
+    ///
+    /// list.remove(idx[0]);
+    /// assert_eq!(
+    ///     list.try_move_next_to(idx[0], idx[2]),
+    ///     Err(MoveError::InvalidIdx(NodeIdxError::RemovedNode))
+    /// );
+    /// assert!(list.eq_to_iter_vals([1, 4, 2, 3, 5]));
+    /// ```
+    fn try_move_next_to(
+        &mut self,
+        idx: DoublyIdx<T>,
+        idx_target: DoublyIdx<T>,
+    ) -> Result<(), MoveError> {
+        let mid = self.try_get_member_ptr(idx)?;
+        let prev = self.try_get_member_ptr(idx_target)?;
+        match mid == prev {
+            true => Err(MoveError::IdenticalNodes),
+            false => {
+                self.move_next_to(idx, idx_target);
+                Ok(())
             }
         }
     }
@@ -592,78 +570,56 @@ where
     fn move_prev_to(&mut self, idx: DoublyIdx<T>, idx_target: DoublyIdx<T>) {
         let mid = self.col().try_get_ptr(idx).expect(IDX_ERR);
         let next = self.col().try_get_ptr(idx_target).expect(IDX_ERR);
+        self.move_prev_to_ptr(mid, next);
+    }
 
-        if mid == next {
-            return;
-        }
-
-        let prev = self.col().node(next).prev().get();
-        let old_next = self.col().node(mid).next().get();
-        let old_prev = self.col().node(mid).prev().get();
-
-        // update the gap
-        match (old_prev, old_next) {
-            (_, Some(old_next)) if old_next == next => return,
-            (Some(old_prev), Some(old_next)) => self.link(old_prev, old_next),
-            (Some(old_prev), None) => {
-                // idx must be col.back
-                self.col_mut().node_mut(old_prev).next_mut().set_none();
-
-                self.col_mut().ends_mut().set_some(BACK_IDX, old_prev);
-            }
-            (None, Some(old_next)) => {
-                // idx must be col.front
-                self.col_mut().node_mut(old_next).prev_mut().set_none();
-
-                self.col_mut().ends_mut().set_some(FRONT_IDX, old_next);
-            }
-            (None, None) => return,
-        }
-
-        // update the fill
-        match prev {
-            Some(prev) => self.link(prev, mid),
-            None => self.col_mut().node_mut(mid).prev_mut().set_none(),
-        }
-        self.link(mid, next);
-
-        // custom ends
-        let old_front = self.ends().get(FRONT_IDX);
-        let old_back = self.ends().get(BACK_IDX);
-
-        if let Some(old_front) = &old_front {
-            match old_front == &next {
-                true => {
-                    // new node placed in front
-                    self.ends_mut().set_some(FRONT_IDX, mid)
-                }
-                false => {
-                    if old_front == &mid {
-                        // old front is moved away
-                        let old_back = old_back.expect("exists");
-                        match mid == old_back {
-                            false => {
-                                let new_front = old_next.expect("exists");
-                                self.ends_mut().set_some(FRONT_IDX, new_front);
-                            }
-                            true => { /* singleton, no update */ }
-                        }
-                    }
-                }
-            }
-        }
-
-        if let Some(old_back) = old_back
-            && old_back == mid
-        {
-            // old back is moved away
-            let old_front = old_front.expect("exists");
-            match old_front == old_back {
-                false => {
-                    let new_back = old_prev.expect("exists");
-                    self.ends_mut().set_some(BACK_IDX, new_back);
-                }
-                true => { /* singleton, no update */ }
+    /// Moves the element with the given `idx` immediately before the target element with the given `idx_target`;
+    /// returns an error and leaves the list unchanged if the move is not possible.
+    ///
+    /// ***O(1)*** when called on a list; ***O(n)*** when called on a slice, since the nodes are
+    /// additionally validated to belong to the slice.
+    ///
+    /// The move fails if:
+    /// * either of the indices is invalid, see [`MoveError::InvalidIdx`];
+    /// * `idx` and `idx_target` point to the same node, see [`MoveError::IdenticalNodes`];
+    /// * either of the nodes does not belong to the slice, see [`MoveError::OutOfSlice`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..6).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// assert_eq!(list.try_move_prev_to(idx[4], idx[1]), Ok(()));
+    /// assert!(list.eq_to_iter_vals([0, 4, 1, 2, 3, 5]));
+    ///
+    /// assert_eq!(list.try_move_prev_to(idx[4], idx[4]), Err(MoveError::IdenticalNodes));
+    ///
+    /// let mut slice = list.slice_mut(idx[1]..=idx[3]);
+    /// assert_eq!(slice.try_move_prev_to(idx[0], idx[2]), Err(MoveError::OutOfSlice));
+    ///
+    /// let other: DoublyList<_> = (0..3).collect();
+    /// let other_idx = other.idx_of(&1).unwrap();
+    /// assert_eq!(
+    ///     list.try_move_prev_to(other_idx, idx[2]),
+    ///     Err(MoveError::InvalidIdx(NodeIdxError::OutOfBounds))
+    /// );
+    /// assert!(list.eq_to_iter_vals([0, 4, 1, 2, 3, 5]));
+    /// ```
+    fn try_move_prev_to(
+        &mut self,
+        idx: DoublyIdx<T>,
+        idx_target: DoublyIdx<T>,
+    ) -> Result<(), MoveError> {
+        let mid = self.try_get_member_ptr(idx)?;
+        let next = self.try_get_member_ptr(idx_target)?;
+        match mid == next {
+            true => Err(MoveError::IdenticalNodes),
+            false => {
+                self.move_prev_to(idx, idx_target);
+                Ok(())
             }
         }
     }
@@ -700,6 +656,41 @@ where
         self.move_prev_to(idx, idx_target);
     }
 
+    /// Moves the element with the given `idx` to the front of the list;
+    /// returns an error and leaves the list unchanged if the move is not possible.
+    ///
+    /// ***O(1)*** when called on a list; ***O(n)*** when called on a slice, since the nodes are
+    /// additionally validated to belong to the slice.
+    ///
+    /// The move fails if:
+    /// * the index is invalid, see [`MoveError::InvalidIdx`];
+    /// * the node does not belong to the slice, see [`MoveError::OutOfSlice`].
+    ///
+    /// Moving the element which is already at the front is a successful no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..6).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// assert_eq!(list.try_move_to_front(idx[3]), Ok(()));
+    /// assert!(list.eq_to_iter_vals([3, 0, 1, 2, 4, 5]));
+    ///
+    /// let mut slice = list.slice_mut(idx[2]..=idx[4]);
+    /// assert_eq!(slice.try_move_to_front(idx[1]), Err(MoveError::OutOfSlice));
+    ///
+    /// list.clear();
+    /// assert!(list.try_move_to_front(idx[2]).is_err());
+    /// ```
+    fn try_move_to_front(&mut self, idx: DoublyIdx<T>) -> Result<(), MoveError> {
+        let _ = self.try_get_member_ptr(idx)?;
+        self.move_to_front(idx);
+        Ok(())
+    }
+
     /// ***O(1)*** Moves the element with the given `idx`
     /// to the back of the list.
     ///
@@ -732,6 +723,41 @@ where
         self.move_next_to(idx, idx_target);
     }
 
+    /// Moves the element with the given `idx` to the back of the list;
+    /// returns an error and leaves the list unchanged if the move is not possible.
+    ///
+    /// ***O(1)*** when called on a list; ***O(n)*** when called on a slice, since the nodes are
+    /// additionally validated to belong to the slice.
+    ///
+    /// The move fails if:
+    /// * the index is invalid, see [`MoveError::InvalidIdx`];
+    /// * the node does not belong to the slice, see [`MoveError::OutOfSlice`].
+    ///
+    /// Moving the element which is already at the back is a successful no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..6).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// assert_eq!(list.try_move_to_back(idx[3]), Ok(()));
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 4, 5, 3]));
+    ///
+    /// let mut slice = list.slice_mut(idx[2]..=idx[4]);
+    /// assert_eq!(slice.try_move_to_back(idx[1]), Err(MoveError::OutOfSlice));
+    ///
+    /// list.clear();
+    /// assert!(list.try_move_to_back(idx[2]).is_err());
+    /// ```
+    fn try_move_to_back(&mut self, idx: DoublyIdx<T>) -> Result<(), MoveError> {
+        let _ = self.try_get_member_ptr(idx)?;
+        self.move_to_back(idx);
+        Ok(())
+    }
+
     /// ***O(1)*** Swaps the elements with indices `a` and `b`.
     ///
     /// # Panics
@@ -760,74 +786,47 @@ where
     fn swap(&mut self, idx_a: DoublyIdx<T>, idx_b: DoublyIdx<T>) {
         let a = self.col().try_get_ptr(idx_a).expect(IDX_ERR);
         let b = self.col().try_get_ptr(idx_b).expect(IDX_ERR);
+        self.swap_ptr(a, b);
+    }
 
-        if a == b {
-            return;
-        }
-
-        let p_a = self.col().node(a).prev().get();
-        let p_b = self.col().node(b).prev().get();
-        let n_a = self.col().node(a).next().get();
-        let n_b = self.col().node(b).next().get();
-
-        match (n_a, n_b) {
-            (Some(n_a), _) if b == n_a => self.move_next_to(idx_a, idx_b),
-            (_, Some(n_b)) if a == n_b => self.move_next_to(idx_b, idx_a),
-            _ => {
-                match p_a {
-                    Some(p_a) => self.link(p_a, b),
-                    None => self.col_mut().node_mut(b).prev_mut().set_none(),
-                }
-
-                match p_b {
-                    Some(p_b) => self.link(p_b, a),
-                    None => self.col_mut().node_mut(a).prev_mut().set_none(),
-                }
-
-                match n_a {
-                    Some(n_a) => self.link(b, n_a),
-                    None => self.col_mut().node_mut(b).next_mut().set_none(),
-                }
-
-                match n_b {
-                    Some(n_b) => self.link(a, n_b),
-                    None => self.col_mut().node_mut(a).next_mut().set_none(),
-                }
-
-                // cache custom ends
-                let custom_front = match self.ends().get(FRONT_IDX) {
-                    Some(x) if x == a => Some(b),
-                    Some(x) if x == b => Some(a),
-                    _ => None,
-                };
-
-                let custom_back = match self.ends().get(BACK_IDX) {
-                    Some(x) if x == a => Some(b),
-                    Some(x) if x == b => Some(a),
-                    _ => None,
-                };
-
-                // update col ends
-                match self.col().ends().get(FRONT_IDX) {
-                    Some(x) if x == a => self.col_mut().ends_mut().set_some(FRONT_IDX, b),
-                    Some(x) if x == b => self.col_mut().ends_mut().set_some(FRONT_IDX, a),
-                    _ => {}
-                }
-
-                match self.col().ends().get(BACK_IDX) {
-                    Some(x) if x == a => self.col_mut().ends_mut().set_some(BACK_IDX, b),
-                    Some(x) if x == b => self.col_mut().ends_mut().set_some(BACK_IDX, a),
-                    _ => {}
-                }
-
-                // update custom ends
-                if let Some(new_front) = custom_front {
-                    self.ends_mut().set_some(FRONT_IDX, new_front);
-                }
-
-                if let Some(new_back) = custom_back {
-                    self.ends_mut().set_some(BACK_IDX, new_back);
-                }
+    /// Swaps the elements with indices `a` and `b`;
+    /// returns an error and leaves the list unchanged if the swap is not possible.
+    ///
+    /// ***O(1)*** when called on a list; ***O(n)*** when called on a slice, since the nodes are
+    /// additionally validated to belong to the slice.
+    ///
+    /// The swap fails if:
+    /// * either of the indices is invalid, see [`MoveError::InvalidIdx`];
+    /// * `idx_a` and `idx_b` point to the same node, see [`MoveError::IdenticalNodes`];
+    /// * either of the nodes does not belong to the slice, see [`MoveError::OutOfSlice`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..6).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// assert_eq!(list.try_swap(idx[1], idx[5]), Ok(()));
+    /// assert!(list.eq_to_iter_vals([0, 5, 2, 3, 4, 1]));
+    ///
+    /// assert_eq!(list.try_swap(idx[3], idx[3]), Err(MoveError::IdenticalNodes));
+    ///
+    /// let mut slice = list.slice_mut(idx[2]..=idx[4]);
+    /// assert_eq!(slice.try_swap(idx[2], idx[0]), Err(MoveError::OutOfSlice));
+    /// assert_eq!(slice.try_swap(idx[2], idx[4]), Ok(()));
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 5, 4, 3, 2, 1]));
+    /// ```
+    fn try_swap(&mut self, idx_a: DoublyIdx<T>, idx_b: DoublyIdx<T>) -> Result<(), MoveError> {
+        let a = self.try_get_member_ptr(idx_a)?;
+        let b = self.try_get_member_ptr(idx_b)?;
+        match a == b {
+            true => Err(MoveError::IdenticalNodes),
+            false => {
+                self.swap(idx_a, idx_b);
+                Ok(())
             }
         }
     }
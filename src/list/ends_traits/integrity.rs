@@ -0,0 +1,163 @@
+use alloc::vec;
+use core::fmt::{Debug, Display};
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{CoreCol, Node, NodePtr, Variant};
+
+/// Error cases of the structural integrity check of a list or slice, returned by `check_integrity`.
+///
+/// Positions refer to the positions of the nodes in the underlying storage of the list,
+/// rather than positions of the elements in the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityError {
+    /// BrokenLink => The node at position `from` links to a node which is not valid as its next:
+    /// * `to` is None if the link points outside of the underlying storage;
+    /// * otherwise, the node at position `to` is either a removed node, or,
+    ///   in a doubly linked list, its previous does not link back to the node at position `from`.
+    BrokenLink {
+        /// Storage position of the node holding the broken link.
+        from: usize,
+        /// Storage position of the node that the link points to; None if it is outside of the storage.
+        to: Option<usize>,
+    },
+    /// EndsMismatch => The ends of the list or slice do not agree with the links of the nodes:
+    /// * only one of the front and back is set;
+    /// * an end points outside of the storage or to a removed node;
+    /// * the front of a list has a previous or the back of a list has a next; or
+    /// * the back is not reachable from the front.
+    EndsMismatch {
+        /// Storage position of the front; None if the front is not set or points outside of the storage.
+        front: Option<usize>,
+        /// Storage position of the back; None if the back is not set or points outside of the storage.
+        back: Option<usize>,
+    },
+    /// LengthMismatch => The length of the list does not agree with the nodes; i.e., either the number of
+    /// active nodes in the storage or the number of nodes reachable from the front differs from the length.
+    LengthMismatch {
+        /// Length of the list.
+        expected: usize,
+        /// Number of nodes found.
+        found: usize,
+    },
+    /// Cycle => Traversal from the front visits the node at `position` twice.
+    Cycle {
+        /// Storage position of the node that is visited twice.
+        position: usize,
+    },
+}
+
+impl Display for IntegrityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <IntegrityError as Debug>::fmt(self, f)
+    }
+}
+
+type Link<V> = fn(&Node<V>) -> Option<NodePtr<V>>;
+
+/// Traverses the nodes from `front` to `back` and checks the links.
+///
+/// * `is_list` determines whether the ends are the ends of the entire list rather than a slice,
+///   in which case the ends and the length are checked against the collection as well.
+/// * `prev` is None for singly linked lists.
+pub(crate) fn check_integrity<V, P>(
+    col: &CoreCol<V, P>,
+    front: Option<NodePtr<V>>,
+    back: Option<NodePtr<V>>,
+    is_list: bool,
+    next: Link<V>,
+    prev: Option<Link<V>>,
+) -> Result<(), IntegrityError>
+where
+    V: Variant,
+    P: PinnedVec<Node<V>>,
+{
+    let active_position =
+        |ptr: NodePtr<V>| col.position_of(ptr).filter(|_| col.node(ptr).is_active());
+
+    let ends_mismatch = || IntegrityError::EndsMismatch {
+        front: front.and_then(|x| col.position_of(x)),
+        back: back.and_then(|x| col.position_of(x)),
+    };
+
+    if is_list {
+        let num_active = col.nodes().iter().filter(|x| x.is_active()).count();
+        if num_active != col.len() {
+            return Err(IntegrityError::LengthMismatch {
+                expected: col.len(),
+                found: num_active,
+            });
+        }
+    }
+
+    let front = match (front, back, prev.is_some()) {
+        (None, None, _) => None,
+        (None, Some(_), _) => return Err(ends_mismatch()),
+        (Some(_), None, true) => return Err(ends_mismatch()),
+        (Some(front), _, _) => Some(front),
+    };
+
+    let mut count = 0;
+
+    if let Some(front) = front {
+        let Some(front_pos) = active_position(front) else {
+            return Err(ends_mismatch());
+        };
+
+        if back.is_some_and(|back| active_position(back).is_none()) {
+            return Err(ends_mismatch());
+        }
+
+        if is_list && prev.and_then(|prev| prev(col.node(front))).is_some() {
+            return Err(ends_mismatch());
+        }
+
+        let mut visited = vec![false; col.nodes().len()];
+        let (mut ptr, mut pos) = (front, front_pos);
+
+        loop {
+            visited[pos] = true;
+            count += 1;
+
+            if Some(ptr) == back {
+                if is_list && next(col.node(ptr)).is_some() {
+                    return Err(ends_mismatch());
+                }
+                break;
+            }
+
+            let Some(next_ptr) = next(col.node(ptr)) else {
+                match back {
+                    Some(_) => return Err(ends_mismatch()),
+                    None => break,
+                }
+            };
+
+            let Some(next_pos) = active_position(next_ptr) else {
+                return Err(IntegrityError::BrokenLink {
+                    from: pos,
+                    to: col.position_of(next_ptr),
+                });
+            };
+
+            if visited[next_pos] {
+                return Err(IntegrityError::Cycle { position: next_pos });
+            }
+
+            if prev.is_some_and(|prev| prev(col.node(next_ptr)) != Some(ptr)) {
+                return Err(IntegrityError::BrokenLink {
+                    from: pos,
+                    to: Some(next_pos),
+                });
+            }
+
+            (ptr, pos) = (next_ptr, next_pos);
+        }
+    }
+
+    match is_list && count != col.len() {
+        true => Err(IntegrityError::LengthMismatch {
+            expected: col.len(),
+            found: count,
+        }),
+        false => Ok(()),
+    }
+}
@@ -1,9 +1,14 @@
 mod doubly_ends;
 mod doubly_ends_mut;
+mod integrity;
+mod move_error;
 mod singly_ends;
 mod singly_ends_mut;
 
 pub use doubly_ends::DoublyEnds;
 pub use doubly_ends_mut::DoublyEndsMut;
+pub use integrity::IntegrityError;
+pub(crate) use integrity::check_integrity;
+pub use move_error::MoveError;
 pub use singly_ends::SinglyEnds;
 pub use singly_ends_mut::SinglyEndsMut;
@@ -0,0 +1,26 @@
+use core::fmt::{Debug, Display};
+use orx_selfref_col::NodeIdxError;
+
+/// Error cases of fallible moves and swaps of nodes, such as `try_move_next_to` or `try_swap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    /// InvalidIdx => One of the node indices is invalid for the list; the wrapped error gives the reason.
+    InvalidIdx(NodeIdxError),
+    /// IdenticalNodes => The node to be moved and the target node, or the two nodes to be swapped, are the same node.
+    IdenticalNodes,
+    /// OutOfSlice => One of the node indices is valid for the list; however, the node does not
+    /// belong to the slice that the method is called on.
+    OutOfSlice,
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <MoveError as Debug>::fmt(self, f)
+    }
+}
+
+impl From<NodeIdxError> for MoveError {
+    fn from(value: NodeIdxError) -> Self {
+        Self::InvalidIdx(value)
+    }
+}
@@ -1,3 +1,4 @@
+use super::{IntegrityError, check_integrity};
 use crate::{Singly, SinglyIdx, list::helper_traits::HasSinglyEnds, type_aliases::IDX_ERR};
 use orx_pinned_vec::PinnedVec;
 use orx_selfref_col::{MemoryPolicy, Node, NodeIdxError};
@@ -33,8 +34,7 @@ where
         M: 'a,
         P: 'a,
     {
-        self.ends()
-            .get()
+        self.front_end()
             .map(|p| unsafe { self.col().data_unchecked(p) })
     }
 
@@ -526,6 +526,40 @@ where
     {
         self.next_idx_of(idx).and_then(|i| self.get(i))
     }
+
+    /// ***O(n)*** Checks the structural integrity of the list or slice;
+    /// returns an [`IntegrityError`] describing the first problem found, if any.
+    ///
+    /// The following are checked:
+    /// * the front, and the back if set, point to active nodes;
+    /// * each node reachable from the front is active;
+    /// * the back, or the end of the list, is reachable from the front without visiting any node twice;
+    /// * in addition, for a list, the length of the list is equal to both the number of active nodes and
+    ///   the number of nodes reachable from the front.
+    ///
+    /// [`IntegrityError`]: crate::IntegrityError
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: SinglyList<_> = (0..8).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// assert_eq!(list.check_integrity(), Ok(()));
+    /// assert_eq!(list.slice(idx[2]..idx[5]).check_integrity(), Ok(()));
+    /// ```
+    fn check_integrity(&self) -> Result<(), IntegrityError> {
+        check_integrity(
+            self.col(),
+            self.front_end(),
+            self.back_end(),
+            self.front_end() == self.col().ends().get() && self.back_end().is_none(),
+            |node| node.next().get(),
+            None,
+        )
+    }
 }
 
 impl<L, T, M, P> SinglyEnds<T, M, P> for L
@@ -1,5 +1,5 @@
 use super::SinglyEnds;
-use crate::{Singly, SinglyIdx, list::helper_traits::HasSinglyEndsMut};
+use crate::{Singly, SinglyIdx, iter::SinglyIterPtr, list::helper_traits::HasSinglyEndsMut};
 use orx_pinned_vec::PinnedVec;
 use orx_selfref_col::{MemoryPolicy, Node, NodeIdxError};
 
@@ -33,8 +33,7 @@ where
         M: 'a,
         P: 'a,
     {
-        self.ends_mut()
-            .get()
+        self.front_end()
             .map(|p| unsafe { self.col_mut().data_mut_unchecked(p) })
     }
 
@@ -308,6 +307,83 @@ where
     {
         self.next_idx_of(idx).and_then(|i| self.get_mut(i))
     }
+
+    /// ***O(n)*** Reverses the list (in place) by relinking its nodes.
+    ///
+    /// Elements are not moved in memory; hence, all indices of the list remain valid.
+    ///
+    /// When called on a slice which does not start at the front of the list, the node preceding
+    /// the slice is found by a forward traversal from the front of the list,
+    /// which takes ***O(n)*** time in the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = SinglyList::new();
+    ///
+    /// let e = list.push_front('e');
+    /// list.push_front('d');
+    /// let c = list.push_front('c');
+    /// list.push_front('b');
+    /// let a = list.push_front('a');
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c', 'd', 'e']));
+    ///
+    /// list.reverse();
+    /// assert!(list.eq_to_iter_vals(['e', 'd', 'c', 'b', 'a']));
+    /// assert_eq!(list.get(a), Some(&'a'));
+    ///
+    /// let mut slice = list.slice_mut(&e..=&c);
+    /// assert!(slice.eq_to_iter_vals(['e', 'd', 'c']));
+    ///
+    /// slice.reverse();
+    /// assert!(slice.eq_to_iter_vals(['c', 'd', 'e']));
+    ///
+    /// assert!(list.eq_to_iter_vals(['c', 'd', 'e', 'b', 'a']));
+    /// ```
+    fn reverse(&mut self) {
+        if let Some(front) = self.front_end() {
+            let back = self.back_end();
+
+            // a slice extending to the end of the list has no custom back
+            let next_of_back = back.and_then(|b| self.col().node(b).next().get());
+
+            let col_front = self.col().ends().get();
+            let prev_of_front = match col_front == Some(front) {
+                true => None,
+                false => SinglyIterPtr::new(self.col(), col_front, None)
+                    .find(|p| self.col().node(*p).next().get() == Some(front)),
+            };
+
+            let mut prev = next_of_back;
+            let mut current = front;
+            loop {
+                let next = self.col().node(current).next().get();
+                self.col_mut().node_mut(current).next_mut().set(prev);
+                prev = Some(current);
+
+                match next {
+                    Some(next) if Some(current) != back => current = next,
+                    _ => break,
+                }
+            }
+            let new_front = current;
+
+            // ends
+
+            match prev_of_front {
+                Some(p) => self.col_mut().node_mut(p).next_mut().set_some(new_front),
+                None => self.col_mut().ends_mut().set_some(new_front),
+            }
+
+            self.set_front_end(Some(new_front));
+            if back.is_some() {
+                self.set_back_end(Some(front));
+            }
+        }
+    }
 }
 
 impl<L, T, M, P> SinglyEndsMut<T, M, P> for L
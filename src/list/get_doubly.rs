@@ -1,11 +1,13 @@
 use super::{List, helper_traits::HasDoublyEnds, slice::ListSlice};
 use crate::{DoublyIdx, variant::Doubly};
 use core::ops::RangeBounds;
-use orx_selfref_col::MemoryPolicy;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
 
-impl<T, M> List<Doubly<T>, M>
+impl<T, M, P> List<Doubly<T>, M, P>
 where
     M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
 {
     /// Creates and returns a slice of the list between the given `range` of indices.
     ///
@@ -77,7 +79,7 @@ where
     /// let slice = list.slice(idx[4]..idx[1]);
     /// assert!(slice.eq_to_iter_vals([4, 5, 6, 7, 8, 9]));
     /// ```
-    pub fn slice<R>(&self, range: R) -> ListSlice<'_, Doubly<T>, M>
+    pub fn slice<R>(&self, range: R) -> ListSlice<'_, Doubly<T>, M, P>
     where
         R: RangeBounds<DoublyIdx<T>>,
     {
@@ -0,0 +1,82 @@
+use super::{List, helper_traits::HasSinglyEnds, slice::ListSlice};
+use crate::{SinglyIdx, variant::Singly};
+use core::ops::RangeBounds;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+impl<T, M, P> List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    /// Creates and returns a slice of the list between the given `range` of indices.
+    ///
+    /// Note that a linked list slice itself also behaves like a linked list,
+    /// reflecting the recursive nature of the data type.
+    /// However, it does not own the data.
+    /// It is rather a view, like a slice is a view to a vec.
+    ///
+    /// Singly linked list slices are forward-only.
+    /// Slicing is ***O(1)*** unless the end of the `range` is excluded.
+    /// Since nodes do not know their previous, the last element of a range such as `a..b`
+    /// is found by a forward search from `a`, which requires ***O(n)*** time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of indices of the range bounds is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = SinglyList::new();
+    ///
+    /// list.push_front(4);
+    /// list.push_front(3);
+    /// list.push_front(1);
+    /// list.push_front(7);
+    /// list.push_front(9);
+    ///
+    /// let expected_values = vec![9, 7, 1, 3, 4];
+    ///
+    /// assert!(list.eq_to_iter_refs(&expected_values));
+    /// assert!(list.slice(..).eq_to_iter_refs(&expected_values));
+    ///
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let slice = list.slice(idx[1]..=idx[3]);
+    /// assert_eq!(slice.front(), Some(&7));
+    /// assert_eq!(slice.get(idx[2]), Some(&1));
+    /// assert!(slice.eq_to_iter_vals([7, 1, 3]));
+    ///
+    /// let sum: usize = slice.iter().sum();
+    /// assert_eq!(sum, 11);
+    /// ```
+    ///
+    /// Note that the linked list and its slices are directed.
+    /// Therefore, if the end of the `range` is before the beginning,
+    /// the slice will continue until the end of the list.
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: SinglyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// // a..b where b comes later, hence, we get the slice a..b
+    /// let slice = list.slice(idx[1]..idx[4]);
+    /// assert!(slice.eq_to_iter_vals([1, 2, 3]));
+    ///
+    /// // a..b where b comes earlier, then, we get the slice a..
+    /// let slice = list.slice(idx[4]..idx[1]);
+    /// assert!(slice.eq_to_iter_vals([4, 5, 6, 7, 8, 9]));
+    /// ```
+    pub fn slice<R>(&self, range: R) -> ListSlice<'_, Singly<T>, M, P>
+    where
+        R: RangeBounds<SinglyIdx<T>>,
+    {
+        let ends = self.slice_ends(range).expect("invalid indices in range");
+        ListSlice { col: &self.0, ends }
+    }
+}
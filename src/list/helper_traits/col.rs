@@ -1,6 +1,7 @@
 use crate::variant::ListVariant;
+use core::ops::Bound;
 use orx_pinned_vec::PinnedVec;
-use orx_selfref_col::{MemoryPolicy, Node, NodeIdx, NodePtr, SelfRefCol};
+use orx_selfref_col::{MemoryPolicy, Node, NodeIdx, NodeIdxError, NodePtr, SelfRefCol};
 
 /// Lists and views backed with a self-referential collection..
 pub trait HasCol<V, M, P>
@@ -15,6 +16,14 @@ where
     fn ptr_to_idx(&self, idx: NodePtr<V>) -> NodeIdx<V> {
         NodeIdx::new(self.col().memory_state(), idx)
     }
+
+    fn try_ptr_bound(&self, bound: Bound<&NodeIdx<V>>) -> Result<Bound<NodePtr<V>>, NodeIdxError> {
+        Ok(match bound {
+            Bound::Included(x) => Bound::Included(self.col().try_get_ptr(*x)?),
+            Bound::Excluded(x) => Bound::Excluded(self.col().try_get_ptr(*x)?),
+            Bound::Unbounded => Bound::Unbounded,
+        })
+    }
 }
 
 /// Lists and views backed with a self-referential collection..
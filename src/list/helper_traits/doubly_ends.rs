@@ -1,6 +1,7 @@
 use super::{HasCol, HasColMut};
+use crate::list::iter_traits::chunk_ends::sub_slice_ends;
 use crate::type_aliases::{BACK_IDX, FRONT_IDX};
-use crate::{Doubly, DoublyIdx};
+use crate::{Doubly, DoublyIdx, MoveError, SliceError, iter::DoublyIterPtr};
 use core::ops::RangeBounds;
 use orx_pinned_vec::PinnedVec;
 use orx_selfref_col::{MemoryPolicy, Node, NodeIdxError, NodePtr, Refs, Variant};
@@ -39,16 +40,29 @@ where
     ) -> Result<Option<NodePtr<Doubly<T>>>, NodeIdxError> {
         use core::ops::Bound::*;
 
+        // the slice continues until the back if the end of the range cannot be reached from the front
+        let back = self.ends().get(BACK_IDX);
+        let reachable = |ptr: NodePtr<Doubly<T>>| {
+            DoublyIterPtr::new(self.col(), Some(front), back).any(|p| p == ptr)
+        };
+
         let end = match range.end_bound() {
             Excluded(x) => {
                 let ptr = self.col().try_get_ptr(*x)?;
-                match ptr == front {
-                    false => self.col().node(ptr).prev().get(),
-                    true => None,
+                match (ptr == front, reachable(ptr)) {
+                    (true, _) => None,
+                    (false, true) => self.col().node(ptr).prev().get(),
+                    (false, false) => back,
                 }
             }
-            Included(x) => Some(self.col().try_get_ptr(*x)?),
-            Unbounded => self.ends().get(BACK_IDX),
+            Included(x) => {
+                let ptr = self.col().try_get_ptr(*x)?;
+                match reachable(ptr) {
+                    true => Some(ptr),
+                    false => back,
+                }
+            }
+            Unbounded => back,
         };
 
         Ok(end)
@@ -74,6 +88,38 @@ where
             None => <Doubly<T> as Variant>::Ends::empty(),
         })
     }
+
+    /// Returns the ends of the sub-slice of this list or view within the given `range`;
+    /// returns an error if any of the range bounds is not an element of this list or view.
+    fn sub_slice_ends<R>(&self, range: R) -> Result<<Doubly<T> as Variant>::Ends, SliceError>
+    where
+        R: RangeBounds<DoublyIdx<T>>,
+    {
+        let front = self.ends().get(FRONT_IDX);
+        let back = self.ends().get(BACK_IDX);
+        let ptrs = DoublyIterPtr::new(self.col(), front, back);
+        let begin = self.try_ptr_bound(range.start_bound())?;
+        let end = self.try_ptr_bound(range.end_bound())?;
+        sub_slice_ends(ptrs, begin, end)
+    }
+
+    /// Returns the pointer of the node with the given `idx` provided that it is valid and
+    /// belongs to this list or view.
+    ///
+    /// ***O(1)*** if this is a list or a view covering the entire list; ***O(n)*** otherwise.
+    fn try_get_member_ptr(&self, idx: DoublyIdx<T>) -> Result<NodePtr<Doubly<T>>, MoveError> {
+        let ptr = self.col().try_get_ptr(idx)?;
+
+        let front = self.ends().get(FRONT_IDX);
+        let back = self.ends().get(BACK_IDX);
+        let is_entire_list =
+            front == self.col().ends().get(FRONT_IDX) && back == self.col().ends().get(BACK_IDX);
+
+        match is_entire_list || DoublyIterPtr::new(self.col(), front, back).any(|p| p == ptr) {
+            true => Ok(ptr),
+            false => Err(MoveError::OutOfSlice),
+        }
+    }
 }
 
 /// Lists and views with owned mutable ends.
@@ -105,4 +151,230 @@ where
         self.col_mut().node_mut(prev).next_mut().set_none();
         self.col_mut().node_mut(next).prev_mut().set_none()
     }
+
+    // moves - nodes are assumed to be valid members
+
+    fn move_next_to_ptr(&mut self, mid: NodePtr<Doubly<T>>, prev: NodePtr<Doubly<T>>) {
+        if mid == prev {
+            return;
+        }
+
+        let next = self.col().node(prev).next().get();
+        let old_next = self.col().node(mid).next().get();
+        let old_prev = self.col().node(mid).prev().get();
+
+        // update the gap
+        match (old_prev, old_next) {
+            (Some(old_prev), _) if old_prev == prev => return,
+            (Some(old_prev), Some(old_next)) => self.link(old_prev, old_next),
+            (Some(old_prev), None) => {
+                // idx must be col.back
+                self.col_mut().node_mut(old_prev).next_mut().set_none();
+
+                self.col_mut().ends_mut().set_some(BACK_IDX, old_prev);
+            }
+            (None, Some(old_next)) => {
+                // idx must be col.front
+                self.col_mut().node_mut(old_next).prev_mut().set_none();
+
+                self.col_mut().ends_mut().set_some(FRONT_IDX, old_next);
+            }
+            (None, None) => return,
+        }
+
+        // update the fill
+        match next {
+            Some(next) => self.link(mid, next),
+            None => self.col_mut().node_mut(mid).next_mut().set_none(),
+        }
+        self.link(prev, mid);
+
+        // custom ends
+        let old_front = self.ends().get(FRONT_IDX);
+        let old_back = self.ends().get(BACK_IDX);
+
+        if let Some(old_back) = old_back {
+            match old_back == prev {
+                true => {
+                    // new node placed in front
+                    self.ends_mut().set_some(BACK_IDX, mid)
+                }
+                false => {
+                    if old_back == mid {
+                        // old front is moved away
+                        let old_front = old_front.expect("exists");
+                        match mid == old_front {
+                            false => {
+                                let new_back = old_prev.expect("exists");
+                                self.ends_mut().set_some(BACK_IDX, new_back);
+                            }
+                            true => { /* singleton, no update */ }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(old_front) = old_front
+            && old_front == mid
+        {
+            // old back is moved away
+            let old_back = old_back.expect("exists");
+            match old_front == old_back {
+                false => {
+                    let new_front = old_next.expect("exists");
+                    self.ends_mut().set_some(FRONT_IDX, new_front);
+                }
+                true => { /* singleton, no update */ }
+            }
+        }
+    }
+
+    fn move_prev_to_ptr(&mut self, mid: NodePtr<Doubly<T>>, next: NodePtr<Doubly<T>>) {
+        if mid == next {
+            return;
+        }
+
+        let prev = self.col().node(next).prev().get();
+        let old_next = self.col().node(mid).next().get();
+        let old_prev = self.col().node(mid).prev().get();
+
+        // update the gap
+        match (old_prev, old_next) {
+            (_, Some(old_next)) if old_next == next => return,
+            (Some(old_prev), Some(old_next)) => self.link(old_prev, old_next),
+            (Some(old_prev), None) => {
+                // idx must be col.back
+                self.col_mut().node_mut(old_prev).next_mut().set_none();
+
+                self.col_mut().ends_mut().set_some(BACK_IDX, old_prev);
+            }
+            (None, Some(old_next)) => {
+                // idx must be col.front
+                self.col_mut().node_mut(old_next).prev_mut().set_none();
+
+                self.col_mut().ends_mut().set_some(FRONT_IDX, old_next);
+            }
+            (None, None) => return,
+        }
+
+        // update the fill
+        match prev {
+            Some(prev) => self.link(prev, mid),
+            None => self.col_mut().node_mut(mid).prev_mut().set_none(),
+        }
+        self.link(mid, next);
+
+        // custom ends
+        let old_front = self.ends().get(FRONT_IDX);
+        let old_back = self.ends().get(BACK_IDX);
+
+        if let Some(old_front) = &old_front {
+            match old_front == &next {
+                true => {
+                    // new node placed in front
+                    self.ends_mut().set_some(FRONT_IDX, mid)
+                }
+                false => {
+                    if old_front == &mid {
+                        // old front is moved away
+                        let old_back = old_back.expect("exists");
+                        match mid == old_back {
+                            false => {
+                                let new_front = old_next.expect("exists");
+                                self.ends_mut().set_some(FRONT_IDX, new_front);
+                            }
+                            true => { /* singleton, no update */ }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(old_back) = old_back
+            && old_back == mid
+        {
+            // old back is moved away
+            let old_front = old_front.expect("exists");
+            match old_front == old_back {
+                false => {
+                    let new_back = old_prev.expect("exists");
+                    self.ends_mut().set_some(BACK_IDX, new_back);
+                }
+                true => { /* singleton, no update */ }
+            }
+        }
+    }
+
+    fn swap_ptr(&mut self, a: NodePtr<Doubly<T>>, b: NodePtr<Doubly<T>>) {
+        if a == b {
+            return;
+        }
+
+        let p_a = self.col().node(a).prev().get();
+        let p_b = self.col().node(b).prev().get();
+        let n_a = self.col().node(a).next().get();
+        let n_b = self.col().node(b).next().get();
+
+        match (n_a, n_b) {
+            (Some(n_a), _) if b == n_a => self.move_next_to_ptr(a, b),
+            (_, Some(n_b)) if a == n_b => self.move_next_to_ptr(b, a),
+            _ => {
+                match p_a {
+                    Some(p_a) => self.link(p_a, b),
+                    None => self.col_mut().node_mut(b).prev_mut().set_none(),
+                }
+
+                match p_b {
+                    Some(p_b) => self.link(p_b, a),
+                    None => self.col_mut().node_mut(a).prev_mut().set_none(),
+                }
+
+                match n_a {
+                    Some(n_a) => self.link(b, n_a),
+                    None => self.col_mut().node_mut(b).next_mut().set_none(),
+                }
+
+                match n_b {
+                    Some(n_b) => self.link(a, n_b),
+                    None => self.col_mut().node_mut(a).next_mut().set_none(),
+                }
+
+                // cache custom ends
+                let custom_front = match self.ends().get(FRONT_IDX) {
+                    Some(x) if x == a => Some(b),
+                    Some(x) if x == b => Some(a),
+                    _ => None,
+                };
+
+                let custom_back = match self.ends().get(BACK_IDX) {
+                    Some(x) if x == a => Some(b),
+                    Some(x) if x == b => Some(a),
+                    _ => None,
+                };
+
+                // update col ends
+                match self.col().ends().get(FRONT_IDX) {
+                    Some(x) if x == a => self.col_mut().ends_mut().set_some(FRONT_IDX, b),
+                    Some(x) if x == b => self.col_mut().ends_mut().set_some(FRONT_IDX, a),
+                    _ => {}
+                }
+
+                match self.col().ends().get(BACK_IDX) {
+                    Some(x) if x == a => self.col_mut().ends_mut().set_some(BACK_IDX, b),
+                    Some(x) if x == b => self.col_mut().ends_mut().set_some(BACK_IDX, a),
+                    _ => {}
+                }
+
+                // update custom ends
+                if let Some(new_front) = custom_front {
+                    self.ends_mut().set_some(FRONT_IDX, new_front);
+                }
+
+                if let Some(new_back) = custom_back {
+                    self.ends_mut().set_some(BACK_IDX, new_back);
+                }
+            }
+        }
+    }
 }
@@ -1,7 +1,10 @@
 use super::{HasCol, HasColMut};
-use crate::Singly;
+use crate::list::iter_traits::chunk_ends::sub_slice_ends;
+use crate::type_aliases::{BACK_IDX, FRONT_IDX};
+use crate::{Singly, SinglyIdx, SliceError, iter::SinglyIterPtr};
+use core::ops::RangeBounds;
 use orx_pinned_vec::PinnedVec;
-use orx_selfref_col::{MemoryPolicy, Node, Variant};
+use orx_selfref_col::{MemoryPolicy, Node, NodeIdxError, NodePtr, Refs, RefsArray};
 
 /// Lists and views with owned ends.
 pub trait HasSinglyEnds<T, M, P>: HasCol<Singly<T>, M, P>
@@ -9,8 +12,95 @@ where
     M: MemoryPolicy<Singly<T>>,
     P: PinnedVec<Node<Singly<T>>>,
 {
-    /// Returns a reference to the ends of the linked list.
-    fn ends(&self) -> &<Singly<T> as Variant>::Ends;
+    /// Returns the pointer to the front of the linked list or view; None if it is empty.
+    fn front_end(&self) -> Option<NodePtr<Singly<T>>>;
+
+    /// Returns the pointer to the back of the view; None if it extends to the end of the linked list.
+    ///
+    /// A singly linked list itself only keeps track of its front; hence, its back end is always None.
+    fn back_end(&self) -> Option<NodePtr<Singly<T>>>;
+
+    /// Returns the pointer to the first node of the given `range`; None if the range starts after the back.
+    fn range_start<R: RangeBounds<SinglyIdx<T>>>(
+        &self,
+        range: &R,
+    ) -> Result<Option<NodePtr<Singly<T>>>, NodeIdxError> {
+        use core::ops::Bound::*;
+
+        let begin = match range.start_bound() {
+            Excluded(x) => {
+                let ptr = self.col().try_get_ptr(*x)?;
+                match self.back_end() == Some(ptr) {
+                    false => self.col().node(ptr).next().get(),
+                    true => None,
+                }
+            }
+            Included(x) => Some(self.col().try_get_ptr(*x)?),
+            Unbounded => self.front_end(),
+        };
+
+        Ok(begin)
+    }
+
+    /// Returns the ends of the slice within the given `range`.
+    ///
+    /// Since nodes do not know their previous, the last node of a range with an excluded end
+    /// is found by a forward search from the beginning of the range.
+    /// If the end of the range cannot be reached from its beginning, the slice extends to the
+    /// end of this list or view.
+    fn slice_ends<R>(&self, range: R) -> Result<RefsArray<2, Singly<T>>, NodeIdxError>
+    where
+        R: RangeBounds<SinglyIdx<T>>,
+    {
+        use core::ops::Bound::*;
+
+        let mut ends = RefsArray::empty();
+
+        if let Some(front) = self.range_start(&range)? {
+            let back = match range.end_bound() {
+                Excluded(x) => {
+                    let ptr = self.col().try_get_ptr(*x)?;
+                    match ptr == front {
+                        true => return Ok(ends),
+                        false => {
+                            let last = self.back_end();
+                            SinglyIterPtr::new(self.col(), Some(front), last)
+                                .find(|p| self.col().node(*p).next().get() == Some(ptr))
+                                .or(last)
+                        }
+                    }
+                }
+                Included(x) => {
+                    let ptr = self.col().try_get_ptr(*x)?;
+                    let last = self.back_end();
+                    match SinglyIterPtr::new(self.col(), Some(front), last).any(|p| p == ptr) {
+                        true => Some(ptr),
+                        false => last,
+                    }
+                }
+                Unbounded => self.back_end(),
+            };
+
+            ends.set_some(FRONT_IDX, front);
+            ends.set(BACK_IDX, back);
+        }
+
+        Ok(ends)
+    }
+
+    /// Returns the ends of the sub-slice of this list or view within the given `range`;
+    /// returns an error if any of the range bounds is not an element of this list or view.
+    fn sub_slice_ends<R>(&self, range: R) -> Result<RefsArray<2, Singly<T>>, SliceError>
+    where
+        R: RangeBounds<SinglyIdx<T>>,
+    {
+        let front = self.front_end();
+        let back = self.back_end();
+        let ptrs = SinglyIterPtr::new(self.col(), front, back);
+        let begin = self.try_ptr_bound(range.start_bound())?;
+        let end = self.try_ptr_bound(range.end_bound())?;
+        sub_slice_ends(ptrs, begin, end)
+    }
 }
 
 /// Lists and views with owned mutable ends.
@@ -19,6 +109,11 @@ where
     M: MemoryPolicy<Singly<T>>,
     P: PinnedVec<Node<Singly<T>>>,
 {
-    /// Returns a mutable reference to the ends of the linked list.
-    fn ends_mut(&mut self) -> &mut <Singly<T> as Variant>::Ends;
+    /// Sets the front of the linked list or view.
+    fn set_front_end(&mut self, front: Option<NodePtr<Singly<T>>>);
+
+    /// Sets the back of the view.
+    ///
+    /// A singly linked list itself does not keep track of its back; hence, the list ignores this call.
+    fn set_back_end(&mut self, back: Option<NodePtr<Singly<T>>>);
 }
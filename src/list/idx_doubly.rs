@@ -3,11 +3,14 @@ use crate::{
     type_aliases::{BACK_IDX, FRONT_IDX, IDX_ERR},
     variant::Doubly,
 };
-use orx_selfref_col::{MemoryPolicy, NodeIdx, NodeIdxError};
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node, NodeIdx, NodeIdxError, NodePtr};
 
-impl<T, M> List<Doubly<T>, M>
+impl<T, M, P> List<Doubly<T>, M, P>
 where
     M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
 {
     // mut
 
@@ -57,7 +60,13 @@ where
 
         self.0.close_and_reclaim(idx)
     }
+}
 
+impl<T, M, P> List<Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
     /// ***O(1)*** Inserts the given `value` as the next of the node with the given `idx`.
     ///
     /// # Panics
@@ -87,6 +96,17 @@ where
     ///```
     pub fn insert_next_to(&mut self, idx: DoublyIdx<T>, value: T) -> DoublyIdx<T> {
         let prev = self.0.try_get_ptr(idx).expect(IDX_ERR);
+        let idx = self.insert_next_to_ptr(prev, value);
+        NodeIdx::new(self.memory_state(), idx)
+    }
+
+    /// Inserts the given `value` as the next of the `prev` node, which must be an active node of the list;
+    /// and returns the pointer of the inserted node.
+    pub(crate) fn insert_next_to_ptr(
+        &mut self,
+        prev: NodePtr<Doubly<T>>,
+        value: T,
+    ) -> NodePtr<Doubly<T>> {
         let next = self.0.node(prev).next().get();
         let idx = self.0.push(value);
 
@@ -101,7 +121,7 @@ where
             None => self.0.ends_mut().set_some(BACK_IDX, idx),
         }
 
-        NodeIdx::new(self.memory_state(), idx)
+        idx
     }
 
     /// ***O(1)*** Inserts the given `value` as the next of the node with the given `idx`.
@@ -134,6 +154,17 @@ where
     ///```
     pub fn insert_prev_to(&mut self, idx: DoublyIdx<T>, value: T) -> DoublyIdx<T> {
         let next = self.0.try_get_ptr(idx).expect(IDX_ERR);
+        let idx = self.insert_prev_to_ptr(next, value);
+        NodeIdx::new(self.memory_state(), idx)
+    }
+
+    /// Inserts the given `value` as the prev of the `next` node, which must be an active node of the list;
+    /// and returns the pointer of the inserted node.
+    pub(crate) fn insert_prev_to_ptr(
+        &mut self,
+        next: NodePtr<Doubly<T>>,
+        value: T,
+    ) -> NodePtr<Doubly<T>> {
         let prev = self.0.node(next).prev().get();
         let idx = self.0.push(value);
 
@@ -148,9 +179,78 @@ where
             None => self.0.ends_mut().set_some(FRONT_IDX, idx),
         }
 
-        NodeIdx::new(self.memory_state(), idx)
+        idx
+    }
+
+    /// ***O(n)*** Inserts the elements of the `iter` in order, immediately after the node with the given `idx`;
+    /// and returns the indices of the inserted nodes, in the order of the `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics:
+    /// * if the `idx` is invalid (`idx_err` is not None for the index),
+    /// * if the element with the given `idx` is already removed from the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = ['a', 'b', 'c'].into_iter().collect();
+    /// let b = list.idx_of(&'b').unwrap();
+    ///
+    /// let idx = list.insert_iter_next_to(b, ['x', 'y', 'z']);
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'x', 'y', 'z', 'c']));
+    ///
+    /// assert_eq!(list.get(idx[2]), Some(&'z'));
+    /// assert!(list.slice(idx[0]..=idx[2]).eq_to_iter_vals(['x', 'y', 'z']));
+    /// ```
+    pub fn insert_iter_next_to<I>(&mut self, idx: DoublyIdx<T>, iter: I) -> Vec<DoublyIdx<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let prev = self.0.try_get_ptr(idx).expect(IDX_ERR);
+        let next = self.0.node(prev).next().get();
+        self.insert_iter_between(Some(prev), next, iter)
+    }
+
+    /// ***O(n)*** Inserts the elements of the `iter` in order, immediately before the node with the given `idx`;
+    /// and returns the indices of the inserted nodes, in the order of the `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics:
+    /// * if the `idx` is invalid (`idx_err` is not None for the index),
+    /// * if the element with the given `idx` is already removed from the list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = ['a', 'b', 'c'].into_iter().collect();
+    /// let b = list.idx_of(&'b').unwrap();
+    ///
+    /// let idx = list.insert_iter_prev_to(b, ['x', 'y', 'z']);
+    /// assert!(list.eq_to_iter_vals(['a', 'x', 'y', 'z', 'b', 'c']));
+    ///
+    /// assert_eq!(list.get(idx[0]), Some(&'x'));
+    /// assert!(list.slice(idx[0]..b).eq_to_iter_vals(['x', 'y', 'z']));
+    /// ```
+    pub fn insert_iter_prev_to<I>(&mut self, idx: DoublyIdx<T>, iter: I) -> Vec<DoublyIdx<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let next = self.0.try_get_ptr(idx).expect(IDX_ERR);
+        let prev = self.0.node(next).prev().get();
+        self.insert_iter_between(prev, Some(next), iter)
     }
+}
 
+impl<T, M> List<Doubly<T>, M>
+where
+    M: MemoryPolicy<Doubly<T>>,
+{
     /// ***O(1)*** Removes and returns value at the given `idx` of the list.
     ///
     /// Does not change the list and returns None:
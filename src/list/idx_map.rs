@@ -0,0 +1,167 @@
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryState, Node, NodeIdx, NodePtr, Variant};
+
+/// A mapping from the indices of a list to the indices of the list that it is converted into.
+///
+/// Conversions such as [`into_doubly`], [`into_singly`] or [`into_pinned_vec`] consume the source list
+/// and create a new list with a different node layout or storage.
+/// Indices obtained from the source list are not valid for the new list;
+/// however, they can be translated by this map.
+///
+/// The map identifies an index by the position of its node in the storage of the source list.
+/// Therefore, indices of other lists, which do not point into the storage of the source list, are not mapped.
+///
+/// [`into_doubly`]: crate::List::into_doubly
+/// [`into_singly`]: crate::List::into_singly
+/// [`into_pinned_vec`]: crate::List::into_pinned_vec
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_linked_list::*;
+///
+/// let mut singly = SinglyList::new();
+/// let c = singly.push_front('c');
+/// let b = singly.push_front('b');
+/// let a = singly.push_front('a');
+///
+/// let (doubly, idx_map): (DoublyList<_>, _) = singly.into_doubly();
+///
+/// let b = idx_map.get(b).unwrap();
+/// assert_eq!(doubly.get(b), Some(&'b'));
+/// assert_eq!(doubly.prev_of(b), Some(&'a'));
+/// assert_eq!(doubly.next_of(b), Some(&'c'));
+/// ```
+pub struct IdxMap<V1, V2 = V1>
+where
+    V1: Variant,
+    V2: Variant,
+{
+    state: MemoryState,
+    fragments: Vec<Fragment>,
+    entries: Vec<Option<NodeIdx<V2>>>,
+    len: usize,
+    phantom: core::marker::PhantomData<V1>,
+}
+
+impl<V1, V2> IdxMap<V1, V2>
+where
+    V1: Variant,
+    V2: Variant,
+{
+    /// Creates the map from pairs of node pointers of the source collection with the given memory
+    /// `state` and storage `fragments` and the corresponding indices of the target collection.
+    pub(crate) fn new<I>(state: MemoryState, fragments: Vec<Fragment>, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (NodePtr<V1>, NodeIdx<V2>)>,
+    {
+        let num_positions = fragments.iter().map(|x| x.len).sum();
+        let mut map = Self {
+            state,
+            fragments,
+            entries: alloc::vec![None; num_positions],
+            len: 0,
+            phantom: Default::default(),
+        };
+        for (ptr, idx) in pairs {
+            if let Some(position) = map.position_of(ptr) {
+                map.entries[position] = Some(idx);
+                map.len += 1;
+            }
+        }
+        map
+    }
+
+    /// Returns the position of the node at the `ptr` in the storage of the source collection;
+    /// None if the pointer does not point to a node of the source storage.
+    fn position_of(&self, ptr: NodePtr<V1>) -> Option<usize> {
+        let node_size = core::mem::size_of::<Node<V1>>();
+        let address = address(ptr);
+        let f = match self.fragments.binary_search_by_key(&address, |x| x.begin) {
+            Ok(f) => f,
+            Err(0) => return None,
+            Err(f) => f - 1,
+        };
+        let fragment = &self.fragments[f];
+        let offset = address - fragment.begin;
+        match offset.is_multiple_of(node_size) && offset / node_size < fragment.len {
+            true => Some(fragment.position + offset / node_size),
+            false => None,
+        }
+    }
+
+    /// ***O(log f)*** Returns the index in the new list corresponding to the index `idx` of the source list,
+    /// where f is the number of fragments of the source storage;
+    /// returns None if `idx` was not a valid index of the source list at the time of the conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut doubly: DoublyList<_> = (0..4).collect();
+    /// let idx: Vec<_> = doubly.indices().collect();
+    /// doubly.remove(idx[2]);
+    ///
+    /// let (singly, idx_map): (SinglyList<_>, _) = doubly.into_singly();
+    ///
+    /// assert_eq!(singly.get(idx_map.get(idx[3]).unwrap()), Some(&3));
+    /// assert_eq!(idx_map.get(idx[2]), None);
+    /// ```
+    pub fn get(&self, idx: NodeIdx<V1>) -> Option<NodeIdx<V2>> {
+        match idx.is_in_state(self.state) {
+            true => self
+                .position_of(idx.node_ptr())
+                .and_then(|position| self.entries[position]),
+            false => None,
+        }
+    }
+
+    /// Returns the number of indices in the map, which is equal to the length of the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the map is empty, which is the case when the converted list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+fn address<V: Variant>(ptr: NodePtr<V>) -> usize {
+    // SAFETY: the pointer is never dereferenced, it is only used as a key
+    unsafe { ptr.ptr() as usize }
+}
+
+/// A contiguous fragment of the node storage of the source collection.
+#[derive(Clone, Copy)]
+pub(crate) struct Fragment {
+    begin: usize,
+    len: usize,
+    position: usize,
+}
+
+/// Returns the fragments of the node storage `nodes`, sorted by their addresses.
+pub(crate) fn storage_fragments<V, P>(nodes: &P) -> Vec<Fragment>
+where
+    V: Variant,
+    P: PinnedVec<Node<V>>,
+{
+    let mut position = 0;
+    let mut fragments: Vec<_> = nodes
+        .slices(..)
+        .into_iter()
+        .map(|slice| {
+            let fragment = Fragment {
+                begin: slice.as_ptr() as usize,
+                len: slice.len(),
+                position,
+            };
+            position += slice.len();
+            fragment
+        })
+        .collect();
+    fragments.sort_unstable_by_key(|x| x.begin);
+    fragments
+}
@@ -0,0 +1,47 @@
+use crate::CapacityError;
+use core::fmt::{Debug, Display};
+use orx_selfref_col::NodeIdxError;
+
+/// Error cases of fallible insertions to a list with a fixed capacity which are positioned by an index
+/// or by a position, such as `try_insert_at` or `try_insert_next_to_within_capacity`.
+///
+/// The value that could not be inserted is given back to the caller in every case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertError<T> {
+    /// NoCapacity => The fixed capacity of the list is exhausted; the wrapped error holds the value.
+    NoCapacity(CapacityError<T>),
+    /// OutOfBounds => The position to insert the value at is greater than the length of the list.
+    OutOfBounds(T),
+    /// InvalidIdx => The node index to insert the value next to or prev to is invalid for the list;
+    /// the wrapped error gives the reason.
+    InvalidIdx(NodeIdxError, T),
+}
+
+impl<T> InsertError<T> {
+    /// Consumes the error and returns the value that could not be inserted.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::NoCapacity(error) => error.into_inner(),
+            Self::OutOfBounds(value) => value,
+            Self::InvalidIdx(_, value) => value,
+        }
+    }
+}
+
+impl<T> Display for InsertError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoCapacity(error) => write!(f, "{error}"),
+            Self::OutOfBounds(_) => {
+                write!(f, "InsertError: the position is out of bounds of the list")
+            }
+            Self::InvalidIdx(error, _) => write!(f, "InsertError: {error:?}"),
+        }
+    }
+}
+
+impl<T> From<CapacityError<T>> for InsertError<T> {
+    fn from(value: CapacityError<T>) -> Self {
+        Self::NoCapacity(value)
+    }
+}
@@ -0,0 +1,116 @@
+use super::{
+    List,
+    common_traits::from_iter::{doubly_from_vec, singly_from_vec},
+    idx_map::{IdxMap, storage_fragments},
+};
+use crate::{Doubly, DoublyIterable, Singly, SinglyIterable};
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+impl<T, M, P> List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    /// ***O(n)*** Converts the list into a list with the same elements in the same order and the same memory policy,
+    /// but using `P2` as the underlying pinned vector;
+    /// and returns it together with the [`IdxMap`] translating indices of this list into indices of the new list.
+    ///
+    /// The elements are moved into the new storage; they are not cloned.
+    ///
+    /// Any pinned vector that can be created from a `Vec` can be the target, such as
+    /// `FixedVec` or `SplitVec` with `Doubling`, `Recursive` or `Linear` growth.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    /// use orx_fixed_vec::FixedVec;
+    /// use orx_split_vec::{Doubling, SplitVec};
+    ///
+    /// let mut list = SinglyList::with_fixed_capacity(3);
+    /// let c = list.push_front('c');
+    /// let b = list.push_front('b');
+    /// let a = list.push_front('a');
+    ///
+    /// // the fixed capacity is full, migrate to a growable storage
+    /// let (mut list, idx_map) = list.into_pinned_vec::<SplitVec<_, Doubling>>();
+    /// list.push_front('x');
+    /// assert!(list.eq_to_iter_vals(['x', 'a', 'b', 'c']));
+    ///
+    /// let b = idx_map.get(b).unwrap();
+    /// assert_eq!(list.get(b), Some(&'b'));
+    ///
+    /// // and back to a fixed storage once the list is frozen
+    /// let (list, _) = list.into_pinned_vec::<FixedVec<_>>();
+    /// assert!(list.eq_to_iter_vals(['x', 'a', 'b', 'c']));
+    /// ```
+    pub fn into_pinned_vec<P2>(self) -> (List<Singly<T>, M, P2>, IdxMap<Singly<T>>)
+    where
+        P2: PinnedVec<Node<Singly<T>>> + From<Vec<Node<Singly<T>>>>,
+    {
+        let state = self.memory_state();
+        let fragments = storage_fragments(self.0.nodes());
+        let ptrs: Vec<_> = self.iter_ptr().collect();
+
+        let list: List<Singly<T>, M, P2> = singly_from_vec(self);
+
+        let idx_map = IdxMap::new(state, fragments, ptrs.into_iter().zip(list.indices()));
+        (list, idx_map)
+    }
+}
+
+impl<T, M, P> List<Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    /// ***O(n)*** Converts the list into a list with the same elements in the same order and the same memory policy,
+    /// but using `P2` as the underlying pinned vector;
+    /// and returns it together with the [`IdxMap`] translating indices of this list into indices of the new list.
+    ///
+    /// The elements are moved into the new storage; they are not cloned.
+    ///
+    /// Any pinned vector that can be created from a `Vec` can be the target, such as
+    /// `FixedVec` or `SplitVec` with `Doubling`, `Recursive` or `Linear` growth.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    /// use orx_fixed_vec::FixedVec;
+    /// use orx_split_vec::{Recursive, SplitVec};
+    ///
+    /// let mut list = DoublyList::with_fixed_capacity(3);
+    /// list.push_back('b');
+    /// let c = list.push_back('c');
+    /// list.push_front('a');
+    ///
+    /// // the fixed capacity is full, migrate to a growable storage
+    /// let (mut list, idx_map) = list.into_pinned_vec::<SplitVec<_, Recursive>>();
+    /// list.push_back('d');
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c', 'd']));
+    ///
+    /// let c = idx_map.get(c).unwrap();
+    /// assert_eq!(list.prev_of(c), Some(&'b'));
+    /// assert_eq!(list.next_of(c), Some(&'d'));
+    ///
+    /// // and back to a fixed storage once the list is frozen
+    /// let (list, _) = list.into_pinned_vec::<FixedVec<_>>();
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c', 'd']));
+    /// ```
+    pub fn into_pinned_vec<P2>(self) -> (List<Doubly<T>, M, P2>, IdxMap<Doubly<T>>)
+    where
+        P2: PinnedVec<Node<Doubly<T>>> + From<Vec<Node<Doubly<T>>>>,
+    {
+        let state = self.memory_state();
+        let fragments = storage_fragments(self.0.nodes());
+        let ptrs: Vec<_> = self.iter_ptr().collect();
+
+        let list: List<Doubly<T>, M, P2> = doubly_from_vec(self);
+
+        let idx_map = IdxMap::new(state, fragments, ptrs.into_iter().zip(list.indices()));
+        (list, idx_map)
+    }
+}
@@ -0,0 +1,117 @@
+use super::{
+    List,
+    common_traits::from_iter::{doubly_from_vec, singly_from_vec},
+    idx_map::{IdxMap, storage_fragments},
+};
+use crate::{
+    Doubly, DoublyIterable, DoublyToSinglyIdxMap, Singly, SinglyIterable, SinglyToDoublyIdxMap,
+};
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+impl<T, M, P> List<Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    /// ***O(n)*** Converts the singly linked list into a doubly linked list with the same elements in the same order;
+    /// and returns it together with the [`IdxMap`] translating indices of this list into indices of the doubly linked list.
+    ///
+    /// The storage of this list cannot be reused: a doubly node additionally holds the reference to its previous node,
+    /// so it does not fit into the slot of a singly node. Therefore, the elements are moved into a new storage;
+    /// they are not cloned.
+    ///
+    /// The memory policy and pinned vector of the doubly linked list can be any,
+    /// and are determined by type inference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut singly = SinglyList::new();
+    /// let c = singly.push_front('c');
+    /// let b = singly.push_front('b');
+    /// let a = singly.push_front('a');
+    ///
+    /// let (doubly, idx_map): (DoublyList<_>, _) = singly.into_doubly();
+    /// assert!(doubly.eq_to_iter_vals(['a', 'b', 'c']));
+    ///
+    /// let c = idx_map.get(c).unwrap();
+    /// assert_eq!(doubly.get(c), Some(&'c'));
+    /// assert_eq!(doubly.prev_of(c), Some(&'b'));
+    ///
+    /// // any memory policy
+    /// let singly: SinglyListLazy<_> = (0..3).collect::<SinglyList<_>>().into_lazy_reclaim();
+    /// let (doubly, _): (DoublyListLazy<_>, _) = singly.into_doubly();
+    /// assert!(doubly.eq_to_iter_vals([0, 1, 2]));
+    /// ```
+    pub fn into_doubly<M2, P2>(self) -> (List<Doubly<T>, M2, P2>, SinglyToDoublyIdxMap<T>)
+    where
+        M2: MemoryPolicy<Doubly<T>>,
+        P2: PinnedVec<Node<Doubly<T>>> + From<Vec<Node<Doubly<T>>>>,
+    {
+        let state = self.memory_state();
+        let fragments = storage_fragments(self.0.nodes());
+        let ptrs: Vec<_> = self.iter_ptr().collect();
+
+        let list: List<Doubly<T>, M2, P2> = doubly_from_vec(self);
+
+        let idx_map = IdxMap::new(state, fragments, ptrs.into_iter().zip(list.indices()));
+        (list, idx_map)
+    }
+}
+
+impl<T, M, P> List<Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    /// ***O(n)*** Converts the doubly linked list into a singly linked list with the same elements in the same order;
+    /// and returns it together with the [`IdxMap`] translating indices of this list into indices of the singly linked list.
+    ///
+    /// The storage of this list cannot be reused: a singly node is smaller than a doubly node, and pinned vectors
+    /// cannot reinterpret their memory as a vector of a different element type. Therefore, the elements are moved
+    /// into a new storage; they are not cloned.
+    ///
+    /// The memory policy and pinned vector of the singly linked list can be any,
+    /// and are determined by type inference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut doubly = DoublyList::new();
+    /// let b = doubly.push_back('b');
+    /// let c = doubly.push_back('c');
+    /// let a = doubly.push_front('a');
+    ///
+    /// let (singly, idx_map): (SinglyList<_>, _) = doubly.into_singly();
+    /// assert!(singly.eq_to_iter_vals(['a', 'b', 'c']));
+    ///
+    /// let b = idx_map.get(b).unwrap();
+    /// assert_eq!(singly.get(b), Some(&'b'));
+    /// assert_eq!(singly.next_of(b), Some(&'c'));
+    ///
+    /// // any memory policy
+    /// let doubly: DoublyListLazy<_> = (0..3).collect::<DoublyList<_>>().into_lazy_reclaim();
+    /// let (singly, _): (SinglyListLazy<_>, _) = doubly.into_singly();
+    /// assert!(singly.eq_to_iter_vals([0, 1, 2]));
+    /// ```
+    pub fn into_singly<M2, P2>(self) -> (List<Singly<T>, M2, P2>, DoublyToSinglyIdxMap<T>)
+    where
+        M2: MemoryPolicy<Singly<T>>,
+        P2: PinnedVec<Node<Singly<T>>> + From<Vec<Node<Singly<T>>>>,
+    {
+        let state = self.memory_state();
+        let fragments = storage_fragments(self.0.nodes());
+        let ptrs: Vec<_> = self.iter_ptr().collect();
+
+        let list: List<Singly<T>, M2, P2> = singly_from_vec(self);
+
+        let idx_map = IdxMap::new(state, fragments, ptrs.into_iter().zip(list.indices()));
+        (list, idx_map)
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{
+    SliceError,
+    type_aliases::{BACK_IDX, FRONT_IDX},
+};
+use core::ops::{Bound, RangeBounds};
+use orx_selfref_col::{NodePtr, Refs, RefsArray, Variant};
+
+/// Groups the consecutive pointers of `ptrs` into chunks of at most `chunk_size` nodes;
+/// and yields the first and last pointers of each chunk.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is zero.
+pub(crate) fn chunk_ends<V, I>(
+    mut ptrs: I,
+    chunk_size: usize,
+) -> impl Iterator<Item = (NodePtr<V>, NodePtr<V>)>
+where
+    V: Variant,
+    I: Iterator<Item = NodePtr<V>>,
+{
+    assert!(chunk_size > 0, "chunk size must be positive");
+
+    core::iter::from_fn(move || {
+        let front = ptrs.next()?;
+        let back = ptrs.by_ref().take(chunk_size - 1).last().unwrap_or(front);
+        Some((front, back))
+    })
+}
+
+/// Yields the first and last pointers of every window of `window_size` consecutive nodes of `ptrs`.
+///
+/// # Panics
+///
+/// Panics if `window_size` is zero.
+pub(crate) fn window_ends<V, I>(
+    ptrs: I,
+    window_size: usize,
+) -> impl Iterator<Item = (NodePtr<V>, NodePtr<V>)>
+where
+    V: Variant,
+    I: Iterator<Item = NodePtr<V>> + Clone,
+{
+    assert!(window_size > 0, "window size must be positive");
+
+    ptrs.clone().zip(ptrs.skip(window_size - 1))
+}
+
+/// Creates ends of a slice from `front` to `back`, both inclusive.
+pub(crate) fn slice_ends<V: Variant>(front: NodePtr<V>, back: NodePtr<V>) -> RefsArray<2, V> {
+    let mut ends = RefsArray::empty();
+    ends.set_some(FRONT_IDX, front);
+    ends.set_some(BACK_IDX, back);
+    ends
+}
+
+/// Creates ends of the slice of `ptrs` between positions `begin` (inclusive) and `end` (exclusive).
+pub(crate) fn position_ends<V, I>(ptrs: I, begin: usize, end: usize) -> RefsArray<2, V>
+where
+    V: Variant,
+    I: Iterator<Item = NodePtr<V>>,
+{
+    let mut ptrs = ptrs.skip(begin).take(end.saturating_sub(begin));
+    match ptrs.next() {
+        Some(front) => slice_ends(front, ptrs.last().unwrap_or(front)),
+        None => RefsArray::empty(),
+    }
+}
+
+/// Converts the positional `range` into `begin` (inclusive) and `end` (exclusive) positions
+/// within a sequence of `len` elements.
+///
+/// # Panics
+///
+/// Panics if the range is out of bounds, or if it starts after it ends.
+pub(crate) fn positions<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let begin = match range.start_bound() {
+        Bound::Included(x) => *x,
+        Bound::Excluded(x) => x.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(x) => x.saturating_add(1),
+        Bound::Excluded(x) => *x,
+        Bound::Unbounded => len,
+    };
+
+    assert!(begin <= end, "range starts at {begin} but ends at {end}");
+    assert!(
+        end <= len,
+        "range end {end} is out of bounds of length {len}"
+    );
+
+    (begin, end)
+}
+
+/// Creates ends of the sub-slice of `ptrs` between the `begin` and `end` node bounds.
+///
+/// Returns an error if any of the bound nodes is not among `ptrs`.
+/// If the end bound is before the begin bound, the sub-slice extends to the end of `ptrs`.
+pub(crate) fn sub_slice_ends<V, I>(
+    ptrs: I,
+    begin: Bound<NodePtr<V>>,
+    end: Bound<NodePtr<V>>,
+) -> Result<RefsArray<2, V>, SliceError>
+where
+    V: Variant,
+    I: Iterator<Item = NodePtr<V>> + Clone,
+{
+    let position = |ptr: NodePtr<V>| {
+        ptrs.clone()
+            .position(|p| p == ptr)
+            .ok_or(SliceError::OutOfSlice)
+    };
+
+    let begin = match begin {
+        Bound::Included(x) => position(x)?,
+        Bound::Excluded(x) => position(x)? + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match end {
+        Bound::Included(x) => position(x).map(|p| (p >= begin).then_some(p + 1))?,
+        Bound::Excluded(x) => position(x).map(|p| (p >= begin).then_some(p))?,
+        Bound::Unbounded => None,
+    };
+
+    Ok(position_ends(ptrs, begin, end.unwrap_or(usize::MAX)))
+}
@@ -1,11 +1,16 @@
+use super::chunk_ends::{chunk_ends, position_ends, positions, slice_ends, window_ends};
+#[cfg(feature = "alloc")]
+use super::node_layout::to_dot;
+use super::node_layout::{NodeLayout, node_layout};
 use crate::{
-    Doubly, DoublyIdx,
+    Doubly, DoublyIdx, ListSlice,
     iter::{DoublyIter, DoublyIterPtr, DoublyLinkIter},
     list::helper_traits::HasDoublyEnds,
     pointers::DoublyPtr,
     type_aliases::{BACK_IDX, FRONT_IDX, OOB},
 };
 use core::iter::{Chain, Rev};
+use core::ops::RangeBounds;
 use orx_pinned_vec::PinnedVec;
 use orx_selfref_col::{MemoryPolicy, Node};
 
@@ -158,6 +163,178 @@ where
         self.iter_ptr()
     }
 
+    // chunks
+
+    /// Returns an iterator of the front and back indices of consecutive chunks of the list, from front to back.
+    /// Each chunk has `chunk_size` elements, except for the last chunk which might be shorter.
+    ///
+    /// The index pairs can be stored and used later to create the chunks as slices by `list.slice(front..=back)`,
+    /// as long as the indices remain valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: DoublyList<_> = (0..7).collect();
+    ///
+    /// let chunks: Vec<_> = list.chunk_indices(3).collect();
+    /// assert_eq!(chunks.len(), 3);
+    ///
+    /// let (front, back) = chunks[1];
+    /// assert_eq!(list.get(front), Some(&3));
+    /// assert_eq!(list.get(back), Some(&5));
+    ///
+    /// let (front, back) = chunks[2];
+    /// assert_eq!(front, back);
+    /// assert_eq!(list.get(front), Some(&6));
+    ///
+    /// let slice = list.slice(chunks[0].0..=chunks[0].1);
+    /// assert!(slice.eq_to_iter_vals([0, 1, 2]));
+    /// ```
+    fn chunk_indices<'a>(
+        &'a self,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = (DoublyIdx<T>, DoublyIdx<T>)>
+    where
+        M: 'a,
+        T: 'a,
+        P: 'a,
+    {
+        let s = self.col().memory_state();
+        chunk_ends(self.iter_ptr(), chunk_size)
+            .map(move |(a, b)| (DoublyIdx::new(s, a), DoublyIdx::new(s, b)))
+    }
+
+    /// Returns an iterator of slices of consecutive chunks of the list, from front to back.
+    /// Each chunk has `chunk_size` elements, except for the last chunk which might be shorter.
+    ///
+    /// See [`chunk_indices`] to obtain the chunk boundaries as indices.
+    ///
+    /// [`chunk_indices`]: crate::DoublyIterable::chunk_indices
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: DoublyList<_> = (0..7).collect();
+    ///
+    /// let sums: Vec<usize> = list.chunks(3).map(|x| x.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 12, 6]);
+    ///
+    /// let mut chunks = list.chunks(3);
+    /// assert!(chunks.next().unwrap().eq_to_iter_vals([0, 1, 2]));
+    /// assert!(chunks.next().unwrap().eq_to_iter_vals([3, 4, 5]));
+    /// assert!(chunks.next().unwrap().eq_to_iter_vals([6]));
+    /// assert!(chunks.next().is_none());
+    /// ```
+    fn chunks<'a>(
+        &'a self,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = ListSlice<'a, Doubly<T>, M, P>>
+    where
+        M: 'a,
+        T: 'a,
+        P: 'a,
+    {
+        let col = self.col();
+        chunk_ends(self.iter_ptr(), chunk_size).map(move |(a, b)| ListSlice {
+            col,
+            ends: slice_ends(a, b),
+        })
+    }
+
+    /// Returns an iterator of slices of all windows of `window_size` consecutive elements of the list, from front to back.
+    /// The iterator is empty if the list has less than `window_size` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: DoublyList<_> = (0..5).collect();
+    ///
+    /// let mut windows = list.windows(3);
+    /// assert!(windows.next().unwrap().eq_to_iter_vals([0, 1, 2]));
+    /// assert!(windows.next().unwrap().eq_to_iter_vals([1, 2, 3]));
+    /// assert!(windows.next().unwrap().eq_to_iter_vals([2, 3, 4]));
+    /// assert!(windows.next().is_none());
+    ///
+    /// assert_eq!(list.windows(6).count(), 0);
+    /// ```
+    fn windows<'a>(
+        &'a self,
+        window_size: usize,
+    ) -> impl Iterator<Item = ListSlice<'a, Doubly<T>, M, P>>
+    where
+        M: 'a,
+        T: 'a,
+        P: 'a,
+    {
+        let col = self.col();
+        window_ends(self.iter_ptr(), window_size).map(move |(a, b)| ListSlice {
+            col,
+            ends: slice_ends(a, b),
+        })
+    }
+
+    // slices
+
+    /// Creates and returns a slice of the list between the given `range` of positions.
+    ///
+    /// Positions are relative to the front of this list or view, as in a vec slice.
+    ///
+    /// ***O(n)*** since the slice boundaries are found by traversing the list from the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if it starts after it ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: DoublyList<_> = (0..10).collect();
+    ///
+    /// let slice = list.slice_at(2..7);
+    /// assert!(slice.eq_to_iter_vals([2, 3, 4, 5, 6]));
+    /// assert_eq!(slice.back(), Some(&6));
+    ///
+    /// let sub_slice = slice.slice_at(1..=2);
+    /// assert!(sub_slice.eq_to_iter_vals([3, 4]));
+    ///
+    /// assert!(list.slice_at(..3).eq_to_iter_vals([0, 1, 2]));
+    /// assert!(list.slice_at(8..).eq_to_iter_vals([8, 9]));
+    /// assert!(list.slice_at(4..4).iter().next().is_none());
+    /// ```
+    fn slice_at<'a, R>(&'a self, range: R) -> ListSlice<'a, Doubly<T>, M, P>
+    where
+        R: RangeBounds<usize>,
+        M: 'a,
+        T: 'a,
+        P: 'a,
+    {
+        let (begin, end) = positions(range, self.iter_ptr().count());
+        ListSlice {
+            col: self.col(),
+            ends: position_ends(self.iter_ptr(), begin, end),
+        }
+    }
+
     // idx
 
     /// Creates a forward iterator starting from the `pivot_idx` and ending at the element before it.
@@ -360,6 +537,89 @@ where
         iter.next().is_none()
     }
 
+    /// Returns an iterator over the slots of the underlying storage of the list, in storage order,
+    /// describing the layout of each node; see [`NodeLayout`].
+    ///
+    /// This method is intended for debugging and inspecting sequences of mutations.
+    /// Note that the iterator yields all slots of the storage, including closed nodes and, in the case of a slice,
+    /// nodes which do not belong to the slice; front and back refer to the ends of this list or slice.
+    ///
+    /// [`NodeLayout`]: crate::NodeLayout
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyListLazy<_> = ['a', 'b', 'c'].into_iter().collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// list.move_to_front(idx[2]);
+    /// list.pop_back();
+    /// assert!(list.eq_to_iter_vals(['c', 'a']));
+    ///
+    /// let layout: Vec<_> = list.node_layout().collect();
+    /// assert_eq!(
+    ///     layout,
+    ///     [
+    ///         NodeLayout { position: 0, is_active: true, prev: Some(2), next: None, is_front: false, is_back: true },
+    ///         NodeLayout { position: 1, is_active: false, prev: None, next: None, is_front: false, is_back: false },
+    ///         NodeLayout { position: 2, is_active: true, prev: None, next: Some(0), is_front: true, is_back: false },
+    ///     ]
+    /// );
+    /// ```
+    fn node_layout<'a>(&'a self) -> impl Iterator<Item = NodeLayout>
+    where
+        M: 'a,
+        T: 'a,
+        P: 'a,
+    {
+        node_layout(
+            self.col(),
+            self.ends().get(FRONT_IDX),
+            self.ends().get(BACK_IDX),
+            |node| node.next().get(),
+            |node| node.prev().get(),
+        )
+    }
+
+    /// Renders the [`node_layout`] of the list in Graphviz DOT format.
+    ///
+    /// The graph draws two clusters side by side:
+    /// * "storage order" lists all slots of the storage in their order in memory, where closed nodes are dashed;
+    /// * "link order" draws the active nodes with the links among them; next links are solid and prev links are dashed,
+    ///   together with the front and back of the list or slice.
+    ///
+    /// Each node is labeled as its storage position followed by its value.
+    ///
+    /// Available with the `alloc` feature, which is enabled by default.
+    ///
+    /// [`node_layout`]: crate::DoublyIterable::node_layout
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyListLazy<_> = ['a', 'b', 'c'].into_iter().collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// list.move_to_front(idx[2]);
+    /// list.pop_back();
+    ///
+    /// let dot = list.to_dot();
+    /// assert!(dot.starts_with("digraph list {"));
+    /// assert!(dot.contains("l2 -> l0;"));
+    /// assert!(dot.contains("l0 -> l2 [style=dashed];"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_dot(&self) -> alloc::string::String
+    where
+        T: core::fmt::Display,
+    {
+        to_dot(self.col(), self.node_layout())
+    }
+
     /// Returns a string representing the values in the underlying storage of the list.
     #[cfg(test)]
     #[allow(clippy::format_collect)]
@@ -1,9 +1,12 @@
+pub(crate) mod chunk_ends;
 mod doubly_iterable;
 mod doubly_iterable_mut;
+pub(crate) mod node_layout;
 mod singly_iterable;
 mod singly_iterable_mut;
 
 pub use doubly_iterable::DoublyIterable;
 pub use doubly_iterable_mut::DoublyIterableMut;
+pub use node_layout::NodeLayout;
 pub use singly_iterable::SinglyIterable;
 pub use singly_iterable_mut::SinglyIterableMut;
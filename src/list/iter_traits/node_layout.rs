@@ -0,0 +1,135 @@
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{CoreCol, Node, NodePtr, Variant};
+
+/// Layout of a slot of the underlying storage of a linked list, yielded by the `node_layout` method.
+///
+/// All positions are positions in the storage, rather than positions of the elements in the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeLayout {
+    /// Position of the slot in the storage.
+    pub position: usize,
+    /// Whether the slot holds an element of the list (active) or a removed node which is not yet reclaimed (closed).
+    pub is_active: bool,
+    /// Storage position of the previous node; always None for singly linked lists and closed nodes.
+    pub prev: Option<usize>,
+    /// Storage position of the next node; always None for closed nodes.
+    pub next: Option<usize>,
+    /// Whether the slot is the front of the list or slice.
+    pub is_front: bool,
+    /// Whether the slot is the back of the list or slice.
+    pub is_back: bool,
+}
+
+type Link<V> = fn(&Node<V>) -> Option<NodePtr<V>>;
+
+pub(crate) fn node_layout<'a, V, P>(
+    col: &'a CoreCol<V, P>,
+    front: Option<NodePtr<V>>,
+    back: Option<NodePtr<V>>,
+    next: Link<V>,
+    prev: Link<V>,
+) -> impl Iterator<Item = NodeLayout> + 'a
+where
+    V: Variant,
+    P: PinnedVec<Node<V>>,
+{
+    let front = front.and_then(|x| col.position_of(x));
+    let back = back.and_then(|x| col.position_of(x));
+
+    col.nodes()
+        .iter()
+        .enumerate()
+        .map(move |(position, node)| NodeLayout {
+            position,
+            is_active: node.is_active(),
+            prev: prev(node).and_then(|x| col.position_of(x)),
+            next: next(node).and_then(|x| col.position_of(x)),
+            is_front: front == Some(position),
+            is_back: back == Some(position),
+        })
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn to_dot<V, P>(
+    col: &CoreCol<V, P>,
+    layout: impl Iterator<Item = NodeLayout>,
+) -> alloc::string::String
+where
+    V: Variant,
+    V::Item: core::fmt::Display,
+    P: PinnedVec<Node<V>>,
+{
+    use alloc::{format, string::String, vec::Vec};
+
+    fn escape(label: &str) -> String {
+        label.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    let layout: Vec<_> = layout.collect();
+    let label = |x: &NodeLayout| match col.nodes().get(x.position).and_then(|n| n.data()) {
+        Some(value) => escape(&format!("{}: {}", x.position, value)),
+        None => format!("{}: x", x.position),
+    };
+
+    let mut dot = String::new();
+    let mut line = |text: String| {
+        dot.push_str(&text);
+        dot.push('\n');
+    };
+
+    line("digraph list {".into());
+    line("    rankdir=LR;".into());
+    line("    node [shape=box];".into());
+
+    line("    subgraph cluster_storage {".into());
+    line("        label=\"storage order\";".into());
+    for x in &layout {
+        let style = if x.is_active { "solid" } else { "dashed" };
+        line(format!(
+            "        s{} [label=\"{}\", style={}];",
+            x.position,
+            label(x),
+            style
+        ));
+    }
+    for w in layout.windows(2) {
+        line(format!(
+            "        s{} -> s{} [style=invis];",
+            w[0].position, w[1].position
+        ));
+    }
+    line("    }".into());
+
+    line("    subgraph cluster_links {".into());
+    line("        label=\"link order\";".into());
+    for x in layout.iter().filter(|x| x.is_active) {
+        line(format!("        l{} [label=\"{}\"];", x.position, label(x)));
+    }
+    for x in layout.iter().filter(|x| x.is_active) {
+        if let Some(next) = x.next {
+            line(format!("        l{} -> l{};", x.position, next));
+        }
+        if let Some(prev) = x.prev {
+            line(format!(
+                "        l{} -> l{} [style=dashed];",
+                x.position, prev
+            ));
+        }
+    }
+    line("    }".into());
+
+    for x in &layout {
+        if x.is_front {
+            line("    front [shape=plaintext];".into());
+            line(format!("    front -> l{};", x.position));
+        }
+        if x.is_back {
+            line("    back [shape=plaintext];".into());
+            line(format!("    back -> l{};", x.position));
+        }
+    }
+
+    line("}".into());
+
+    dot
+}
@@ -36,8 +36,9 @@ where
     where
         M: 'a,
     {
-        let a = self.ends().get();
-        SinglyIterMut::new(self.col_mut(), a)
+        let a = self.front_end();
+        let b = self.back_end();
+        SinglyIterMut::new(self.col_mut(), a, b)
     }
 
     // idx
@@ -75,7 +76,8 @@ where
         M: 'a,
     {
         let a = self.col().try_get_ptr(idx).expect(OOB);
-        SinglyIterMut::new(self.col_mut(), Some(a))
+        let b = self.back_end();
+        SinglyIterMut::new(self.col_mut(), Some(a), b)
     }
 }
 
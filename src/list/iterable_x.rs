@@ -0,0 +1,119 @@
+use super::List;
+use crate::variant::ListVariant;
+use core::iter::FilterMap;
+use orx_iterable::{Collection, Iterable};
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+type NodesIter<'a, P> = <<P as Collection>::Iterable<'a> as Iterable>::Iter;
+
+/// Iterator created by [`IterableX`], yielding references to elements of the list in arbitrary order.
+pub type IterX<'a, V, P> = FilterMap<
+    NodesIter<'a, P>,
+    fn(&'a Node<V>) -> Option<&'a <V as orx_selfref_col::Variant>::Item>,
+>;
+
+/// An [`Iterable`] over references to the elements of a list in **arbitrary order**,
+/// created by the [`iterable_x`] method.
+///
+/// Each call to `iter` creates an iterator which is equivalent to [`iter_x`];
+/// it is often faster than iterating from front to back, and can be preferred whenever the order does not matter.
+///
+/// [`Iterable`]: orx_iterable::Iterable
+/// [`iterable_x`]: crate::List::iterable_x
+/// [`iter_x`]: crate::List::iter_x
+pub struct IterableX<'a, V, M, P>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    list: &'a List<V, M, P>,
+}
+
+impl<V, M, P> Clone for IterableX<'_, V, M, P>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V, M, P> Copy for IterableX<'_, V, M, P>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+}
+
+impl<'a, V, M, P> Iterable for IterableX<'a, V, M, P>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    type Item = &'a V::Item;
+
+    type Iter = IterX<'a, V, P>;
+
+    fn iter(&self) -> Self::Iter {
+        let nodes: &'a P = self.list.0.nodes();
+        let data: fn(&'a Node<V>) -> Option<&'a V::Item> = |x| x.data();
+        nodes.iter().filter_map(data)
+    }
+}
+
+impl<V, M, P> List<V, M, P>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Returns an [`Iterable`] over references to the elements of the list in **arbitrary order**.
+    ///
+    /// Note that the list itself implements [`Collection`] and [`CollectionMut`] with front to back order.
+    /// This is the unordered counterpart which can be passed to apis expecting an `Iterable`
+    /// whenever the order of elements does not matter; see [`iter_x`].
+    ///
+    /// Since both [`Collection`] and the iterable traits of the list, such as [`DoublyIterable`], define an `iter` method,
+    /// calling `list.iter()` while `Collection` is in scope fails with an ambiguity error (E0034).
+    /// In such a scope, the call must be written in the fully qualified form, such as `DoublyIterable::iter(&list)`
+    /// for front to back iteration or `Collection::iter(&list)`; the same holds for slices of the list.
+    ///
+    /// [`DoublyIterable`]: crate::DoublyIterable
+    /// [`Iterable`]: orx_iterable::Iterable
+    /// [`Collection`]: orx_iterable::Collection
+    /// [`CollectionMut`]: orx_iterable::CollectionMut
+    /// [`iter_x`]: crate::List::iter_x
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    /// use orx_iterable::Iterable;
+    ///
+    /// fn sum_and_count<'a>(numbers: impl Iterable<Item = &'a i32>) -> (i32, usize) {
+    ///     (numbers.iter().sum(), numbers.iter().count())
+    /// }
+    ///
+    /// let mut list = DoublyList::new();
+    /// list.push_front(2);
+    /// list.push_back(3);
+    /// list.push_front(1);
+    ///
+    /// assert_eq!(sum_and_count(list.iterable_x()), (6, 3));
+    ///
+    /// // with `Collection` in scope, `iter` is called in the fully qualified form
+    /// use orx_iterable::Collection;
+    ///
+    /// assert!(DoublyIterable::iter(&list).eq(&[1, 2, 3]));
+    /// assert_eq!(Collection::iter(&list).sum::<i32>(), 6);
+    /// ```
+    pub fn iterable_x(&self) -> IterableX<'_, V, M, P> {
+        IterableX { list: self }
+    }
+}
@@ -0,0 +1,523 @@
+use super::{
+    helper_traits::{HasCol, HasColMut, HasDoublyEnds, HasDoublyEndsMut},
+    idx_map::IdxMap,
+};
+use crate::{
+    Doubly, DoublyEnds, DoublyEndsMut, DoublyIdx, DoublyListLazy, type_aliases::DefaultPinVec,
+};
+use alloc::collections::BTreeMap;
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Display},
+};
+use orx_selfref_col::{MemoryReclaimNever, MemoryState, SelfRefCol, Utilization, Variant};
+
+/// A map from the keys of a [`KeyedDoublyList`] to the indices of the nodes holding the values.
+///
+/// It is implemented for `BTreeMap`, with the `std` feature for `std::collections::HashMap`
+/// and with the `hashbrown` feature for `hashbrown::HashMap`.
+///
+/// Keys are looked up by `Q`, which is any borrowed form of the key, such as `str` for `String` keys,
+/// as in the `get` and `remove` methods of the maps. It is the key type itself by default.
+pub trait KeyIndex<K, T, Q: ?Sized = K> {
+    /// Returns the index of the node of the `key`; None if the key does not exist.
+    fn get_idx(&self, key: &Q) -> Option<DoublyIdx<T>>;
+
+    /// Inserts the `idx` for the `key`, which is known not to exist in the map.
+    fn insert_idx(&mut self, key: K, idx: DoublyIdx<T>);
+
+    /// Removes and returns the index of the `key`; None if the key does not exist.
+    fn remove_idx(&mut self, key: &Q) -> Option<DoublyIdx<T>>;
+
+    /// Removes all keys.
+    fn clear_indices(&mut self);
+
+    /// Calls `f` on a mutable reference of each index in the map.
+    fn for_each_idx_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut DoublyIdx<T>);
+}
+
+impl<K, T, Q> KeyIndex<K, T, Q> for BTreeMap<K, DoublyIdx<T>>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    fn get_idx(&self, key: &Q) -> Option<DoublyIdx<T>> {
+        self.get(key).copied()
+    }
+
+    fn insert_idx(&mut self, key: K, idx: DoublyIdx<T>) {
+        self.insert(key, idx);
+    }
+
+    fn remove_idx(&mut self, key: &Q) -> Option<DoublyIdx<T>> {
+        self.remove(key)
+    }
+
+    fn clear_indices(&mut self) {
+        self.clear();
+    }
+
+    fn for_each_idx_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut DoublyIdx<T>),
+    {
+        self.values_mut().for_each(f);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, T, Q, S> KeyIndex<K, T, Q> for std::collections::HashMap<K, DoublyIdx<T>, S>
+where
+    K: core::hash::Hash + Eq + Borrow<Q>,
+    Q: core::hash::Hash + Eq + ?Sized,
+    S: core::hash::BuildHasher,
+{
+    fn get_idx(&self, key: &Q) -> Option<DoublyIdx<T>> {
+        self.get(key).copied()
+    }
+
+    fn insert_idx(&mut self, key: K, idx: DoublyIdx<T>) {
+        self.insert(key, idx);
+    }
+
+    fn remove_idx(&mut self, key: &Q) -> Option<DoublyIdx<T>> {
+        self.remove(key)
+    }
+
+    fn clear_indices(&mut self) {
+        self.clear();
+    }
+
+    fn for_each_idx_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut DoublyIdx<T>),
+    {
+        self.values_mut().for_each(f);
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K, T, Q, S> KeyIndex<K, T, Q> for hashbrown::HashMap<K, DoublyIdx<T>, S>
+where
+    K: core::hash::Hash + Eq + Borrow<Q>,
+    Q: core::hash::Hash + Eq + ?Sized,
+    S: core::hash::BuildHasher,
+{
+    fn get_idx(&self, key: &Q) -> Option<DoublyIdx<T>> {
+        self.get(key).copied()
+    }
+
+    fn insert_idx(&mut self, key: K, idx: DoublyIdx<T>) {
+        self.insert(key, idx);
+    }
+
+    fn remove_idx(&mut self, key: &Q) -> Option<DoublyIdx<T>> {
+        self.remove(key)
+    }
+
+    fn clear_indices(&mut self) {
+        self.clear();
+    }
+
+    fn for_each_idx_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut DoublyIdx<T>),
+    {
+        self.values_mut().for_each(f);
+    }
+}
+
+/// Error cases of the key based operations of a [`KeyedDoublyList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyedListError {
+    /// KeyNotFound => The key referred to does not exist in the list.
+    KeyNotFound,
+    /// DuplicateKey => The key of the element to be inserted already exists in the list.
+    DuplicateKey,
+}
+
+impl Display for KeyedListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <KeyedListError as Debug>::fmt(self, f)
+    }
+}
+
+/// A doubly linked list whose elements can additionally be found by unique keys,
+/// such as an ordered set or a linked hash map.
+///
+/// Values are stored in a doubly linked list with lazy memory reclaim, and the keys are mapped to
+/// the indices of the nodes by a [`KeyIndex`], which is a `BTreeMap` by default or a `HashMap`
+/// with the `std` or `hashbrown` feature.
+///
+/// Keys are the stable handles of the elements. Indices returned by the keyed list remain valid
+/// while elements are inserted or moved; however, [`remove_by_key`] might reclaim the closed nodes of
+/// removed elements, which changes the [`memory_state`] and invalidates the indices obtained before.
+/// The key index itself is always remapped, so [`idx_of_key`] returns a fresh index of an element.
+///
+/// [`remove_by_key`]: crate::KeyedDoublyList::remove_by_key
+/// [`memory_state`]: crate::KeyedDoublyList::memory_state
+/// [`idx_of_key`]: crate::KeyedDoublyList::idx_of_key
+///
+/// Methods of [`DoublyEnds`], [`DoublyEndsMut`], [`DoublyIterable`] and [`DoublyIterableMut`] are available
+/// on the keyed list; hence, the values can be accessed and moved by their indices, and iterated in list order.
+///
+/// [`DoublyIterable`]: crate::DoublyIterable
+/// [`DoublyIterableMut`]: crate::DoublyIterableMut
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_linked_list::*;
+///
+/// let mut list = KeyedDoublyList::new();
+///
+/// list.push_back("b", 2).unwrap();
+/// list.push_back("d", 4).unwrap();
+/// list.push_front("a", 1).unwrap();
+/// list.insert_next_to_key(&"b", "c", 3).unwrap();
+/// assert!(list.eq_to_iter_vals([1, 2, 3, 4]));
+///
+/// assert_eq!(list.push_back("a", 10), Err(KeyedListError::DuplicateKey));
+/// assert_eq!(list.get_by_key(&"c"), Some(&3));
+///
+/// list.move_key_to_front(&"d").unwrap();
+/// assert!(list.eq_to_iter_vals([4, 1, 2, 3]));
+///
+/// assert_eq!(list.remove_by_key(&"a"), Some(1));
+/// assert_eq!(list.remove_by_key(&"a"), None);
+/// assert!(list.iter().eq(&[4, 2, 3]));
+/// ```
+pub struct KeyedDoublyList<K, T, I = BTreeMap<K, DoublyIdx<T>>>
+where
+    I: KeyIndex<K, T>,
+{
+    list: DoublyListLazy<T>,
+    keys: I,
+    phantom: core::marker::PhantomData<K>,
+}
+
+impl<K, T, I> Default for KeyedDoublyList<K, T, I>
+where
+    I: KeyIndex<K, T> + Default,
+{
+    fn default() -> Self {
+        Self::with_key_index(I::default())
+    }
+}
+
+impl<K, T> KeyedDoublyList<K, T>
+where
+    K: Ord,
+{
+    /// Creates an empty keyed list using a `BTreeMap` as the key index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<K, T, I> KeyedDoublyList<K, T, I>
+where
+    I: KeyIndex<K, T>,
+{
+    /// Creates an empty keyed list using the given empty `key_index`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut list = KeyedDoublyList::with_key_index(BTreeMap::new());
+    /// list.push_back(42, 'x').unwrap();
+    /// assert_eq!(list.get_by_key(&42), Some(&'x'));
+    /// ```
+    pub fn with_key_index(key_index: I) -> Self {
+        Self {
+            list: DoublyListLazy::new(),
+            keys: key_index,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns true if an element with the `key` exists in the list.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized,
+        I: KeyIndex<K, T, Q>,
+    {
+        self.keys.get_idx(key).is_some()
+    }
+
+    /// Returns the index of the element with the `key`; None if the key does not exist.
+    ///
+    /// The index remains valid until the element is removed, or until the keyed list reclaims the
+    /// memory of removed elements, which is reflected by a change in its [`memory_state`].
+    ///
+    /// [`memory_state`]: crate::KeyedDoublyList::memory_state
+    pub fn idx_of_key<Q>(&self, key: &Q) -> Option<DoublyIdx<T>>
+    where
+        Q: ?Sized,
+        I: KeyIndex<K, T, Q>,
+    {
+        self.keys.get_idx(key)
+    }
+
+    /// Returns the memory state of the underlying list; indices obtained in a different state are invalid.
+    pub fn memory_state(&self) -> MemoryState {
+        self.list.memory_state()
+    }
+
+    /// Returns the node utilization of the underlying list, where closed nodes belong to removed elements
+    /// which are not yet reclaimed.
+    pub fn node_utilization(&self) -> Utilization {
+        self.list.node_utilization()
+    }
+
+    /// ***O(1)*** with a hash map, or ***O(log n)*** with a btree map, returns a reference to the value of the `key`;
+    /// None if the key does not exist.
+    pub fn get_by_key<Q>(&self, key: &Q) -> Option<&T>
+    where
+        Q: ?Sized,
+        I: KeyIndex<K, T, Q>,
+    {
+        self.keys.get_idx(key).and_then(|idx| self.list.get(idx))
+    }
+
+    /// Returns a mutable reference to the value of the `key`; None if the key does not exist.
+    pub fn get_mut_by_key<Q>(&mut self, key: &Q) -> Option<&mut T>
+    where
+        Q: ?Sized,
+        I: KeyIndex<K, T, Q>,
+    {
+        self.keys
+            .get_idx(key)
+            .and_then(|idx| self.list.get_mut(idx))
+    }
+
+    /// Pushes the `value` with the `key` to the front of the list and returns its index.
+    ///
+    /// Returns [`KeyedListError::DuplicateKey`] and leaves the list unchanged if the key already exists.
+    pub fn push_front(&mut self, key: K, value: T) -> Result<DoublyIdx<T>, KeyedListError> {
+        self.insert_with(key, |list| list.push_front(value))
+    }
+
+    /// Pushes the `value` with the `key` to the back of the list and returns its index.
+    ///
+    /// Returns [`KeyedListError::DuplicateKey`] and leaves the list unchanged if the key already exists.
+    pub fn push_back(&mut self, key: K, value: T) -> Result<DoublyIdx<T>, KeyedListError> {
+        self.insert_with(key, |list| list.push_back(value))
+    }
+
+    /// Inserts the `value` with the `key` next to the element with the `anchor` key and returns its index.
+    ///
+    /// Leaves the list unchanged and returns an error if:
+    /// * the `anchor` does not exist, see [`KeyedListError::KeyNotFound`];
+    /// * the `key` already exists, see [`KeyedListError::DuplicateKey`].
+    pub fn insert_next_to_key<Q>(
+        &mut self,
+        anchor: &Q,
+        key: K,
+        value: T,
+    ) -> Result<DoublyIdx<T>, KeyedListError>
+    where
+        Q: ?Sized,
+        I: KeyIndex<K, T, Q>,
+    {
+        let anchor = self.keys.get_idx(anchor);
+        let anchor = anchor.ok_or(KeyedListError::KeyNotFound)?;
+        self.insert_with(key, |list| list.insert_next_to(anchor, value))
+    }
+
+    /// Inserts the `value` with the `key` prev to the element with the `anchor` key and returns its index.
+    ///
+    /// Leaves the list unchanged and returns an error if:
+    /// * the `anchor` does not exist, see [`KeyedListError::KeyNotFound`];
+    /// * the `key` already exists, see [`KeyedListError::DuplicateKey`].
+    pub fn insert_prev_to_key<Q>(
+        &mut self,
+        anchor: &Q,
+        key: K,
+        value: T,
+    ) -> Result<DoublyIdx<T>, KeyedListError>
+    where
+        Q: ?Sized,
+        I: KeyIndex<K, T, Q>,
+    {
+        let anchor = self.keys.get_idx(anchor);
+        let anchor = anchor.ok_or(KeyedListError::KeyNotFound)?;
+        self.insert_with(key, |list| list.insert_prev_to(anchor, value))
+    }
+
+    /// Removes and returns the value of the `key`; None if the key does not exist.
+    ///
+    /// The removed element leaves a closed node behind. Once there are at least 32 closed nodes and
+    /// they outnumber the elements, this method reclaims them as [`reclaim_closed_nodes`] does.
+    /// In that case, the [`memory_state`] changes and **all indices returned before this call are invalidated**;
+    /// the keys are not affected and [`idx_of_key`] can be used to obtain the new indices.
+    ///
+    /// [`reclaim_closed_nodes`]: crate::KeyedDoublyList::reclaim_closed_nodes
+    /// [`memory_state`]: crate::KeyedDoublyList::memory_state
+    /// [`idx_of_key`]: crate::KeyedDoublyList::idx_of_key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = KeyedDoublyList::new();
+    /// for i in 0..100 {
+    ///     list.push_back(i, i).unwrap();
+    /// }
+    /// let idx = list.idx_of_key(&99).unwrap();
+    /// let state = list.memory_state();
+    ///
+    /// for i in 0..60 {
+    ///     list.remove_by_key(&i);
+    /// }
+    ///
+    /// // closed nodes outnumbered the elements and are reclaimed
+    /// assert_ne!(list.memory_state(), state);
+    /// assert_eq!(list.get(idx), None);
+    ///
+    /// let idx = list.idx_of_key(&99).unwrap();
+    /// assert_eq!(list.get(idx), Some(&99));
+    /// ```
+    pub fn remove_by_key<Q>(&mut self, key: &Q) -> Option<T>
+    where
+        Q: ?Sized,
+        I: KeyIndex<K, T, Q>,
+    {
+        let idx = self.keys.remove_idx(key)?;
+        let value = self.list.remove(idx);
+        if let Some(idx_map) = self.list.reclaim_if_sparse() {
+            self.remap_keys(&idx_map);
+        }
+        Some(value)
+    }
+
+    /// ***O(1)*** Moves the element with the `key` to the front of the list.
+    ///
+    /// Returns [`KeyedListError::KeyNotFound`] if the key does not exist.
+    pub fn move_key_to_front<Q>(&mut self, key: &Q) -> Result<(), KeyedListError>
+    where
+        Q: ?Sized,
+        I: KeyIndex<K, T, Q>,
+    {
+        let idx = self.keys.get_idx(key).ok_or(KeyedListError::KeyNotFound)?;
+        self.list.move_to_front(idx);
+        Ok(())
+    }
+
+    /// ***O(1)*** Moves the element with the `key` to the back of the list.
+    ///
+    /// Returns [`KeyedListError::KeyNotFound`] if the key does not exist.
+    pub fn move_key_to_back<Q>(&mut self, key: &Q) -> Result<(), KeyedListError>
+    where
+        Q: ?Sized,
+        I: KeyIndex<K, T, Q>,
+    {
+        let idx = self.keys.get_idx(key).ok_or(KeyedListError::KeyNotFound)?;
+        self.list.move_to_back(idx);
+        Ok(())
+    }
+
+    /// Removes all elements and keys.
+    pub fn clear(&mut self) {
+        self.list.clear();
+        self.keys.clear_indices();
+    }
+
+    /// Reclaims the memory of removed elements and remaps the indices in the key index.
+    ///
+    /// Note that [`remove_by_key`] also performs this automatically whenever the removed elements outnumber the elements.
+    /// Indices obtained before the reclaim are invalidated if the [`memory_state`] changes.
+    ///
+    /// [`remove_by_key`]: crate::KeyedDoublyList::remove_by_key
+    ///
+    /// [`memory_state`]: crate::KeyedDoublyList::memory_state
+    pub fn reclaim_closed_nodes(&mut self) {
+        if let Some(idx_map) = self.list.reclaim_closed_nodes_with_idx_map() {
+            self.remap_keys(&idx_map);
+        }
+    }
+
+    fn insert_with<F>(&mut self, key: K, insert: F) -> Result<DoublyIdx<T>, KeyedListError>
+    where
+        F: FnOnce(&mut DoublyListLazy<T>) -> DoublyIdx<T>,
+    {
+        match self.keys.get_idx(&key) {
+            Some(_) => Err(KeyedListError::DuplicateKey),
+            None => {
+                let idx = insert(&mut self.list);
+                self.keys.insert_idx(key, idx);
+                Ok(idx)
+            }
+        }
+    }
+
+    fn remap_keys(&mut self, idx_map: &IdxMap<Doubly<T>>) {
+        self.keys.for_each_idx_mut(|idx| {
+            if let Some(new_idx) = idx_map.get(*idx) {
+                *idx = new_idx;
+            }
+        });
+    }
+}
+
+impl<K, T, I> HasCol<Doubly<T>, MemoryReclaimNever, DefaultPinVec<Doubly<T>>>
+    for KeyedDoublyList<K, T, I>
+where
+    I: KeyIndex<K, T>,
+{
+    #[inline(always)]
+    fn col(&self) -> &SelfRefCol<Doubly<T>, MemoryReclaimNever, DefaultPinVec<Doubly<T>>> {
+        &self.list.0
+    }
+}
+
+impl<K, T, I> HasColMut<Doubly<T>, MemoryReclaimNever, DefaultPinVec<Doubly<T>>>
+    for KeyedDoublyList<K, T, I>
+where
+    I: KeyIndex<K, T>,
+{
+    #[inline(always)]
+    fn col_mut(
+        &mut self,
+    ) -> &mut SelfRefCol<Doubly<T>, MemoryReclaimNever, DefaultPinVec<Doubly<T>>> {
+        &mut self.list.0
+    }
+}
+
+impl<K, T, I> HasDoublyEnds<T, MemoryReclaimNever, DefaultPinVec<Doubly<T>>>
+    for KeyedDoublyList<K, T, I>
+where
+    I: KeyIndex<K, T>,
+{
+    #[inline(always)]
+    fn ends(&self) -> &<Doubly<T> as Variant>::Ends {
+        self.list.0.ends()
+    }
+}
+
+impl<K, T, I> HasDoublyEndsMut<T, MemoryReclaimNever, DefaultPinVec<Doubly<T>>>
+    for KeyedDoublyList<K, T, I>
+where
+    I: KeyIndex<K, T>,
+{
+    #[inline(always)]
+    fn ends_mut(&mut self) -> &mut <Doubly<T> as Variant>::Ends {
+        self.list.0.ends_mut()
+    }
+}
@@ -27,7 +27,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = SinglyList::from_iter(['b', 'c', 'd']);
+    /// let mut list: SinglyList<_> = SinglyList::from_iter(['b', 'c', 'd']);
     ///
     /// list.insert_at(0, 'a');
     /// assert!(list.eq_to_iter_vals(['a', 'b', 'c', 'd']));
@@ -69,7 +69,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = SinglyList::from_iter(['a', 'b', 'c', 'd', 'e']);
+    /// let mut list: SinglyList<_> = SinglyList::from_iter(['a', 'b', 'c', 'd', 'e']);
     ///
     /// let value = list.remove_at(0);
     /// assert_eq!(value, Some('a'));
@@ -130,7 +130,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['b', 'c', 'd']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['b', 'c', 'd']);
     ///
     /// list.insert_at(0, 'a');
     /// assert!(list.eq_to_iter_vals(['a', 'b', 'c', 'd']));
@@ -177,7 +177,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['b', 'c', 'd']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['b', 'c', 'd']);
     ///
     /// list.insert_at_from_back(0, 'e');
     /// assert!(list.eq_to_iter_vals(['b', 'c', 'd', 'e']));
@@ -226,7 +226,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['a', 'b', 'c', 'd', 'e']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['a', 'b', 'c', 'd', 'e']);
     ///
     /// let value = list.remove_at(0);
     /// assert_eq!(value, Some('a'));
@@ -276,7 +276,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['a', 'b', 'c', 'd', 'e']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['a', 'b', 'c', 'd', 'e']);
     ///
     /// let value = list.remove_at_from_back(4);
     /// assert_eq!(value, Some('a'));
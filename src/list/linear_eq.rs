@@ -21,7 +21,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['a', 'b', 'c', 'd']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['a', 'b', 'c', 'd']);
     ///
     /// let x = list.idx_of(&'x');
     /// assert!(x.is_none());
@@ -83,7 +83,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['a', 'b', 'c', 'd']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['a', 'b', 'c', 'd']);
     ///
     /// let x = list.position_of_value(&'x');
     /// assert_eq!(x, None);
@@ -121,7 +121,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['a', 'b', 'c', 'd']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['a', 'b', 'c', 'd']);
     ///
     /// let x = list.idx_of(&'x');
     /// assert!(x.is_none());
@@ -183,7 +183,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['a', 'b', 'c', 'd']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['a', 'b', 'c', 'd']);
     ///
     /// let x = list.position_of_value(&'x');
     /// assert_eq!(x, None);
@@ -212,7 +212,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['a', 'b', 'c', 'd']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['a', 'b', 'c', 'd']);
     ///
     /// let x = list.idx_of(&'x');
     /// assert!(x.is_none());
@@ -276,7 +276,7 @@ where
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = DoublyList::from_iter(['a', 'b', 'c', 'd']);
+    /// let mut list: DoublyList<_> = DoublyList::from_iter(['a', 'b', 'c', 'd']);
     ///
     /// let x = list.position_of_from_back(&'x');
     /// assert_eq!(x, None);
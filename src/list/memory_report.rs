@@ -0,0 +1,171 @@
+use super::List;
+use crate::variant::ListVariant;
+use alloc::vec::Vec;
+use core::mem::size_of;
+use orx_fixed_vec::FixedVec;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+use orx_split_vec::{Growth, SplitVec};
+
+/// Statistics on the memory used by the underlying storage of a linked list,
+/// which is created by the [`memory_report`] method.
+///
+/// [`memory_report`]: crate::List::memory_report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryReport {
+    /// Number of active nodes; i.e., the length of the list.
+    pub num_active_nodes: usize,
+    /// Number of closed nodes which are removed from the list but still occupy positions in the storage.
+    pub num_closed_nodes: usize,
+    /// Number of nodes that the underlying storage can hold without allocating.
+    pub capacity: usize,
+    /// Size of a node in bytes, including the element and the links.
+    pub node_size_bytes: usize,
+    /// Size of the element stored in a node in bytes.
+    pub element_size_bytes: usize,
+    /// Bytes per node used by the links and the bookkeeping of the node; i.e., `node_size_bytes - element_size_bytes`.
+    pub link_overhead_bytes: usize,
+    /// Number of nodes stored in each contiguous fragment of the storage holding nodes, in storage order.
+    ///
+    /// A `FixedVec` storage has a single fragment, while a `SplitVec` storage might have many.
+    /// Allocated fragments which do not hold any node are not included;
+    /// see [`fragment_capacities`] for all allocated fragments of these storages.
+    ///
+    /// [`fragment_capacities`]: crate::List::fragment_capacities
+    pub fragment_lengths: Vec<usize>,
+    /// Bytes allocated for the nodes; i.e., `capacity * node_size_bytes`.
+    pub allocated_bytes: usize,
+    /// Estimated number of bytes that would be made reusable for new nodes by [`reclaim_closed_nodes`];
+    /// i.e., `num_closed_nodes * node_size_bytes`.
+    ///
+    /// Note that reclaiming does not release the allocated memory; see [`shrink_to_fit`] for the latter.
+    ///
+    /// [`reclaim_closed_nodes`]: crate::List::reclaim_closed_nodes
+    /// [`shrink_to_fit`]: crate::List::shrink_to_fit
+    pub reclaimable_bytes: usize,
+}
+
+impl MemoryReport {
+    /// Number of contiguous fragments of the storage holding nodes.
+    pub fn num_fragments(&self) -> usize {
+        self.fragment_lengths.len()
+    }
+}
+
+impl<V, M, P> List<V, M, P>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// ***O(f)*** Creates a report on the memory used by the underlying storage of the list,
+    /// where f is the number of fragments of the storage.
+    ///
+    /// Unlike [`node_utilization`] which only counts the nodes, the report additionally provides
+    /// the capacity, per node sizes, the fragments of the storage and
+    /// the estimated gain of reclaiming closed nodes.
+    ///
+    /// [`node_utilization`]: crate::List::node_utilization
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyListLazy<u64, _> = DoublyListLazy::with_fixed_capacity(16);
+    /// list.extend(0..10);
+    /// list.pop_front();
+    /// list.pop_back();
+    ///
+    /// let report = list.memory_report();
+    /// assert_eq!(report.num_active_nodes, 8);
+    /// assert_eq!(report.num_closed_nodes, 2);
+    /// assert_eq!(report.capacity, 16);
+    /// assert_eq!(report.element_size_bytes, 8);
+    /// assert_eq!(report.fragment_lengths, vec![10]);
+    /// assert_eq!(report.reclaimable_bytes, 2 * report.node_size_bytes);
+    ///
+    /// let report = format!("{:?}", report);
+    /// assert!(report.contains("num_closed_nodes: 2"));
+    /// ```
+    pub fn memory_report(&self) -> MemoryReport {
+        let nodes = self.0.nodes();
+        let num_active_nodes = self.len();
+        let num_closed_nodes = nodes.len() - num_active_nodes;
+        let capacity = nodes.capacity();
+
+        let node_size_bytes = size_of::<Node<V>>();
+        let element_size_bytes = size_of::<V::Item>();
+        let fragment_lengths = nodes.slices(..).into_iter().map(|x| x.len()).collect();
+
+        MemoryReport {
+            num_active_nodes,
+            num_closed_nodes,
+            capacity,
+            node_size_bytes,
+            element_size_bytes,
+            link_overhead_bytes: node_size_bytes - element_size_bytes,
+            fragment_lengths,
+            allocated_bytes: capacity * node_size_bytes,
+            reclaimable_bytes: num_closed_nodes * node_size_bytes,
+        }
+    }
+}
+
+impl<V, M> List<V, M, FixedVec<Node<V>>>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+{
+    /// ***O(1)*** Returns the capacity of each allocated fragment of the underlying storage, in storage order;
+    /// which is the single fixed capacity of the `FixedVec`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: DoublyList<char, _> = DoublyList::with_fixed_capacity(16);
+    /// assert_eq!(list.fragment_capacities(), vec![16]);
+    /// ```
+    pub fn fragment_capacities(&self) -> Vec<usize> {
+        alloc::vec![self.0.nodes().capacity()]
+    }
+}
+
+impl<V, M, G> List<V, M, SplitVec<Node<V>, G>>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    G: Growth,
+{
+    /// ***O(f)*** Returns the capacity of each allocated fragment of the underlying `SplitVec`, in storage order,
+    /// where f is the number of fragments.
+    ///
+    /// Unlike the [`fragment_lengths`] of the memory report, fragments which are allocated
+    /// but do not yet hold any node are included.
+    ///
+    /// [`fragment_lengths`]: crate::MemoryReport::fragment_lengths
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<u64> = SinglyList::new();
+    /// assert_eq!(list.memory_report().num_fragments(), 0);
+    /// assert_eq!(list.fragment_capacities(), vec![list.capacity()]);
+    ///
+    /// list.extend(0..10);
+    /// let capacities = list.fragment_capacities();
+    /// assert_eq!(capacities.iter().sum::<usize>(), list.capacity());
+    /// ```
+    pub fn fragment_capacities(&self) -> Vec<usize> {
+        self.0
+            .nodes()
+            .fragments()
+            .iter()
+            .map(|x| x.capacity())
+            .collect()
+    }
+}
@@ -1,12 +1,14 @@
 use super::{List, helper_traits::HasDoublyEnds};
 use crate::{
-    ListSliceMut,
+    DoublyIterable, ListChunksMut, ListSliceMut,
+    list::iter_traits::chunk_ends::{position_ends, positions},
     type_aliases::{BACK_IDX, DoublyIdx, FRONT_IDX},
     variant::Doubly,
 };
+use alloc::vec::Vec;
 use core::ops::RangeBounds;
 use orx_pinned_vec::PinnedVec;
-use orx_selfref_col::{MemoryPolicy, Node, NodeIdx, Refs};
+use orx_selfref_col::{MemoryPolicy, Node, NodeIdx, NodePtr, Refs};
 
 impl<T, M, P> List<Doubly<T>, M, P>
 where
@@ -147,6 +149,106 @@ where
         NodeIdx::new(self.0.memory_state(), idx)
     }
 
+    /// ***O(n)*** Pushes all elements of the `iter` to the `front` of the list, preserving their order;
+    /// and returns the indices of the inserted nodes, in the order of the `iter`.
+    ///
+    /// Note that this is different than calling `push_front` for each element,
+    /// which would place the elements to the front in the reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = [3, 4].into_iter().collect();
+    ///
+    /// let idx = list.push_front_iter([0, 1, 2]);
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 3, 4]));
+    ///
+    /// assert_eq!(idx.len(), 3);
+    /// assert_eq!(list.get(idx[1]), Some(&1));
+    ///
+    /// let slice = list.slice(idx[0]..=idx[2]);
+    /// assert!(slice.eq_to_iter_vals([0, 1, 2]));
+    /// ```
+    pub fn push_front_iter<I>(&mut self, iter: I) -> Vec<DoublyIdx<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let front = self.0.ends().get(FRONT_IDX);
+        self.insert_iter_between(None, front, iter)
+    }
+
+    /// ***O(n)*** Pushes all elements of the `iter` to the `back` of the list;
+    /// and returns the indices of the inserted nodes, in the order of the `iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = [0, 1].into_iter().collect();
+    ///
+    /// let idx = list.push_back_iter([2, 3, 4]);
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 3, 4]));
+    ///
+    /// assert_eq!(idx.len(), 3);
+    /// assert_eq!(list.get(idx[0]), Some(&2));
+    ///
+    /// let slice = list.slice(idx[0]..);
+    /// assert!(slice.eq_to_iter_vals([2, 3, 4]));
+    /// ```
+    pub fn push_back_iter<I>(&mut self, iter: I) -> Vec<DoublyIdx<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let back = self.0.ends().get(BACK_IDX);
+        self.insert_iter_between(back, None, iter)
+    }
+
+    /// Inserts the elements of the `iter` in order between the nodes `prev` and `next`,
+    /// which must be consecutive nodes, or ends of the list when None;
+    /// and returns the indices of the inserted nodes.
+    pub(crate) fn insert_iter_between<I>(
+        &mut self,
+        prev: Option<NodePtr<Doubly<T>>>,
+        next: Option<NodePtr<Doubly<T>>>,
+        iter: I,
+    ) -> Vec<DoublyIdx<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let state = self.0.memory_state();
+        let mut indices = Vec::with_capacity(iter.size_hint().0);
+
+        let mut last = prev;
+        for value in iter {
+            let idx = self.0.push(value);
+            match last {
+                Some(last) => {
+                    self.0.node_mut(last).next_mut().set_some(idx);
+                    self.0.node_mut(idx).prev_mut().set_some(last);
+                }
+                None => self.0.ends_mut().set_some(FRONT_IDX, idx),
+            }
+            indices.push(NodeIdx::new(state, idx));
+            last = Some(idx);
+        }
+
+        if let Some(last) = last.filter(|_| !indices.is_empty()) {
+            match next {
+                Some(next) => {
+                    self.0.node_mut(last).next_mut().set_some(next);
+                    self.0.node_mut(next).prev_mut().set_some(last);
+                }
+                None => self.0.ends_mut().set_some(BACK_IDX, last),
+            }
+        }
+
+        indices
+    }
+
     /// ***O(1)*** Pops and returns the value at the `front` of the list; returns None if the list is empty.
     ///
     /// # Examples
@@ -288,4 +390,70 @@ where
         let ends = self.slice_ends(range).expect("invalid indices in range");
         ListSliceMut { list: self, ends }
     }
+
+    /// Creates and returns a mutable slice of the list between the given `range` of positions.
+    ///
+    /// ***O(n)*** since the slice boundaries are found by traversing the list from the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if it starts after it ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..8).collect();
+    ///
+    /// let mut slice = list.slice_mut_at(2..5);
+    /// assert!(slice.eq_to_iter_vals([2, 3, 4]));
+    ///
+    /// for x in slice.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 20, 30, 40, 5, 6, 7]));
+    /// ```
+    pub fn slice_mut_at<R>(&mut self, range: R) -> ListSliceMut<'_, Doubly<T>, M, P>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (begin, end) = positions(range, self.len());
+        let ends = position_ends(self.iter_ptr(), begin, end);
+        ListSliceMut { list: self, ends }
+    }
+
+    /// Returns consecutive chunks of the list as mutable slices, from front to back.
+    /// Each chunk has `chunk_size` elements, except for the last chunk which might be shorter.
+    ///
+    /// Since each mutable slice mutably borrows the list, chunks are pulled one at a time
+    /// by [`ListChunksMut::next_chunk`] rather than by an `Iterator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..7).collect();
+    ///
+    /// let mut chunks = list.chunks_mut(3);
+    /// while let Some(mut chunk) = chunks.next_chunk() {
+    ///     let first = *chunk.front().unwrap();
+    ///     for x in chunk.iter_mut() {
+    ///         *x -= first;
+    ///     }
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 0, 1, 2, 0]));
+    /// ```
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ListChunksMut<'_, Doubly<T>, M, P> {
+        let front = self.0.ends().get(FRONT_IDX);
+        let back = self.0.ends().get(BACK_IDX);
+        ListChunksMut::new(self, front, back, chunk_size)
+    }
 }
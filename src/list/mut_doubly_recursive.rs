@@ -3,6 +3,7 @@ use crate::{
     type_aliases::{BACK_IDX, FRONT_IDX},
     variant::Doubly,
 };
+use orx_pinned_vec::PinnedVec;
 use orx_selfref_col::{MemoryPolicy, Node, Refs};
 use orx_split_vec::{Recursive, SplitVec};
 
@@ -37,7 +38,10 @@ where
         let (col, other_state) = other.0.into_inner();
         let (nodes, ends, _len) = col.into_inner();
 
-        self.0.append_nodes(nodes);
+        // an empty storage would leave an empty fragment in the middle of the nodes
+        if !nodes.is_empty() {
+            self.0.append_nodes(nodes);
+        }
 
         let old_front_exists = !self.0.ends().is_empty();
         let new_front_exists = !ends.is_empty();
@@ -46,7 +50,9 @@ where
             (_, false) => { /* no update when new is empty */ }
             (false, true) => {
                 let new_front = ends.get(FRONT_IDX).expect("exists");
+                let new_back = ends.get(BACK_IDX).expect("exists");
                 self.0.ends_mut().set_some(FRONT_IDX, new_front);
+                self.0.ends_mut().set_some(BACK_IDX, new_back);
             }
             (true, true) => {
                 let new_front = ends.get(FRONT_IDX).expect("exists");
@@ -96,7 +102,10 @@ where
         let (col, other_state) = other.0.into_inner();
         let (nodes, ends, _len) = col.into_inner();
 
-        self.0.append_nodes(nodes);
+        // an empty storage would leave an empty fragment in the middle of the nodes
+        if !nodes.is_empty() {
+            self.0.append_nodes(nodes);
+        }
 
         let old_back_exists = !self.0.ends().is_empty();
         let new_back_exists = !ends.is_empty();
@@ -104,7 +113,9 @@ where
         match (old_back_exists, new_back_exists) {
             (_, false) => { /* no update when new is empty */ }
             (false, true) => {
+                let new_front = ends.get(FRONT_IDX).expect("exists");
                 let new_back = ends.get(BACK_IDX).expect("exists");
+                self.0.ends_mut().set_some(FRONT_IDX, new_front);
                 self.0.ends_mut().set_some(BACK_IDX, new_back);
             }
             (true, true) => {
@@ -0,0 +1,255 @@
+use crate::{
+    CapacityError, DoublyEnds, DoublyIdx, InsertError, List, SinglyIdx,
+    variant::{Doubly, Singly},
+};
+use orx_fixed_vec::FixedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+// singly
+
+impl<T, M> List<Singly<T>, M, FixedVec<Node<Singly<T>>>>
+where
+    M: MemoryPolicy<Singly<T>>,
+{
+    /// ***O(1)*** Pushes the `value` to the `front` of the list and returns its index;
+    /// or returns back the `value` wrapped in a [`CapacityError`] without changing the list
+    /// if the fixed capacity of the list is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = SinglyList::with_fixed_capacity(2);
+    ///
+    /// assert!(list.try_push_front('b').is_ok());
+    /// assert!(list.try_push_front('a').is_ok());
+    /// assert_eq!(list.try_push_front('x'), Err(CapacityError('x')));
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b']));
+    /// ```
+    pub fn try_push_front(&mut self, value: T) -> Result<SinglyIdx<T>, CapacityError<T>> {
+        match self.has_capacity_for_one() {
+            true => Ok(self.push_front(value)),
+            false => Err(CapacityError(value)),
+        }
+    }
+
+    /// ***O(n)*** Inserts the given `value` at the `position`-th element of the list and returns its index;
+    /// or returns back the `value` wrapped in an [`InsertError`] without changing the list:
+    /// * [`InsertError::OutOfBounds`] if `position > self.len()`,
+    /// * [`InsertError::NoCapacity`] if the fixed capacity of the list is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = SinglyList::with_fixed_capacity(3);
+    /// list.push_front('c');
+    /// list.push_front('a');
+    ///
+    /// assert_eq!(list.try_insert_at(3, 'x'), Err(InsertError::OutOfBounds('x')));
+    /// assert!(list.try_insert_at(1, 'b').is_ok());
+    ///
+    /// let error = list.try_insert_at(3, 'd');
+    /// assert_eq!(error, Err(InsertError::NoCapacity(CapacityError('d'))));
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c']));
+    /// ```
+    pub fn try_insert_at(
+        &mut self,
+        position: usize,
+        value: T,
+    ) -> Result<SinglyIdx<T>, InsertError<T>> {
+        match (position <= self.len(), self.has_capacity_for_one()) {
+            (false, _) => Err(InsertError::OutOfBounds(value)),
+            (true, false) => Err(CapacityError(value).into()),
+            (true, true) => Ok(self.insert_at(position, value)),
+        }
+    }
+}
+
+// doubly
+
+impl<T, M> List<Doubly<T>, M, FixedVec<Node<Doubly<T>>>>
+where
+    M: MemoryPolicy<Doubly<T>>,
+{
+    /// ***O(1)*** Pushes the `value` to the `front` of the list and returns its index;
+    /// or returns back the `value` wrapped in a [`CapacityError`] without changing the list
+    /// if the fixed capacity of the list is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = DoublyList::with_fixed_capacity(2);
+    ///
+    /// assert!(list.try_push_front('b').is_ok());
+    /// assert!(list.try_push_front('a').is_ok());
+    /// assert_eq!(list.try_push_front('x'), Err(CapacityError('x')));
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b']));
+    /// ```
+    pub fn try_push_front(&mut self, value: T) -> Result<DoublyIdx<T>, CapacityError<T>> {
+        match self.has_capacity_for_one() {
+            true => Ok(self.push_front(value)),
+            false => Err(CapacityError(value)),
+        }
+    }
+
+    /// ***O(1)*** Pushes the `value` to the `back` of the list and returns its index;
+    /// or returns back the `value` wrapped in a [`CapacityError`] without changing the list
+    /// if the fixed capacity of the list is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = DoublyList::with_fixed_capacity(2);
+    ///
+    /// assert!(list.try_push_back('a').is_ok());
+    /// assert!(list.try_push_back('b').is_ok());
+    ///
+    /// let value = match list.try_push_back('x') {
+    ///     Ok(_) => None,
+    ///     Err(error) => Some(error.into_inner()),
+    /// };
+    /// assert_eq!(value, Some('x'));
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b']));
+    /// ```
+    pub fn try_push_back(&mut self, value: T) -> Result<DoublyIdx<T>, CapacityError<T>> {
+        match self.has_capacity_for_one() {
+            true => Ok(self.push_back(value)),
+            false => Err(CapacityError(value)),
+        }
+    }
+
+    /// ***O(n)*** Inserts the given `value` at the `position`-th element of the list and returns its index;
+    /// or returns back the `value` wrapped in an [`InsertError`] without changing the list:
+    /// * [`InsertError::OutOfBounds`] if `position > self.len()`,
+    /// * [`InsertError::NoCapacity`] if the fixed capacity of the list is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = DoublyList::with_fixed_capacity(3);
+    /// list.push_back('a');
+    /// list.push_back('c');
+    ///
+    /// assert_eq!(list.try_insert_at(3, 'x'), Err(InsertError::OutOfBounds('x')));
+    /// assert!(list.try_insert_at(1, 'b').is_ok());
+    ///
+    /// let error = list.try_insert_at(3, 'd');
+    /// assert_eq!(error, Err(InsertError::NoCapacity(CapacityError('d'))));
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c']));
+    /// ```
+    pub fn try_insert_at(
+        &mut self,
+        position: usize,
+        value: T,
+    ) -> Result<DoublyIdx<T>, InsertError<T>> {
+        match (position <= self.len(), self.has_capacity_for_one()) {
+            (false, _) => Err(InsertError::OutOfBounds(value)),
+            (true, false) => Err(CapacityError(value).into()),
+            (true, true) => Ok(self.insert_at(position, value)),
+        }
+    }
+
+    /// ***O(1)*** Inserts the given `value` as the next of the node with the given `idx` and returns its index;
+    /// or returns back the `value` wrapped in an [`InsertError`] without changing the list:
+    /// * [`InsertError::InvalidIdx`] if the `idx` is invalid (`idx_err` is not None for the index),
+    ///   or the element with the given `idx` is already removed from the list,
+    /// * [`InsertError::NoCapacity`] if the fixed capacity of the list is exhausted.
+    ///
+    /// This is the fallible counterpart of [`insert_next_to`] for lists with a fixed capacity.
+    /// Note that [`try_insert_next_to`] is available for all lists, which only checks the `idx`,
+    /// and which panics if the fixed capacity is exhausted.
+    ///
+    /// [`insert_next_to`]: crate::List::insert_next_to
+    /// [`try_insert_next_to`]: crate::List::try_insert_next_to
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = DoublyListLazy::with_fixed_capacity(3);
+    /// let a = list.push_back('a');
+    /// let c = list.push_back('c');
+    /// list.remove(c);
+    ///
+    /// let error = list.try_insert_next_to_within_capacity(c, 'x');
+    /// assert_eq!(error, Err(InsertError::InvalidIdx(NodeIdxError::RemovedNode, 'x')));
+    ///
+    /// assert!(list.try_insert_next_to_within_capacity(a, 'b').is_ok());
+    ///
+    /// let error = list.try_insert_next_to_within_capacity(a, 'y');
+    /// assert_eq!(error, Err(InsertError::NoCapacity(CapacityError('y'))));
+    ///
+    /// assert!(list.eq_to_iter_vals(['a', 'b']));
+    /// ```
+    pub fn try_insert_next_to_within_capacity(
+        &mut self,
+        idx: DoublyIdx<T>,
+        value: T,
+    ) -> Result<DoublyIdx<T>, InsertError<T>> {
+        match (self.idx_err(idx), self.has_capacity_for_one()) {
+            (Some(error), _) => Err(InsertError::InvalidIdx(error, value)),
+            (None, false) => Err(CapacityError(value).into()),
+            (None, true) => Ok(self.insert_next_to(idx, value)),
+        }
+    }
+
+    /// ***O(1)*** Inserts the given `value` as the previous of the node with the given `idx` and returns its index;
+    /// or returns back the `value` wrapped in an [`InsertError`] without changing the list:
+    /// * [`InsertError::InvalidIdx`] if the `idx` is invalid (`idx_err` is not None for the index),
+    ///   or the element with the given `idx` is already removed from the list,
+    /// * [`InsertError::NoCapacity`] if the fixed capacity of the list is exhausted.
+    ///
+    /// This is the fallible counterpart of [`insert_prev_to`] for lists with a fixed capacity.
+    /// Note that [`try_insert_prev_to`] is available for all lists, which only checks the `idx`,
+    /// and which panics if the fixed capacity is exhausted.
+    ///
+    /// [`insert_prev_to`]: crate::List::insert_prev_to
+    /// [`try_insert_prev_to`]: crate::List::try_insert_prev_to
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = DoublyListLazy::with_fixed_capacity(3);
+    /// let a = list.push_back('a');
+    /// let c = list.push_back('c');
+    /// list.remove(a);
+    ///
+    /// let error = list.try_insert_prev_to_within_capacity(a, 'x');
+    /// assert_eq!(error, Err(InsertError::InvalidIdx(NodeIdxError::RemovedNode, 'x')));
+    ///
+    /// assert!(list.try_insert_prev_to_within_capacity(c, 'b').is_ok());
+    ///
+    /// let error = list.try_insert_prev_to_within_capacity(c, 'y');
+    /// assert_eq!(error, Err(InsertError::NoCapacity(CapacityError('y'))));
+    ///
+    /// assert!(list.eq_to_iter_vals(['b', 'c']));
+    /// ```
+    pub fn try_insert_prev_to_within_capacity(
+        &mut self,
+        idx: DoublyIdx<T>,
+        value: T,
+    ) -> Result<DoublyIdx<T>, InsertError<T>> {
+        match (self.idx_err(idx), self.has_capacity_for_one()) {
+            (Some(error), _) => Err(InsertError::InvalidIdx(error, value)),
+            (None, false) => Err(CapacityError(value).into()),
+            (None, true) => Ok(self.insert_prev_to(idx, value)),
+        }
+    }
+}
@@ -1,7 +1,13 @@
-use super::List;
-use crate::{SinglyIdx, iter::SinglyIterMut, variant::Singly};
+use super::{List, helper_traits::HasSinglyEnds};
+use crate::{
+    ListChunksMut, ListSliceMut, SinglyIdx, SinglyIterable,
+    iter::{SinglyIterMut, SinglyIterPtr},
+    list::iter_traits::chunk_ends::{position_ends, positions},
+    variant::Singly,
+};
+use core::ops::RangeBounds;
 use orx_pinned_vec::PinnedVec;
-use orx_selfref_col::{MemoryPolicy, Node, NodeIdx, Refs};
+use orx_selfref_col::{MemoryPolicy, Node, NodeIdx, NodePtr, Refs};
 
 impl<T, M, P> List<Singly<T>, M, P>
 where
@@ -69,6 +75,66 @@ where
         NodeIdx::new(self.0.memory_state(), idx)
     }
 
+    /// ***O(n)*** Pushes all elements of the `iter` to the `front` of the list, preserving their order.
+    ///
+    /// Note that this is different than calling `push_front` for each element,
+    /// which would place the elements to the front in the reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<_> = [3, 4].into_iter().collect();
+    ///
+    /// list.extend_front([0, 1, 2]);
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 3, 4]));
+    ///
+    /// let mut other: SinglyList<_> = [3, 4].into_iter().collect();
+    /// for x in [0, 1, 2] {
+    ///     other.push_front(x);
+    /// }
+    /// assert!(other.eq_to_iter_vals([2, 1, 0, 3, 4]));
+    /// ```
+    pub fn extend_front<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.insert_iter_after(None, iter);
+    }
+
+    /// Returns the pointer to the last node of the list by traversing it from the front;
+    /// None if the list is empty.
+    pub(crate) fn back_ptr(&self) -> Option<NodePtr<Singly<T>>> {
+        SinglyIterPtr::new(&self.0, self.0.ends().get(), None).last()
+    }
+
+    /// Inserts the elements of the `iter` in order immediately after the node `prev`;
+    /// or to the front of the list if `prev` is None.
+    pub(crate) fn insert_iter_after<I>(&mut self, prev: Option<NodePtr<Singly<T>>>, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let next = match prev {
+            Some(prev) => self.0.node(prev).next().get(),
+            None => self.0.ends().get(),
+        };
+
+        let mut last = prev;
+        for value in iter {
+            let idx = self.0.push(value);
+            match last {
+                Some(last) => self.0.node_mut(last).next_mut().set_some(idx),
+                None => self.0.ends_mut().set_some(idx),
+            }
+            last = Some(idx);
+        }
+
+        if let Some(last) = last.filter(|x| Some(*x) != prev) {
+            self.0.node_mut(last).next_mut().set(next);
+        }
+    }
+
     /// ***O(1)*** Pops and returns the value at the `front` of the list; returns None if the list is empty.
     ///
     /// # Examples
@@ -121,4 +187,130 @@ where
     pub fn iter_mut(&mut self) -> SinglyIterMut<'_, T, P> {
         SinglyIterMut::new_old(&mut self.0)
     }
+
+    /// Creates and returns a mutable slice of the list between the given `range` of indices.
+    ///
+    /// Note that a linked list slice itself also behaves like a linked list,
+    /// reflecting the recursive nature of the data type.
+    /// However, it does not own the data.
+    /// It is rather a view, like a slice is a view to a vec.
+    ///
+    /// Singly linked list slices are forward-only.
+    /// Slicing is ***O(1)*** unless the end of the `range` is excluded.
+    /// Since nodes do not know their previous, the last element of a range such as `a..b`
+    /// is found by a forward search from `a`, which requires ***O(n)*** time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of indices of the range bounds is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<_> = (0..8).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let mut slice = list.slice_mut(idx[2]..idx[5]);
+    /// assert!(slice.eq_to_iter_vals([2, 3, 4]));
+    ///
+    /// *slice.front_mut().unwrap() = 20;
+    /// for x in slice.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 200, 30, 40, 5, 6, 7]));
+    /// ```
+    ///
+    /// Note that the linked list and its slices are directed.
+    /// Therefore, if the end of the `range` is before the beginning,
+    /// the slice will continue until the end of the list.
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// // a..b where b comes later, hence, we get the slice a..b
+    /// let slice = list.slice_mut(idx[1]..idx[4]);
+    /// assert!(slice.eq_to_iter_vals([1, 2, 3]));
+    ///
+    /// // a..b where b comes earlier, then, we get the slice a..
+    /// let slice = list.slice_mut(idx[4]..idx[1]);
+    /// assert!(slice.eq_to_iter_vals([4, 5, 6, 7, 8, 9]));
+    /// ```
+    pub fn slice_mut<R>(&mut self, range: R) -> ListSliceMut<'_, Singly<T>, M, P>
+    where
+        R: RangeBounds<SinglyIdx<T>>,
+    {
+        let ends = self.slice_ends(range).expect("invalid indices in range");
+        ListSliceMut { list: self, ends }
+    }
+
+    /// Creates and returns a mutable slice of the list between the given `range` of positions.
+    ///
+    /// ***O(n)*** since the slice boundaries are found by traversing the list from the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if it starts after it ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<_> = (0..8).collect();
+    ///
+    /// let mut slice = list.slice_mut_at(2..5);
+    /// assert!(slice.eq_to_iter_vals([2, 3, 4]));
+    ///
+    /// for x in slice.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 20, 30, 40, 5, 6, 7]));
+    /// ```
+    pub fn slice_mut_at<R>(&mut self, range: R) -> ListSliceMut<'_, Singly<T>, M, P>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (begin, end) = positions(range, self.len());
+        let ends = position_ends(self.iter_ptr(), begin, end);
+        ListSliceMut { list: self, ends }
+    }
+
+    /// Returns consecutive chunks of the list as mutable slices, from front to back.
+    /// Each chunk has `chunk_size` elements, except for the last chunk which might be shorter.
+    ///
+    /// Since each mutable slice mutably borrows the list, chunks are pulled one at a time
+    /// by [`ListChunksMut::next_chunk`] rather than by an `Iterator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<_> = (0..7).collect();
+    ///
+    /// let mut chunks = list.chunks_mut(3);
+    /// while let Some(mut chunk) = chunks.next_chunk() {
+    ///     let first = *chunk.front().unwrap();
+    ///     for x in chunk.iter_mut() {
+    ///         *x -= first;
+    ///     }
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 0, 1, 2, 0]));
+    /// ```
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ListChunksMut<'_, Singly<T>, M, P> {
+        let front = self.0.ends().get();
+        ListChunksMut::new(self, front, None, chunk_size)
+    }
 }
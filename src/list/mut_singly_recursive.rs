@@ -0,0 +1,116 @@
+use super::List;
+use crate::variant::Singly;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+use orx_split_vec::{Recursive, SplitVec};
+
+impl<T, M> List<Singly<T>, M, SplitVec<Node<Singly<T>>, Recursive>>
+where
+    M: MemoryPolicy<Singly<T>>,
+{
+    /// ***O(m)*** Appends the `other` list to the `front` of this list,
+    /// where m is the length of the `other` list.
+    ///
+    /// Time complexity:
+    /// * ***O(1)*** gets `front` of this list, say a,
+    /// * ***O(m)*** gets `back` of the other list by traversing it, say b,
+    /// * ***O(1)*** connects `b -> a`.
+    ///
+    /// Note that the nodes of the other list are not copied;
+    /// the fragments of the recursive `SplitVec` are appended to the storage of this list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = SinglyList::new();
+    /// list.push_front('c');
+    /// list.push_front('b');
+    /// list.push_front('a');
+    ///
+    /// let other = SinglyList::from_iter(['d', 'e'].into_iter());
+    ///
+    /// list.append_front(other);
+    /// assert!(list.eq_to_iter_vals(['d', 'e', 'a', 'b', 'c']));
+    /// ```
+    pub fn append_front<M2: MemoryPolicy<Singly<T>>>(&mut self, other: List<Singly<T>, M2>) {
+        let new_back = other.back_ptr();
+
+        let (col, other_state) = other.0.into_inner();
+        let (nodes, ends, _len) = col.into_inner();
+
+        // an empty storage would leave an empty fragment in the middle of the nodes
+        if !nodes.is_empty() {
+            self.0.append_nodes(nodes);
+        }
+
+        if let (Some(new_front), Some(new_back)) = (ends.get(), new_back) {
+            if let Some(old_front) = self.0.ends().get() {
+                self.0.node_mut(new_back).next_mut().set_some(old_front);
+            }
+            self.0.ends_mut().set_some(new_front);
+        }
+
+        // update state if necessary
+        if other_state != self.memory_state() {
+            self.0.update_state(true);
+            while self.memory_state() == other_state {
+                self.0.update_state(true);
+            }
+        }
+    }
+
+    /// ***O(n)*** Appends the `other` list to the `back` of this list,
+    /// where n is the length of this list.
+    ///
+    /// Time complexity:
+    /// * ***O(n)*** gets `back` of this list by traversing it, say a,
+    /// * ***O(1)*** gets `front` of the other list, say b,
+    /// * ***O(1)*** connects `a -> b`.
+    ///
+    /// Note that the nodes of the other list are not copied;
+    /// the fragments of the recursive `SplitVec` are appended to the storage of this list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = SinglyList::new();
+    /// list.push_front('c');
+    /// list.push_front('b');
+    /// list.push_front('a');
+    ///
+    /// let other = SinglyList::from_iter(['d', 'e'].into_iter());
+    ///
+    /// list.append_back(other);
+    /// assert!(list.eq_to_iter_vals(['a', 'b', 'c', 'd', 'e']));
+    /// ```
+    pub fn append_back<M2: MemoryPolicy<Singly<T>>>(&mut self, other: List<Singly<T>, M2>) {
+        let old_back = self.back_ptr();
+
+        let (col, other_state) = other.0.into_inner();
+        let (nodes, ends, _len) = col.into_inner();
+
+        // an empty storage would leave an empty fragment in the middle of the nodes
+        if !nodes.is_empty() {
+            self.0.append_nodes(nodes);
+        }
+
+        if let Some(new_front) = ends.get() {
+            match old_back {
+                Some(old_back) => self.0.node_mut(old_back).next_mut().set_some(new_front),
+                None => self.0.ends_mut().set_some(new_front),
+            }
+        }
+
+        // update state if necessary
+        if other_state != self.memory_state() {
+            self.0.update_state(true);
+            while self.memory_state() == other_state {
+                self.0.update_state(true);
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
-    DoublyList, DoublyListLazy, DoublyListThreshold, SinglyList, SinglyListLazy,
-    SinglyListThreshold, list::List, variant::ListVariant,
+    DoublyList, DoublyListIncremental, DoublyListLazy, DoublyListThreshold, SinglyList,
+    SinglyListLazy, SinglyListThreshold, list::List, variant::ListVariant,
 };
+use alloc::vec::Vec;
 use orx_fixed_vec::FixedVec;
 use orx_pinned_vec::PinnedVec;
 use orx_selfref_col::{MemoryPolicy, Node, Refs, SelfRefCol};
@@ -66,6 +67,17 @@ impl<T> DoublyList<T> {
     pub fn with_threshold_reclaimer<const D: usize>() -> DoublyListThreshold<D, T> {
         List(SelfRefCol::new())
     }
+
+    /// Creates an empty doubly linked list with incremental memory reclaim policy:
+    /// * memory of removed nodes is reclaimed gradually once utilization falls below 75%;
+    /// * each removal moves at most `K` nodes to reclaim memory.
+    ///
+    /// See [`MemoryReclaimIncremental`] for details and validity of indices.
+    ///
+    /// [`MemoryReclaimIncremental`]: crate::MemoryReclaimIncremental
+    pub fn with_incremental_reclaimer<const K: usize>() -> DoublyListIncremental<K, T> {
+        List(SelfRefCol::new())
+    }
 }
 impl<T> Default for DoublyList<T> {
     fn default() -> Self {
@@ -136,6 +148,29 @@ where
     pub fn with_recursive_growth() -> Self {
         Self::from_empty_pinned_vec(SplitVec::with_recursive_growth())
     }
+
+    /// Creates a linked list that uses a [`SplitVec<T, Recursive>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Recursive.html) as the underlying storage,
+    /// which is the default storage, with an initial capacity of at least `capacity` nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list = DoublyList::with_capacity(100);
+    /// assert!(list.capacity() >= 100);
+    ///
+    /// for i in 0..100 {
+    ///     list.push_back(i);
+    /// }
+    /// assert!(list.eq_to_iter_vals(0..100));
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        match capacity {
+            0 => Self::with_recursive_growth(),
+            n => Self::from_empty_pinned_vec(Vec::with_capacity(n).into()),
+        }
+    }
 }
 
 impl<V, M> List<V, M, SplitVec<Node<V>, Linear>>
@@ -0,0 +1,105 @@
+use crate::{Doubly, DoublyEndsMut, DoublyIterable, List, Singly};
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+/// A single mutation applied to a doubly linked list while generating random lists.
+///
+/// Node references are given as positions which are resolved modulo the length
+/// of the list at the time the operation is applied; hence, every operation is
+/// applicable to any list. Operations which reference a node are skipped on an
+/// empty list, and moves which are not possible are ignored.
+#[derive(Debug, Clone)]
+pub(crate) enum DoublyOp<T> {
+    PushFront(T),
+    PushBack(T),
+    InsertNextTo(usize, T),
+    InsertPrevTo(usize, T),
+    Remove(usize),
+    MoveToFront(usize),
+    MoveToBack(usize),
+    MoveNextTo(usize, usize),
+    MovePrevTo(usize, usize),
+}
+
+/// A single mutation applied to a singly linked list while generating random lists.
+///
+/// Positions are resolved modulo the length of the list at the time the operation
+/// is applied; insertions use the length plus one so that pushing to the back is possible.
+#[derive(Debug, Clone)]
+pub(crate) enum SinglyOp<T> {
+    PushFront(T),
+    InsertAt(usize, T),
+    RemoveAt(usize),
+    PopFront,
+}
+
+impl<T> DoublyOp<T> {
+    /// Applies the operation on the `list`.
+    pub(crate) fn apply<M>(self, list: &mut List<Doubly<T>, M>)
+    where
+        M: MemoryPolicy<Doubly<T>>,
+    {
+        let idx_at = |list: &List<Doubly<T>, M>, i: usize| match list.len() {
+            0 => None,
+            len => list.indices().nth(i % len),
+        };
+
+        match self {
+            Self::PushFront(value) => _ = list.push_front(value),
+            Self::PushBack(value) => _ = list.push_back(value),
+            Self::InsertNextTo(i, value) => match idx_at(list, i) {
+                Some(idx) => _ = list.insert_next_to(idx, value),
+                None => _ = list.push_back(value),
+            },
+            Self::InsertPrevTo(i, value) => match idx_at(list, i) {
+                Some(idx) => _ = list.insert_prev_to(idx, value),
+                None => _ = list.push_front(value),
+            },
+            Self::Remove(i) => {
+                if let Some(idx) = idx_at(list, i) {
+                    _ = list.remove(idx);
+                }
+            }
+            Self::MoveToFront(i) => {
+                if let Some(idx) = idx_at(list, i) {
+                    _ = list.try_move_to_front(idx);
+                }
+            }
+            Self::MoveToBack(i) => {
+                if let Some(idx) = idx_at(list, i) {
+                    _ = list.try_move_to_back(idx);
+                }
+            }
+            Self::MoveNextTo(i, j) => {
+                if let (Some(idx), Some(pivot)) = (idx_at(list, i), idx_at(list, j)) {
+                    _ = list.try_move_next_to(idx, pivot);
+                }
+            }
+            Self::MovePrevTo(i, j) => {
+                if let (Some(idx), Some(pivot)) = (idx_at(list, i), idx_at(list, j)) {
+                    _ = list.try_move_prev_to(idx, pivot);
+                }
+            }
+        }
+    }
+}
+
+impl<T> SinglyOp<T> {
+    /// Applies the operation on the `list`.
+    pub(crate) fn apply<M, P>(self, list: &mut List<Singly<T>, M, P>)
+    where
+        M: MemoryPolicy<Singly<T>>,
+        P: PinnedVec<Node<Singly<T>>>,
+    {
+        match self {
+            Self::PushFront(value) => _ = list.push_front(value),
+            Self::InsertAt(i, value) => _ = list.insert_at(i % (list.len() + 1), value),
+            Self::RemoveAt(i) => {
+                if !list.is_empty() {
+                    _ = list.remove_at(i % list.len());
+                }
+            }
+            Self::PopFront => _ = list.pop_front(),
+        }
+    }
+}
@@ -1,7 +1,11 @@
-use super::List;
-use crate::variant::ListVariant;
+use super::{
+    List,
+    idx_map::{IdxMap, storage_fragments},
+};
+use crate::{Doubly, DoublyIterable, variant::ListVariant};
+use alloc::vec::Vec;
 use orx_pinned_vec::PinnedVec;
-use orx_selfref_col::{MemoryPolicy, MemoryReclaimer, MemoryState, Node};
+use orx_selfref_col::{MemoryPolicy, MemoryReclaimNever, MemoryReclaimer, MemoryState, Node};
 
 impl<V, M, P> List<V, M, P>
 where
@@ -27,3 +31,41 @@ where
         (old, self.0.memory_state())
     }
 }
+
+/// Minimum number of closed nodes required before [`List::reclaim_if_sparse`] reclaims them.
+pub(crate) const MIN_CLOSED_TO_RECLAIM: usize = 32;
+
+impl<T, P> List<Doubly<T>, MemoryReclaimNever, P>
+where
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    /// Reclaims closed nodes and returns the map from the indices obtained before the reclaim to
+    /// the indices of the same elements afterwards; None if the nodes are not reorganized,
+    /// in which case the indices remain valid.
+    pub(crate) fn reclaim_closed_nodes_with_idx_map(&mut self) -> Option<IdxMap<Doubly<T>>> {
+        let old_state = self.memory_state();
+        let old_fragments = storage_fragments(self.0.nodes());
+        let old_ptrs: Vec<_> = self.iter_ptr().collect();
+
+        let (old, new) = self.reclaim_closed_nodes();
+        match old == new {
+            true => None,
+            false => Some(IdxMap::new(
+                old_state,
+                old_fragments,
+                old_ptrs.into_iter().zip(self.indices()),
+            )),
+        }
+    }
+
+    /// Reclaims closed nodes only if there are at least [`MIN_CLOSED_TO_RECLAIM`] of them
+    /// and they outnumber the active nodes; returns the index map as
+    /// [`List::reclaim_closed_nodes_with_idx_map`] does.
+    pub(crate) fn reclaim_if_sparse(&mut self) -> Option<IdxMap<Doubly<T>>> {
+        let num_closed = self.node_utilization().num_closed_nodes;
+        match num_closed >= MIN_CLOSED_TO_RECLAIM && num_closed > self.len() {
+            true => self.reclaim_closed_nodes_with_idx_map(),
+            false => None,
+        }
+    }
+}
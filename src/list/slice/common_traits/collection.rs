@@ -0,0 +1,109 @@
+use crate::{
+    Doubly, DoublyIterableMut, ListSlice, ListSliceMut, Singly, SinglyIterableMut,
+    iter::{DoublyIterMut, SinglyIterMut},
+};
+use orx_iterable::{Collection, CollectionMut};
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+// singly
+
+impl<T, M, P> Collection for ListSlice<'_, Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    type Item = T;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+impl<T, M, P> Collection for ListSliceMut<'_, Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    type Item = T;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+impl<T, M, P> CollectionMut for ListSliceMut<'_, Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    type IterMut<'i>
+        = SinglyIterMut<'i, T, P>
+    where
+        Self: 'i;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        <Self as SinglyIterableMut<T, M, P>>::iter_mut(self)
+    }
+}
+
+// doubly
+
+impl<T, M, P> Collection for ListSlice<'_, Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    type Item = T;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+impl<T, M, P> Collection for ListSliceMut<'_, Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    type Item = T;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+impl<T, M, P> CollectionMut for ListSliceMut<'_, Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    type IterMut<'i>
+        = DoublyIterMut<'i, T, P>
+    where
+        Self: 'i;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        <Self as DoublyIterableMut<T, M, P>>::iter_mut(self)
+    }
+}
@@ -1,45 +1,201 @@
-use crate::{Doubly, DoublyIterable, ListSlice, Singly, SinglyIterable};
+use crate::{Doubly, DoublyIterable, List, ListSlice, ListSliceMut, Singly, SinglyIterable};
 use orx_pinned_vec::PinnedVec;
 use orx_selfref_col::{MemoryPolicy, Node};
 
 // singly
 
-impl<T, M, P> PartialEq for ListSlice<'_, Singly<T>, M, P>
+impl<T, M, P, M2, P2> PartialEq<ListSlice<'_, Singly<T>, M2, P2>> for ListSlice<'_, Singly<T>, M, P>
 where
     T: PartialEq,
     M: MemoryPolicy<Singly<T>>,
     P: PinnedVec<Node<Singly<T>>>,
+    M2: MemoryPolicy<Singly<T>>,
+    P2: PinnedVec<Node<Singly<T>>>,
 {
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &ListSlice<'_, Singly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P, M2, P2> PartialEq<ListSliceMut<'_, Singly<T>, M2, P2>>
+    for ListSlice<'_, Singly<T>, M, P>
+where
+    T: PartialEq,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+    M2: MemoryPolicy<Singly<T>>,
+    P2: PinnedVec<Node<Singly<T>>>,
+{
+    fn eq(&self, other: &ListSliceMut<'_, Singly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P, M2, P2> PartialEq<List<Singly<T>, M2, P2>> for ListSlice<'_, Singly<T>, M, P>
+where
+    T: PartialEq,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+    M2: MemoryPolicy<Singly<T>>,
+    P2: PinnedVec<Node<Singly<T>>>,
+{
+    fn eq(&self, other: &List<Singly<T>, M2, P2>) -> bool {
         self.eq_to_iter_refs(other.iter())
     }
 }
 
 impl<T, M, P> Eq for ListSlice<'_, Singly<T>, M, P>
+where
+    T: Eq,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+}
+
+impl<T, M, P, M2, P2> PartialEq<ListSlice<'_, Singly<T>, M2, P2>>
+    for ListSliceMut<'_, Singly<T>, M, P>
+where
+    T: PartialEq,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+    M2: MemoryPolicy<Singly<T>>,
+    P2: PinnedVec<Node<Singly<T>>>,
+{
+    fn eq(&self, other: &ListSlice<'_, Singly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P, M2, P2> PartialEq<ListSliceMut<'_, Singly<T>, M2, P2>>
+    for ListSliceMut<'_, Singly<T>, M, P>
 where
     T: PartialEq,
     M: MemoryPolicy<Singly<T>>,
     P: PinnedVec<Node<Singly<T>>>,
+    M2: MemoryPolicy<Singly<T>>,
+    P2: PinnedVec<Node<Singly<T>>>,
+{
+    fn eq(&self, other: &ListSliceMut<'_, Singly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P, M2, P2> PartialEq<List<Singly<T>, M2, P2>> for ListSliceMut<'_, Singly<T>, M, P>
+where
+    T: PartialEq,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+    M2: MemoryPolicy<Singly<T>>,
+    P2: PinnedVec<Node<Singly<T>>>,
+{
+    fn eq(&self, other: &List<Singly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P> Eq for ListSliceMut<'_, Singly<T>, M, P>
+where
+    T: Eq,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
 {
 }
 
 // doubly
 
-impl<T, M, P> PartialEq for ListSlice<'_, Doubly<T>, M, P>
+impl<T, M, P, M2, P2> PartialEq<ListSlice<'_, Doubly<T>, M2, P2>> for ListSlice<'_, Doubly<T>, M, P>
 where
     T: PartialEq,
     M: MemoryPolicy<Doubly<T>>,
     P: PinnedVec<Node<Doubly<T>>>,
+    M2: MemoryPolicy<Doubly<T>>,
+    P2: PinnedVec<Node<Doubly<T>>>,
 {
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &ListSlice<'_, Doubly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P, M2, P2> PartialEq<ListSliceMut<'_, Doubly<T>, M2, P2>>
+    for ListSlice<'_, Doubly<T>, M, P>
+where
+    T: PartialEq,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+    M2: MemoryPolicy<Doubly<T>>,
+    P2: PinnedVec<Node<Doubly<T>>>,
+{
+    fn eq(&self, other: &ListSliceMut<'_, Doubly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P, M2, P2> PartialEq<List<Doubly<T>, M2, P2>> for ListSlice<'_, Doubly<T>, M, P>
+where
+    T: PartialEq,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+    M2: MemoryPolicy<Doubly<T>>,
+    P2: PinnedVec<Node<Doubly<T>>>,
+{
+    fn eq(&self, other: &List<Doubly<T>, M2, P2>) -> bool {
         self.eq_to_iter_refs(other.iter())
     }
 }
 
 impl<T, M, P> Eq for ListSlice<'_, Doubly<T>, M, P>
+where
+    T: Eq,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+}
+
+impl<T, M, P, M2, P2> PartialEq<ListSlice<'_, Doubly<T>, M2, P2>>
+    for ListSliceMut<'_, Doubly<T>, M, P>
+where
+    T: PartialEq,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+    M2: MemoryPolicy<Doubly<T>>,
+    P2: PinnedVec<Node<Doubly<T>>>,
+{
+    fn eq(&self, other: &ListSlice<'_, Doubly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P, M2, P2> PartialEq<ListSliceMut<'_, Doubly<T>, M2, P2>>
+    for ListSliceMut<'_, Doubly<T>, M, P>
 where
     T: PartialEq,
     M: MemoryPolicy<Doubly<T>>,
     P: PinnedVec<Node<Doubly<T>>>,
+    M2: MemoryPolicy<Doubly<T>>,
+    P2: PinnedVec<Node<Doubly<T>>>,
+{
+    fn eq(&self, other: &ListSliceMut<'_, Doubly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P, M2, P2> PartialEq<List<Doubly<T>, M2, P2>> for ListSliceMut<'_, Doubly<T>, M, P>
+where
+    T: PartialEq,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+    M2: MemoryPolicy<Doubly<T>>,
+    P2: PinnedVec<Node<Doubly<T>>>,
+{
+    fn eq(&self, other: &List<Doubly<T>, M2, P2>) -> bool {
+        self.eq_to_iter_refs(other.iter())
+    }
+}
+
+impl<T, M, P> Eq for ListSliceMut<'_, Doubly<T>, M, P>
+where
+    T: Eq,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
 {
 }
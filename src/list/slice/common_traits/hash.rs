@@ -0,0 +1,64 @@
+use crate::{Doubly, DoublyIterable, ListSlice, ListSliceMut, Singly, SinglyIterable};
+use core::hash::{Hash, Hasher};
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+// singly
+
+impl<T, M, P> Hash for ListSlice<'_, Singly<T>, M, P>
+where
+    T: Hash,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.iter().count());
+        for x in self.iter() {
+            x.hash(state);
+        }
+    }
+}
+
+impl<T, M, P> Hash for ListSliceMut<'_, Singly<T>, M, P>
+where
+    T: Hash,
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.iter().count());
+        for x in self.iter() {
+            x.hash(state);
+        }
+    }
+}
+
+// doubly
+
+impl<T, M, P> Hash for ListSlice<'_, Doubly<T>, M, P>
+where
+    T: Hash,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.iter().count());
+        for x in self.iter() {
+            x.hash(state);
+        }
+    }
+}
+
+impl<T, M, P> Hash for ListSliceMut<'_, Doubly<T>, M, P>
+where
+    T: Hash,
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.iter().count());
+        for x in self.iter() {
+            x.hash(state);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    Doubly, DoublyIterable, DoublyIterableMut, ListSlice, ListSliceMut, Singly, SinglyIterable,
+    SinglyIterableMut,
+    iter::{DoublyIter, DoublyIterMut, SinglyIter, SinglyIterMut},
+};
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+// singly
+
+impl<'a, T, M, P> IntoIterator for &'a ListSlice<'_, Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    type Item = &'a T;
+
+    type IntoIter = SinglyIter<'a, T, P>;
+
+    /// Returns a forward iterator to references of elements of the slice from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, M, P> IntoIterator for &'a ListSliceMut<'_, Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    type Item = &'a T;
+
+    type IntoIter = SinglyIter<'a, T, P>;
+
+    /// Returns a forward iterator to references of elements of the slice from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, M, P> IntoIterator for &'a mut ListSliceMut<'_, Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = SinglyIterMut<'a, T, P>;
+
+    /// Returns a forward iterator to mutable references of elements of the slice from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// doubly
+
+impl<'a, T, M, P> IntoIterator for &'a ListSlice<'_, Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    type Item = &'a T;
+
+    type IntoIter = DoublyIter<'a, T, P>;
+
+    /// Returns a forward iterator to references of elements of the slice from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, M, P> IntoIterator for &'a ListSliceMut<'_, Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    type Item = &'a T;
+
+    type IntoIter = DoublyIter<'a, T, P>;
+
+    /// Returns a forward iterator to references of elements of the slice from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, M, P> IntoIterator for &'a mut ListSliceMut<'_, Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    type Item = &'a mut T;
+
+    type IntoIter = DoublyIterMut<'a, T, P>;
+
+    /// Returns a forward iterator to mutable references of elements of the slice from front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
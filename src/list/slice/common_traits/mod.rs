@@ -1 +1,5 @@
+mod collection;
 mod eq;
+mod hash;
+mod into_iter;
+mod ord;
@@ -7,25 +7,22 @@ where
     V: ListVariant,
     M: MemoryPolicy<V>,
 {
-    /// Returns the number of elements in the list.
+    /// Returns whether or not the slice is empty.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use orx_linked_list::*;
     ///
-    /// let mut list = SinglyList::new();
+    /// let list: DoublyList<_> = (0..4).collect();
+    /// let idx: Vec<_> = list.indices().collect();
     ///
-    /// assert!(list.is_empty());
-    ///
-    /// list.push_front('a');
-    /// assert!(!list.is_empty());
-    ///
-    /// _ = list.pop_front();
-    /// assert!(list.is_empty());
+    /// assert!(!list.slice(..).is_empty());
+    /// assert!(!list.slice(idx[1]..idx[2]).is_empty());
+    /// assert!(list.slice(idx[1]..idx[1]).is_empty());
     /// ```
     #[inline(always)]
-    pub fn is_empty(&self) -> bool where {
-        !self.ends.is_empty()
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
     }
 }
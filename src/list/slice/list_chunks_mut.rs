@@ -0,0 +1,110 @@
+use super::ListSliceMut;
+use crate::{
+    List,
+    list::iter_traits::chunk_ends::slice_ends,
+    type_aliases::{DefaultMemory, DefaultPinVec},
+    variant::ListVariant,
+};
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node, NodePtr, RefsSingle};
+
+/// Mutable chunks of a linked list, created by the `chunks_mut` method.
+///
+/// Each chunk is a [`ListSliceMut`] of at most `chunk_size` consecutive elements.
+///
+/// Since a mutable slice mutably borrows the list, two chunks cannot be alive at the same time.
+/// Therefore, `ListChunksMut` is not an `Iterator`; chunks are rather pulled one at a time
+/// by the [`next_chunk`] method.
+///
+/// [`next_chunk`]: crate::ListChunksMut::next_chunk
+///
+/// # Examples
+///
+/// ```rust
+/// use orx_linked_list::*;
+///
+/// let mut list: DoublyList<_> = (0..7).collect();
+///
+/// let mut chunks = list.chunks_mut(3);
+/// while let Some(mut chunk) = chunks.next_chunk() {
+///     chunk.reverse();
+/// }
+///
+/// assert!(list.eq_to_iter_vals([2, 1, 0, 5, 4, 3, 6]));
+/// ```
+pub struct ListChunksMut<'a, V, M = DefaultMemory<V>, P = DefaultPinVec<V>>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    list: &'a mut List<V, M, P>,
+    current: Option<NodePtr<V>>,
+    last: Option<NodePtr<V>>,
+    chunk_size: usize,
+}
+
+impl<'a, V, M, P> ListChunksMut<'a, V, M, P>
+where
+    V: ListVariant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Creates chunks starting at `current` and ending at `last`;
+    /// or continuing until the end of the list if `last` is None.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub(crate) fn new(
+        list: &'a mut List<V, M, P>,
+        current: Option<NodePtr<V>>,
+        last: Option<NodePtr<V>>,
+        chunk_size: usize,
+    ) -> Self {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        Self {
+            list,
+            current,
+            last,
+            chunk_size,
+        }
+    }
+}
+
+impl<V, M, P> ListChunksMut<'_, V, M, P>
+where
+    V: ListVariant<Next = RefsSingle<V>>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Returns the next chunk as a mutable slice; returns None if all chunks are consumed.
+    ///
+    /// The boundaries of the following chunk are determined before the current chunk is returned.
+    /// Therefore, structural mutations applied on a chunk do not change the elements of the chunks that follow.
+    pub fn next_chunk(&mut self) -> Option<ListSliceMut<'_, V, M, P>> {
+        let front = self.current?;
+
+        let mut back = front;
+        for _ in 1..self.chunk_size {
+            match Some(back) == self.last {
+                true => break,
+                false => match self.list.0.node(back).next().get() {
+                    Some(next) => back = next,
+                    None => break,
+                },
+            }
+        }
+
+        self.current = match Some(back) == self.last {
+            true => None,
+            false => self.list.0.node(back).next().get(),
+        };
+
+        let ends = slice_ends(front, back);
+        Some(ListSliceMut {
+            list: self.list,
+            ends,
+        })
+    }
+}
@@ -1,11 +1,11 @@
 use crate::{
     Doubly, Singly,
     list::helper_traits::{HasCol, HasDoublyEnds, HasSinglyEnds},
-    type_aliases::{DefaultMemory, DefaultPinVec},
+    type_aliases::{BACK_IDX, DefaultMemory, DefaultPinVec, FRONT_IDX},
     variant::ListVariant,
 };
 use orx_pinned_vec::PinnedVec;
-use orx_selfref_col::{MemoryPolicy, Node, SelfRefCol, Variant};
+use orx_selfref_col::{MemoryPolicy, Node, NodePtr, RefsArray, SelfRefCol, Variant};
 
 /// A slice of a linked list.
 ///
@@ -18,7 +18,7 @@ where
     P: PinnedVec<Node<V>>,
 {
     pub(crate) col: &'a SelfRefCol<V, M, P>,
-    pub(crate) ends: V::Ends,
+    pub(crate) ends: RefsArray<2, V>,
 }
 
 impl<V, M, P> HasCol<V, M, P> for ListSlice<'_, V, M, P>
@@ -39,8 +39,13 @@ where
     P: PinnedVec<Node<Singly<T>>>,
 {
     #[inline(always)]
-    fn ends(&self) -> &<Singly<T> as Variant>::Ends {
-        &self.ends
+    fn front_end(&self) -> Option<NodePtr<Singly<T>>> {
+        self.ends.get(FRONT_IDX)
+    }
+
+    #[inline(always)]
+    fn back_end(&self) -> Option<NodePtr<Singly<T>>> {
+        self.ends.get(BACK_IDX)
     }
 }
 
@@ -1,13 +1,14 @@
+use super::ListChunksMut;
 use crate::{
     Doubly, List, Singly,
     list::helper_traits::{
         HasCol, HasColMut, HasDoublyEnds, HasDoublyEndsMut, HasSinglyEnds, HasSinglyEndsMut,
     },
-    type_aliases::{DefaultMemory, DefaultPinVec},
+    type_aliases::{BACK_IDX, DefaultMemory, DefaultPinVec, FRONT_IDX},
     variant::ListVariant,
 };
 use orx_pinned_vec::PinnedVec;
-use orx_selfref_col::{MemoryPolicy, Node, SelfRefCol, Variant};
+use orx_selfref_col::{MemoryPolicy, Node, NodePtr, RefsArray, SelfRefCol, Variant};
 
 /// A mutable slice of a linked list.
 ///
@@ -20,7 +21,7 @@ where
     P: PinnedVec<Node<V>>,
 {
     pub(crate) list: &'a mut List<V, M, P>,
-    pub(crate) ends: V::Ends,
+    pub(crate) ends: RefsArray<2, V>,
 }
 
 impl<V, M, P> HasCol<V, M, P> for ListSliceMut<'_, V, M, P>
@@ -53,8 +54,13 @@ where
     P: PinnedVec<Node<Singly<T>>>,
 {
     #[inline(always)]
-    fn ends(&self) -> &<Singly<T> as Variant>::Ends {
-        &self.ends
+    fn front_end(&self) -> Option<NodePtr<Singly<T>>> {
+        self.ends.get(FRONT_IDX)
+    }
+
+    #[inline(always)]
+    fn back_end(&self) -> Option<NodePtr<Singly<T>>> {
+        self.ends.get(BACK_IDX)
     }
 }
 
@@ -64,8 +70,13 @@ where
     P: PinnedVec<Node<Singly<T>>>,
 {
     #[inline(always)]
-    fn ends_mut(&mut self) -> &mut <Singly<T> as Variant>::Ends {
-        &mut self.ends
+    fn set_front_end(&mut self, front: Option<NodePtr<Singly<T>>>) {
+        self.ends.set(FRONT_IDX, front);
+    }
+
+    #[inline(always)]
+    fn set_back_end(&mut self, back: Option<NodePtr<Singly<T>>>) {
+        self.ends.set(BACK_IDX, back);
     }
 }
 
@@ -90,3 +101,80 @@ where
         &mut self.ends
     }
 }
+
+// chunks
+
+impl<T, M, P> ListSliceMut<'_, Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    /// Returns consecutive chunks of the slice as mutable slices, from front to back.
+    /// Each chunk has `chunk_size` elements, except for the last chunk which might be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<_> = (0..7).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let mut slice = list.slice_mut(idx[1]..idx[6]);
+    ///
+    /// let mut chunks = slice.chunks_mut(2);
+    /// while let Some(mut chunk) = chunks.next_chunk() {
+    ///     let first = *chunk.front().unwrap();
+    ///     for x in chunk.iter_mut() {
+    ///         *x -= first;
+    ///     }
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 0, 1, 0, 1, 0, 6]));
+    /// ```
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ListChunksMut<'_, Singly<T>, M, P> {
+        let front = self.ends.get(FRONT_IDX);
+        let back = self.ends.get(BACK_IDX);
+        ListChunksMut::new(self.list, front, back, chunk_size)
+    }
+}
+
+impl<T, M, P> ListSliceMut<'_, Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    /// Returns consecutive chunks of the slice as mutable slices, from front to back.
+    /// Each chunk has `chunk_size` elements, except for the last chunk which might be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..7).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let mut slice = list.slice_mut(idx[1]..idx[6]);
+    ///
+    /// let mut chunks = slice.chunks_mut(2);
+    /// while let Some(mut chunk) = chunks.next_chunk() {
+    ///     chunk.reverse();
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 2, 1, 4, 3, 5, 6]));
+    /// ```
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ListChunksMut<'_, Doubly<T>, M, P> {
+        let front = self.ends.get(FRONT_IDX);
+        let back = self.ends.get(BACK_IDX);
+        ListChunksMut::new(self.list, front, back, chunk_size)
+    }
+}
@@ -1,7 +1,12 @@
 mod common_traits;
 mod get;
+mod list_chunks_mut;
 mod list_slice;
 mod list_slice_mut;
+mod slice_error;
+mod sub_slice;
 
+pub use list_chunks_mut::ListChunksMut;
 pub use list_slice::ListSlice;
 pub use list_slice_mut::ListSliceMut;
+pub use slice_error::SliceError;
@@ -0,0 +1,24 @@
+use core::fmt::{Debug, Display};
+use orx_selfref_col::NodeIdxError;
+
+/// Error cases of fallible sub-slicing of a slice, such as `try_slice` or `try_slice_mut`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    /// InvalidIdx => One of the indices of the range bounds is invalid for the list; the wrapped error gives the reason.
+    InvalidIdx(NodeIdxError),
+    /// OutOfSlice => One of the indices of the range bounds is valid for the list; however, the node does not
+    /// belong to the slice that the method is called on.
+    OutOfSlice,
+}
+
+impl Display for SliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <SliceError as Debug>::fmt(self, f)
+    }
+}
+
+impl From<NodeIdxError> for SliceError {
+    fn from(value: NodeIdxError) -> Self {
+        Self::InvalidIdx(value)
+    }
+}
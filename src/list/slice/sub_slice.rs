@@ -0,0 +1,415 @@
+use super::{ListSlice, ListSliceMut};
+use crate::{
+    Doubly, DoublyIdx, DoublyIterable, Singly, SinglyIdx, SinglyIterable, SliceError,
+    list::{
+        helper_traits::{HasDoublyEnds, HasSinglyEnds},
+        iter_traits::chunk_ends::{position_ends, positions},
+    },
+};
+use core::ops::RangeBounds;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+
+// singly
+
+impl<'a, T, M, P> ListSlice<'a, Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    /// Creates and returns a sub-slice of this slice between the given `range` of indices.
+    ///
+    /// ***O(n)*** since the range bounds are validated to be elements of this slice.
+    ///
+    /// Similar to slicing a list, if the end of the `range` is before its beginning,
+    /// the sub-slice extends to the end of this slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of indices of the range bounds is invalid or is not an element of this slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: SinglyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let slice = list.slice(idx[2]..idx[8]);
+    /// assert!(slice.eq_to_iter_vals([2, 3, 4, 5, 6, 7]));
+    ///
+    /// let sub_slice = slice.slice(idx[3]..=idx[5]);
+    /// assert!(sub_slice.eq_to_iter_vals([3, 4, 5]));
+    ///
+    /// let sub_slice = slice.slice(idx[5]..);
+    /// assert!(sub_slice.eq_to_iter_vals([5, 6, 7]));
+    /// ```
+    pub fn slice<R>(&self, range: R) -> ListSlice<'a, Singly<T>, M, P>
+    where
+        R: RangeBounds<SinglyIdx<T>>,
+    {
+        self.try_slice(range).expect("invalid indices in range")
+    }
+
+    /// Creates and returns a sub-slice of this slice between the given `range` of indices;
+    /// returns an error if any of the indices of the range bounds is invalid.
+    ///
+    /// ***O(n)*** since the range bounds are validated to be elements of this slice.
+    ///
+    /// An index which is invalid for the list leads to a [`SliceError::InvalidIdx`] error,
+    /// while an index which is valid for the list but does not belong to this slice
+    /// leads to a [`SliceError::OutOfSlice`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: SinglyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let slice = list.slice(idx[2]..idx[8]);
+    ///
+    /// let sub_slice = slice.try_slice(idx[3]..idx[5]);
+    /// assert!(sub_slice.is_ok_and(|x| x.eq_to_iter_vals([3, 4])));
+    ///
+    /// let sub_slice = slice.try_slice(idx[0]..idx[5]);
+    /// assert_eq!(sub_slice.err(), Some(SliceError::OutOfSlice));
+    ///
+    /// let sub_slice = slice.try_slice(idx[3]..=idx[8]);
+    /// assert_eq!(sub_slice.err(), Some(SliceError::OutOfSlice));
+    /// ```
+    pub fn try_slice<R>(&self, range: R) -> Result<ListSlice<'a, Singly<T>, M, P>, SliceError>
+    where
+        R: RangeBounds<SinglyIdx<T>>,
+    {
+        let ends = self.sub_slice_ends(range)?;
+        Ok(ListSlice {
+            col: self.col,
+            ends,
+        })
+    }
+}
+
+impl<T, M, P> ListSliceMut<'_, Singly<T>, M, P>
+where
+    M: MemoryPolicy<Singly<T>>,
+    P: PinnedVec<Node<Singly<T>>>,
+{
+    /// Creates and returns a mutable sub-slice of this slice between the given `range` of indices.
+    ///
+    /// ***O(n)*** since the range bounds are validated to be elements of this slice.
+    ///
+    /// Similar to slicing a list, if the end of the `range` is before its beginning,
+    /// the sub-slice extends to the end of this slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of indices of the range bounds is invalid or is not an element of this slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let mut slice = list.slice_mut(idx[2]..idx[8]);
+    ///
+    /// let mut sub_slice = slice.slice_mut(idx[3]..=idx[5]);
+    /// for x in sub_slice.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 30, 40, 50, 6, 7, 8, 9]));
+    /// ```
+    pub fn slice_mut<R>(&mut self, range: R) -> ListSliceMut<'_, Singly<T>, M, P>
+    where
+        R: RangeBounds<SinglyIdx<T>>,
+    {
+        self.try_slice_mut(range).expect("invalid indices in range")
+    }
+
+    /// Creates and returns a mutable sub-slice of this slice between the given `range` of indices;
+    /// returns an error if any of the indices of the range bounds is invalid.
+    ///
+    /// ***O(n)*** since the range bounds are validated to be elements of this slice.
+    ///
+    /// An index which is invalid for the list leads to a [`SliceError::InvalidIdx`] error,
+    /// while an index which is valid for the list but does not belong to this slice
+    /// leads to a [`SliceError::OutOfSlice`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let mut slice = list.slice_mut(idx[2]..idx[8]);
+    ///
+    /// let sub_slice = slice.try_slice_mut(idx[0]..idx[5]);
+    /// assert_eq!(sub_slice.err(), Some(SliceError::OutOfSlice));
+    ///
+    /// let mut sub_slice = slice.try_slice_mut(idx[6]..).unwrap();
+    /// *sub_slice.front_mut().unwrap() = 60;
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 3, 4, 5, 60, 7, 8, 9]));
+    /// ```
+    pub fn try_slice_mut<R>(
+        &mut self,
+        range: R,
+    ) -> Result<ListSliceMut<'_, Singly<T>, M, P>, SliceError>
+    where
+        R: RangeBounds<SinglyIdx<T>>,
+    {
+        let ends = self.sub_slice_ends(range)?;
+        Ok(ListSliceMut {
+            list: self.list,
+            ends,
+        })
+    }
+
+    /// Creates and returns a mutable sub-slice of this slice between the given `range` of positions.
+    ///
+    /// Positions are relative to the front of this slice, as in a vec slice.
+    ///
+    /// ***O(n)*** since the slice boundaries are found by traversing the slice from the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if it starts after it ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: SinglyList<_> = (0..10).collect();
+    ///
+    /// let mut slice = list.slice_mut_at(2..8);
+    ///
+    /// let mut sub_slice = slice.slice_mut_at(1..3);
+    /// for x in sub_slice.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 30, 40, 5, 6, 7, 8, 9]));
+    /// ```
+    pub fn slice_mut_at<R>(&mut self, range: R) -> ListSliceMut<'_, Singly<T>, M, P>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (begin, end) = positions(range, self.iter_ptr().count());
+        let ends = position_ends(self.iter_ptr(), begin, end);
+        ListSliceMut {
+            list: self.list,
+            ends,
+        }
+    }
+}
+
+// doubly
+
+impl<'a, T, M, P> ListSlice<'a, Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    /// Creates and returns a sub-slice of this slice between the given `range` of indices.
+    ///
+    /// ***O(n)*** since the range bounds are validated to be elements of this slice.
+    ///
+    /// Similar to slicing a list, if the end of the `range` is before its beginning,
+    /// the sub-slice extends to the end of this slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of indices of the range bounds is invalid or is not an element of this slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: DoublyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let slice = list.slice(idx[2]..idx[8]);
+    /// assert!(slice.eq_to_iter_vals([2, 3, 4, 5, 6, 7]));
+    ///
+    /// let sub_slice = slice.slice(idx[3]..=idx[5]);
+    /// assert!(sub_slice.eq_to_iter_vals([3, 4, 5]));
+    ///
+    /// let sub_slice = slice.slice(idx[5]..);
+    /// assert!(sub_slice.eq_to_iter_vals([5, 6, 7]));
+    /// ```
+    pub fn slice<R>(&self, range: R) -> ListSlice<'a, Doubly<T>, M, P>
+    where
+        R: RangeBounds<DoublyIdx<T>>,
+    {
+        self.try_slice(range).expect("invalid indices in range")
+    }
+
+    /// Creates and returns a sub-slice of this slice between the given `range` of indices;
+    /// returns an error if any of the indices of the range bounds is invalid.
+    ///
+    /// ***O(n)*** since the range bounds are validated to be elements of this slice.
+    ///
+    /// An index which is invalid for the list leads to a [`SliceError::InvalidIdx`] error,
+    /// while an index which is valid for the list but does not belong to this slice
+    /// leads to a [`SliceError::OutOfSlice`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let list: DoublyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let slice = list.slice(idx[2]..idx[8]);
+    ///
+    /// let sub_slice = slice.try_slice(idx[3]..idx[5]);
+    /// assert!(sub_slice.is_ok_and(|x| x.eq_to_iter_vals([3, 4])));
+    ///
+    /// let sub_slice = slice.try_slice(idx[0]..idx[5]);
+    /// assert_eq!(sub_slice.err(), Some(SliceError::OutOfSlice));
+    ///
+    /// let sub_slice = slice.try_slice(idx[3]..=idx[8]);
+    /// assert_eq!(sub_slice.err(), Some(SliceError::OutOfSlice));
+    /// ```
+    pub fn try_slice<R>(&self, range: R) -> Result<ListSlice<'a, Doubly<T>, M, P>, SliceError>
+    where
+        R: RangeBounds<DoublyIdx<T>>,
+    {
+        let ends = self.sub_slice_ends(range)?;
+        Ok(ListSlice {
+            col: self.col,
+            ends,
+        })
+    }
+}
+
+impl<T, M, P> ListSliceMut<'_, Doubly<T>, M, P>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: PinnedVec<Node<Doubly<T>>>,
+{
+    /// Creates and returns a mutable sub-slice of this slice between the given `range` of indices.
+    ///
+    /// ***O(n)*** since the range bounds are validated to be elements of this slice.
+    ///
+    /// Similar to slicing a list, if the end of the `range` is before its beginning,
+    /// the sub-slice extends to the end of this slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of indices of the range bounds is invalid or is not an element of this slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let mut slice = list.slice_mut(idx[2]..idx[8]);
+    ///
+    /// let mut sub_slice = slice.slice_mut(idx[3]..=idx[5]);
+    /// for x in sub_slice.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 30, 40, 50, 6, 7, 8, 9]));
+    /// ```
+    pub fn slice_mut<R>(&mut self, range: R) -> ListSliceMut<'_, Doubly<T>, M, P>
+    where
+        R: RangeBounds<DoublyIdx<T>>,
+    {
+        self.try_slice_mut(range).expect("invalid indices in range")
+    }
+
+    /// Creates and returns a mutable sub-slice of this slice between the given `range` of indices;
+    /// returns an error if any of the indices of the range bounds is invalid.
+    ///
+    /// ***O(n)*** since the range bounds are validated to be elements of this slice.
+    ///
+    /// An index which is invalid for the list leads to a [`SliceError::InvalidIdx`] error,
+    /// while an index which is valid for the list but does not belong to this slice
+    /// leads to a [`SliceError::OutOfSlice`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..10).collect();
+    /// let idx: Vec<_> = list.indices().collect();
+    ///
+    /// let mut slice = list.slice_mut(idx[2]..idx[8]);
+    ///
+    /// let sub_slice = slice.try_slice_mut(idx[0]..idx[5]);
+    /// assert_eq!(sub_slice.err(), Some(SliceError::OutOfSlice));
+    ///
+    /// let mut sub_slice = slice.try_slice_mut(idx[6]..).unwrap();
+    /// *sub_slice.front_mut().unwrap() = 60;
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 3, 4, 5, 60, 7, 8, 9]));
+    /// ```
+    pub fn try_slice_mut<R>(
+        &mut self,
+        range: R,
+    ) -> Result<ListSliceMut<'_, Doubly<T>, M, P>, SliceError>
+    where
+        R: RangeBounds<DoublyIdx<T>>,
+    {
+        let ends = self.sub_slice_ends(range)?;
+        Ok(ListSliceMut {
+            list: self.list,
+            ends,
+        })
+    }
+
+    /// Creates and returns a mutable sub-slice of this slice between the given `range` of positions.
+    ///
+    /// Positions are relative to the front of this slice, as in a vec slice.
+    ///
+    /// ***O(n)*** since the slice boundaries are found by traversing the slice from the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if it starts after it ends.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use orx_linked_list::*;
+    ///
+    /// let mut list: DoublyList<_> = (0..10).collect();
+    ///
+    /// let mut slice = list.slice_mut_at(2..8);
+    ///
+    /// let mut sub_slice = slice.slice_mut_at(1..3);
+    /// for x in sub_slice.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    ///
+    /// assert!(list.eq_to_iter_vals([0, 1, 2, 30, 40, 5, 6, 7, 8, 9]));
+    /// ```
+    pub fn slice_mut_at<R>(&mut self, range: R) -> ListSliceMut<'_, Doubly<T>, M, P>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (begin, end) = positions(range, self.iter_ptr().count());
+        let ends = position_ends(self.iter_ptr(), begin, end);
+        ListSliceMut {
+            list: self.list,
+            ends,
+        }
+    }
+}
@@ -13,6 +13,9 @@ impl DoublyReclaimer {
     where
         P: PinnedVec<Node<Doubly<T>>>,
     {
+        // closed positions are truncated after reclaim and might be reused by later pushes;
+        // hence, the state must change even if no node moves, so that indices of removed nodes remain invalid
+        let any_truncated = col.nodes().len() > col.len();
         let mut any_swapped = false;
 
         // SAFETY: lifetimes of `forward` and `backward` iterators are limited to this method
@@ -40,7 +43,7 @@ impl DoublyReclaimer {
             }
         }
 
-        any_swapped
+        any_swapped || any_truncated
     }
 }
 
@@ -53,7 +56,7 @@ impl<T> MemoryReclaimer<Doubly<T>> for DoublyReclaimer {
     }
 }
 
-fn swap<P, T>(
+pub(crate) fn swap<P, T>(
     col: &mut CoreCol<Doubly<T>, P>,
     vacant: *const Node<Doubly<T>>,
     occupied: *const Node<Doubly<T>>,
@@ -78,14 +81,23 @@ fn swap<P, T>(
     });
 
     // SAFETY: we have a mutual &mut reference to the underlying collection
-    // which is guaranteed to be in the same memory state as occupied
-    if occupied == unsafe { col.ends().get(FRONT_IDX).expect("nonempty list").ptr() } {
+    // which is guaranteed to be in the same memory state as occupied;
+    // ends might be empty when the nodes are linked by lists of an arena, rather than by the collection
+    if col
+        .ends()
+        .get(FRONT_IDX)
+        .is_some_and(|end| occupied == unsafe { end.ptr() })
+    {
         col.ends_mut().set(FRONT_IDX, node_ptr(vacant));
     }
 
     // SAFETY: we have a mutual &mut reference to the underlying collection
     // which is guaranteed to be in the same memory state as occupied
-    if occupied == unsafe { col.ends().get(BACK_IDX).expect("nonempty list").ptr() } {
+    if col
+        .ends()
+        .get(BACK_IDX)
+        .is_some_and(|end| occupied == unsafe { end.ptr() })
+    {
         col.ends_mut().set(BACK_IDX, node_ptr(vacant));
     }
 }
@@ -0,0 +1,107 @@
+use super::{doubly_reclaimer::swap, node_ptr_at_pos};
+use crate::variant::Doubly;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{CoreCol, MemoryPolicy, Node, NodePtr};
+
+/// Memory reclaim policy which spreads the reorganization of nodes over removals,
+/// rather than compacting the entire storage at once.
+///
+/// * As long as the ratio of closed nodes to all nodes does not exceed one over `2^D`,
+///   nothing happens; this is identical to the threshold policy.
+/// * Once the threshold is exceeded, each removal inspects at most `K` storage positions:
+///   the position of the removed node and a window of `K - 1` positions which sweeps
+///   the storage over consecutive removals.
+///   Each closed node found is filled by moving the last active node of the storage into it.
+///   Therefore, **at most `K` nodes are moved per removal**.
+/// * Closed nodes at the end of the storage are trimmed away. Trimming is not bounded by `K`;
+///   however, each position is trimmed at most once after being closed, so its cost is amortized over the removals.
+///
+/// Compared to the threshold policy, the amortized cost of a removal no longer grows with the length of the list;
+/// the price is that closed nodes are not reclaimed all at once, but gradually as removals continue.
+/// Note that with `K = 1` only the hole of the removed node itself is filled;
+/// `K >= 2` is required to make progress on the closed nodes left behind before the threshold was reached.
+/// [`reclaim_closed_nodes`] can still be called at any time to reclaim all of them.
+///
+/// # Validity of Indices
+///
+/// * Insertions never invalidate indices.
+/// * A removal invalidates indices if it moves at least one node or truncates the storage;
+///   this can only happen while the utilization is below the threshold.
+/// * Whether or not the indices are still valid can be checked by comparing the [`memory_state`]
+///   before and after the operation, or by [`idx_err`] for a particular index.
+///
+/// Since moving a node requires constant time access to its previous node,
+/// this policy is available for doubly linked lists; see [`DoublyListIncremental`].
+///
+/// [`reclaim_closed_nodes`]: crate::List::reclaim_closed_nodes
+/// [`memory_state`]: crate::List::memory_state
+/// [`idx_err`]: crate::DoublyEnds::idx_err
+/// [`DoublyListIncremental`]: crate::DoublyListIncremental
+#[derive(Clone, Copy, Default)]
+pub struct MemoryReclaimIncremental<const D: usize, const K: usize>;
+
+impl<const D: usize, const K: usize, T> MemoryPolicy<Doubly<T>> for MemoryReclaimIncremental<D, K> {
+    fn reclaim_closed_nodes<P>(
+        col: &mut CoreCol<Doubly<T>, P>,
+        closed_node_ptr: NodePtr<Doubly<T>>,
+    ) -> bool
+    where
+        P: PinnedVec<Node<Doubly<T>>>,
+    {
+        let used = col.nodes().len();
+        let allowed_vacant = used >> D;
+        let num_vacant = used - col.len();
+
+        if num_vacant <= allowed_vacant {
+            return false;
+        }
+
+        let is_closed = |col: &CoreCol<Doubly<T>, P>, position: usize| {
+            col.node(node_ptr_at_pos(col, position)).is_closed()
+        };
+
+        // the position of the removed node is inspected first; remaining K-1 positions
+        // form a window sweeping the storage as the length of the list changes
+        let removed = col.position_of_unchecked(closed_node_ptr);
+        let window = K.saturating_sub(1);
+        let sweep_begin = col.len().wrapping_mul(window) % used;
+        let sweep_end = sweep_begin.saturating_add(window).min(used);
+        let positions = core::iter::once(removed).chain(sweep_begin..sweep_end);
+
+        // invariant: all nodes at positions `last..used` are closed
+        let mut last = used;
+        let mut num_moved = 0;
+
+        for v in positions.take(K) {
+            if v >= last || !is_closed(col, v) {
+                continue;
+            }
+
+            while last > v + 1 && is_closed(col, last - 1) {
+                last -= 1;
+            }
+
+            match last > v + 1 {
+                true => {
+                    // SAFETY: both pointers belong to the storage of `col` to which we hold a mutual reference
+                    let vacant = unsafe { node_ptr_at_pos(col, v).ptr() };
+                    let occupied = unsafe { node_ptr_at_pos(col, last - 1).ptr() };
+                    swap(col, vacant, occupied);
+                    num_moved += 1;
+                    last -= 1;
+                }
+                false => last = v,
+            }
+        }
+
+        while last > 0 && is_closed(col, last - 1) {
+            last -= 1;
+        }
+
+        col.nodes_mut().truncate(last);
+
+        // truncated positions might be reused by later pushes; hence, the state changes
+        // so that the indices of the removed nodes remain invalid
+        num_moved > 0 || last < used
+    }
+}
@@ -1,5 +1,28 @@
 mod doubly_reclaimer;
+mod incremental;
 mod singly_reclaimer;
 
 pub(crate) use doubly_reclaimer::DoublyReclaimer;
+pub use incremental::MemoryReclaimIncremental;
 pub(crate) use singly_reclaimer::SinglyReclaimer;
+
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{CoreCol, Node, NodePtr, Variant};
+
+/// Returns the pointer to the node at the given `position` of the storage.
+///
+/// Unlike `CoreCol::node_ptr_at_pos`, the position is resolved by the lengths rather than capacities of the
+/// fragments; and hence, is consistent with `CoreCol::position_of` also when appended lists leave partially
+/// filled fragments in the middle of the storage.
+///
+/// # Panics
+///
+/// Panics if the position is out of bounds.
+pub(crate) fn node_ptr_at_pos<V, P>(col: &CoreCol<V, P>, position: usize) -> NodePtr<V>
+where
+    V: Variant,
+    P: PinnedVec<Node<V>>,
+{
+    let node = col.nodes().get(position).expect("out-of-bounds");
+    NodePtr::new(node as *const Node<V> as *mut Node<V>)
+}
@@ -10,7 +10,9 @@ impl<T> MemoryReclaimer<Singly<T>> for SinglyReclaimer {
     where
         P: PinnedVec<Node<Singly<T>>>,
     {
-        let mut nodes_moved = false;
+        // closed positions are truncated after reclaim and might be reused by later pushes;
+        // hence, the state must change even if no node moves, so that indices of removed nodes remain invalid
+        let mut state_changed = col.nodes().len() > col.len();
 
         if let Some(mut occupied_ptr) = col.ends().get() {
             let mut prev = core::ptr::null();
@@ -29,7 +31,7 @@ impl<T> MemoryReclaimer<Singly<T>> for SinglyReclaimer {
                         let swapped = o > v;
                         match swapped {
                             true => {
-                                nodes_moved = true;
+                                state_changed = true;
                                 // SAFETY: we have a mutual &mut reference to the underlying collection
                                 // which is guaranteed to be in the same memory state as occupied
                                 swap(col, vacant_ptr, unsafe { occupied_ptr.ptr() }, prev);
@@ -42,7 +44,7 @@ impl<T> MemoryReclaimer<Singly<T>> for SinglyReclaimer {
 
                         match next {
                             Some(next) => occupied_ptr = next,
-                            None => return nodes_moved,
+                            None => return state_changed,
                         }
 
                         if swapped {
@@ -53,7 +55,7 @@ impl<T> MemoryReclaimer<Singly<T>> for SinglyReclaimer {
             }
         }
 
-        nodes_moved
+        state_changed
     }
 }
 
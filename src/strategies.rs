@@ -0,0 +1,174 @@
+//! [`proptest`](https://docs.rs/proptest) strategies generating linked lists.
+//!
+//! Each strategy starts from an empty list and applies a random sequence of
+//! mutations on it, such as pushes, insertions relative to existing nodes,
+//! removals and moves. The generated value is the resulting list together with
+//! the indices of all of its elements in list order; these indices are valid
+//! for the generated list.
+//!
+//! Since the list is built from a sequence of operations, shrinking a failing case
+//! shrinks the sequence of operations, which typically yields short and small lists.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use orx_linked_list::*;
+//! use proptest::{prelude::*, test_runner::TestRunner};
+//!
+//! let mut runner = TestRunner::default();
+//! let strategy = strategies::doubly_list(any::<u8>(), 0..64);
+//!
+//! let result = runner.run(&strategy, |(mut list, idx)| {
+//!     prop_assert_eq!(list.len(), idx.len());
+//!     let values: Vec<_> = list.iter().copied().collect();
+//!
+//!     list.reverse();
+//!     list.reverse();
+//!
+//!     prop_assert!(list.eq_to_iter_vals(values));
+//!     prop_assert!(idx.iter().all(|i| list.is_valid(*i)));
+//!     Ok(())
+//! });
+//! assert!(result.is_ok());
+//! ```
+
+use crate::{
+    Doubly, DoublyIdx, DoublyIterable, DoublyList, DoublyListLazy, List, Singly, SinglyIdx,
+    SinglyIterable, SinglyList, SinglyListLazy,
+    list::random_ops::{DoublyOp, SinglyOp},
+};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use orx_selfref_col::MemoryPolicy;
+use proptest::{collection::SizeRange, prelude::*};
+
+fn doubly_op<T, S>(element: S) -> impl Strategy<Value = DoublyOp<T>>
+where
+    T: Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    prop_oneof![
+        element.clone().prop_map(DoublyOp::PushFront),
+        element.clone().prop_map(DoublyOp::PushBack),
+        (any::<usize>(), element.clone()).prop_map(|(i, x)| DoublyOp::InsertNextTo(i, x)),
+        (any::<usize>(), element).prop_map(|(i, x)| DoublyOp::InsertPrevTo(i, x)),
+        any::<usize>().prop_map(DoublyOp::Remove),
+        any::<usize>().prop_map(DoublyOp::MoveToFront),
+        any::<usize>().prop_map(DoublyOp::MoveToBack),
+        (any::<usize>(), any::<usize>()).prop_map(|(i, j)| DoublyOp::MoveNextTo(i, j)),
+        (any::<usize>(), any::<usize>()).prop_map(|(i, j)| DoublyOp::MovePrevTo(i, j)),
+    ]
+}
+
+fn singly_op<T, S>(element: S) -> impl Strategy<Value = SinglyOp<T>>
+where
+    T: Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    prop_oneof![
+        element.clone().prop_map(SinglyOp::PushFront),
+        (any::<usize>(), element).prop_map(|(i, x)| SinglyOp::InsertAt(i, x)),
+        any::<usize>().prop_map(SinglyOp::RemoveAt),
+        Just(()).prop_map(|_| SinglyOp::PopFront),
+    ]
+}
+
+fn doubly<T, M, S>(
+    element: S,
+    num_ops: impl Into<SizeRange>,
+) -> impl Strategy<Value = (List<Doubly<T>, M>, Vec<DoublyIdx<T>>)>
+where
+    T: Debug,
+    M: MemoryPolicy<Doubly<T>>,
+    List<Doubly<T>, M>: Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    proptest::collection::vec(doubly_op(element), num_ops).prop_map(|ops| {
+        let mut list = List::with_recursive_growth();
+        for op in ops {
+            op.apply(&mut list);
+        }
+        let idx = list.indices().collect();
+        (list, idx)
+    })
+}
+
+fn singly<T, M, S>(
+    element: S,
+    num_ops: impl Into<SizeRange>,
+) -> impl Strategy<Value = (List<Singly<T>, M>, Vec<SinglyIdx<T>>)>
+where
+    T: Debug,
+    M: MemoryPolicy<Singly<T>>,
+    List<Singly<T>, M>: Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    proptest::collection::vec(singly_op(element), num_ops).prop_map(|ops| {
+        let mut list = List::with_recursive_growth();
+        for op in ops {
+            op.apply(&mut list);
+        }
+        let idx = list.indices().collect();
+        (list, idx)
+    })
+}
+
+/// Strategy generating a [`DoublyList`] together with the indices of its elements in list order.
+///
+/// The list is built by applying `num_ops` random `push_front`, `push_back`, `insert_next_to`,
+/// `insert_prev_to`, `remove` and `move_*` operations, where new values are drawn from `element`.
+pub fn doubly_list<T, S>(
+    element: S,
+    num_ops: impl Into<SizeRange>,
+) -> impl Strategy<Value = (DoublyList<T>, Vec<DoublyIdx<T>>)>
+where
+    T: Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    doubly(element, num_ops)
+}
+
+/// Strategy generating a [`DoublyListLazy`] together with the indices of its elements in list order.
+///
+/// Since removed nodes are never reclaimed, the generated list might contain closed nodes
+/// in its storage, which makes it suitable for testing behavior in the presence of gaps.
+pub fn doubly_list_lazy<T, S>(
+    element: S,
+    num_ops: impl Into<SizeRange>,
+) -> impl Strategy<Value = (DoublyListLazy<T>, Vec<DoublyIdx<T>>)>
+where
+    T: Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    doubly(element, num_ops)
+}
+
+/// Strategy generating a [`SinglyList`] together with the indices of its elements in list order.
+///
+/// The list is built by applying `num_ops` random `push_front`, `insert_at`, `remove_at` and
+/// `pop_front` operations, where new values are drawn from `element`.
+pub fn singly_list<T, S>(
+    element: S,
+    num_ops: impl Into<SizeRange>,
+) -> impl Strategy<Value = (SinglyList<T>, Vec<SinglyIdx<T>>)>
+where
+    T: Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    singly(element, num_ops)
+}
+
+/// Strategy generating a [`SinglyListLazy`] together with the indices of its elements in list order.
+///
+/// Since removed nodes are never reclaimed, the generated list might contain closed nodes
+/// in its storage.
+pub fn singly_list_lazy<T, S>(
+    element: S,
+    num_ops: impl Into<SizeRange>,
+) -> impl Strategy<Value = (SinglyListLazy<T>, Vec<SinglyIdx<T>>)>
+where
+    T: Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    singly(element, num_ops)
+}
@@ -0,0 +1,585 @@
+//! Model-based differential testing of doubly linked lists.
+//!
+//! A [`DifferentialChecker`] applies operation scripts of [`Op`]s both on a [`List`] and on a
+//! reference model, a `VecDeque<(id, T)>` where each element is tagged with a unique id.
+//! After every step the checker compares the list against the model:
+//! * structural integrity of the list, see [`check_integrity`];
+//! * length and the elements in both directions;
+//! * validity of the indices of all elements of the list, which must point to the expected elements;
+//! * invalidity of the indices of all removed elements.
+//!
+//! The checker is generic over the memory policy and the underlying storage, and hence, can be used
+//! to test custom memory policies, or any memory policy and storage under custom sequences of operations.
+//! Storages declare whether or not they support appending lists by implementing [`CheckedStorage`];
+//! append operations are skipped for storages which do not.
+//! Random scripts can be created by [`random_script`], or the checker can be run with a random
+//! script at once by [`check_random`].
+//!
+//! [`check_integrity`]: crate::DoublyEnds::check_integrity
+//!
+//! # Examples
+//!
+//! ```rust
+//! use orx_linked_list::*;
+//! use orx_linked_list::testing::*;
+//!
+//! let list = DoublyList::with_threshold_reclaimer::<1>();
+//! let result = check_random(list, 42, 500, |x| x % 100);
+//! assert!(result.is_ok());
+//!
+//! let list: DoublyListLazy<_> = [1, 2, 3].into_iter().collect();
+//! let mut checker = DifferentialChecker::new(list);
+//! let script = [Op::PushFront(0), Op::Remove(2), Op::Reverse, Op::Reclaim];
+//! assert_eq!(checker.run(script), Ok(()));
+//! assert!(checker.list().eq_to_iter_vals([3, 1, 0]));
+//! ```
+
+use crate::{
+    DoublyEnds, DoublyEndsMut, DoublyIdx, DoublyIterable, DoublyList, IntegrityError, List,
+    MoveError, type_aliases::DefaultPinVec, variant::Doubly,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use core::fmt::{Debug, Display};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, MemoryState, Node};
+use orx_split_vec::{Doubling, Linear, Recursive, SplitVec};
+
+/// Underlying storage of a doubly linked list which can be tested by the [`DifferentialChecker`].
+///
+/// Appending lists is only available for lists using `SplitVec` with `Recursive` growth; therefore,
+/// [`Op::AppendFront`] and [`Op::AppendBack`] are applied only on such lists and skipped on the others.
+pub trait CheckedStorage<T>: PinnedVec<Node<Doubly<T>>> + Sized {
+    /// Appends a list of the `values` to the front of the `list` and returns true;
+    /// returns false without changing the list if the storage does not support appending.
+    fn append_front<M>(list: &mut List<Doubly<T>, M, Self>, values: Vec<T>) -> bool
+    where
+        M: MemoryPolicy<Doubly<T>>,
+    {
+        let _ = (list, values);
+        false
+    }
+
+    /// Appends a list of the `values` to the back of the `list` and returns true;
+    /// returns false without changing the list if the storage does not support appending.
+    fn append_back<M>(list: &mut List<Doubly<T>, M, Self>, values: Vec<T>) -> bool
+    where
+        M: MemoryPolicy<Doubly<T>>,
+    {
+        let _ = (list, values);
+        false
+    }
+}
+
+impl<T> CheckedStorage<T> for SplitVec<Node<Doubly<T>>, Recursive> {
+    fn append_front<M>(list: &mut List<Doubly<T>, M, Self>, values: Vec<T>) -> bool
+    where
+        M: MemoryPolicy<Doubly<T>>,
+    {
+        list.append_front(DoublyList::from_iter(values));
+        true
+    }
+
+    fn append_back<M>(list: &mut List<Doubly<T>, M, Self>, values: Vec<T>) -> bool
+    where
+        M: MemoryPolicy<Doubly<T>>,
+    {
+        list.append_back(DoublyList::from_iter(values));
+        true
+    }
+}
+
+impl<T> CheckedStorage<T> for SplitVec<Node<Doubly<T>>, Doubling> {}
+
+impl<T> CheckedStorage<T> for SplitVec<Node<Doubly<T>>, Linear> {}
+
+impl<T> CheckedStorage<T> for FixedVec<Node<Doubly<T>>> {}
+
+/// An operation of a differential testing script.
+///
+/// Elements are referred to by their positions in the list which are taken modulo the length
+/// of the list at the time the operation is applied; hence, every operation is applicable to
+/// any list. Operations referring to an element are skipped when the list is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op<T> {
+    /// Pushes the value to the front with `push_front`.
+    PushFront(T),
+    /// Pushes the value to the back with `push_back`.
+    PushBack(T),
+    /// Pops from the front with `pop_front`.
+    PopFront,
+    /// Pops from the back with `pop_back`.
+    PopBack,
+    /// Inserts the value at the position, taken modulo length plus one, with `insert_at`.
+    InsertAt(usize, T),
+    /// Removes the element at the position, taken modulo length plus one, with `remove_at`;
+    /// hence, the position might be out of bounds.
+    RemoveAt(usize),
+    /// Inserts the value next to the element at the position with `insert_next_to`.
+    InsertNextTo(usize, T),
+    /// Inserts the value prev to the element at the position with `insert_prev_to`.
+    InsertPrevTo(usize, T),
+    /// Removes the element at the position by its index with `remove`.
+    Remove(usize),
+    /// Moves the element at the first position next to the element at the second position with `try_move_next_to`.
+    MoveNextTo(usize, usize),
+    /// Moves the element at the first position prev to the element at the second position with `try_move_prev_to`.
+    MovePrevTo(usize, usize),
+    /// Moves the element at the position to the front with `try_move_to_front`.
+    MoveToFront(usize),
+    /// Moves the element at the position to the back with `try_move_to_back`.
+    MoveToBack(usize),
+    /// Swaps the elements at the two positions with `try_swap`.
+    Swap(usize, usize),
+    /// Reverses the list with `reverse`.
+    Reverse,
+    /// Creates a slice between the two positions, both inclusive, with `slice` and compares it with the model.
+    Slice(usize, usize),
+    /// Reverses the slice between the two positions, both inclusive, created with `slice_mut`.
+    ReverseSlice(usize, usize),
+    /// Appends a list of the values to the front with `append_front`;
+    /// skipped if the storage does not support appending, see [`CheckedStorage`].
+    AppendFront(Vec<T>),
+    /// Appends a list of the values to the back with `append_back`;
+    /// skipped if the storage does not support appending, see [`CheckedStorage`].
+    AppendBack(Vec<T>),
+    /// Reclaims closed nodes with `reclaim_closed_nodes`.
+    Reclaim,
+}
+
+/// Kind of a divergence of the list from the reference model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DivergenceKind {
+    /// Integrity => The list failed the structural integrity check.
+    Integrity(IntegrityError),
+    /// Length => Length of the list differs from that of the model.
+    Length {
+        /// Length of the model.
+        expected: usize,
+        /// Length of the list.
+        found: usize,
+    },
+    /// Contents => Elements of the list in forward or backward order differ from those of the model.
+    Contents,
+    /// Output => The value returned by a pop or remove operation differs from that of the model.
+    Output,
+    /// MoveResult => A move or swap succeeded or failed while the model expects otherwise.
+    MoveResult {
+        /// Result expected by the model.
+        expected: Result<(), MoveError>,
+        /// Result returned by the list.
+        found: Result<(), MoveError>,
+    },
+    /// InvalidIndex => Index of the element at the `position` of the list is either invalid
+    /// or points to a different element.
+    InvalidIndex {
+        /// Position of the element in the list.
+        position: usize,
+    },
+    /// StaleIndex => Index of a removed element, or an index obtained before the nodes were
+    /// reorganized, is still valid.
+    StaleIndex,
+}
+
+/// Divergence of the list from the reference model observed by the [`DifferentialChecker`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// Zero-based number of the operation after which the divergence is observed.
+    pub step: usize,
+    /// Kind of the divergence.
+    pub kind: DivergenceKind,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "divergence after step {}: {:?}", self.step, self.kind)
+    }
+}
+
+/// Applies operations on a doubly linked list and a reference `VecDeque<(id, T)>` model
+/// side by side, and compares them after every step.
+///
+/// See the [module documentation](crate::testing) for details.
+pub struct DifferentialChecker<T, M, P = DefaultPinVec<Doubly<T>>>
+where
+    M: MemoryPolicy<Doubly<T>>,
+    P: CheckedStorage<T>,
+{
+    list: List<Doubly<T>, M, P>,
+    model: VecDeque<(usize, T)>,
+    indices: Vec<DoublyIdx<T>>,
+    removed: Vec<usize>,
+    state: MemoryState,
+    step: usize,
+}
+
+impl<T, M, P> DifferentialChecker<T, M, P>
+where
+    T: Clone + PartialEq,
+    M: MemoryPolicy<Doubly<T>>,
+    P: CheckedStorage<T>,
+{
+    /// Creates a checker for the given `list`, which is not required to be empty.
+    pub fn new(list: List<Doubly<T>, M, P>) -> Self {
+        let indices: Vec<_> = list.indices().collect();
+        let model = list.iter().cloned().enumerate().collect();
+        let state = list.memory_state();
+        Self {
+            list,
+            model,
+            indices,
+            removed: Vec::new(),
+            state,
+            step: 0,
+        }
+    }
+
+    /// Returns a reference to the list under test.
+    pub fn list(&self) -> &List<Doubly<T>, M, P> {
+        &self.list
+    }
+
+    /// Returns the reference model, elements of which are tagged by unique ids.
+    pub fn model(&self) -> &VecDeque<(usize, T)> {
+        &self.model
+    }
+
+    /// Consumes the checker and returns the list under test.
+    pub fn into_list(self) -> List<Doubly<T>, M, P> {
+        self.list
+    }
+
+    /// Applies all operations of the `script` one by one; and returns the first divergence, if any.
+    pub fn run<I>(&mut self, script: I) -> Result<(), Divergence>
+    where
+        I: IntoIterator<Item = Op<T>>,
+    {
+        script.into_iter().try_for_each(|op| self.apply(op))
+    }
+
+    /// Applies the operation on both the list and the model, and compares them afterwards.
+    pub fn apply(&mut self, op: Op<T>) -> Result<(), Divergence> {
+        let step = self.step;
+        self.step += 1;
+        let fail = |kind| Err(Divergence { step, kind });
+
+        let len = self.model.len();
+        let num_prior_ids = self.indices.len();
+        match op {
+            Op::PushFront(x) => {
+                let idx = self.list.push_front(x.clone());
+                let id = self.new_id(idx);
+                self.model.push_front((id, x));
+            }
+            Op::PushBack(x) => {
+                let idx = self.list.push_back(x.clone());
+                let id = self.new_id(idx);
+                self.model.push_back((id, x));
+            }
+            Op::PopFront => {
+                let found = self.list.pop_front();
+                let expected = self.model.pop_front().map(|x| self.remove_id(x));
+                if found != expected {
+                    return fail(DivergenceKind::Output);
+                }
+            }
+            Op::PopBack => {
+                let found = self.list.pop_back();
+                let expected = self.model.pop_back().map(|x| self.remove_id(x));
+                if found != expected {
+                    return fail(DivergenceKind::Output);
+                }
+            }
+            Op::InsertAt(i, x) => {
+                let i = i % (len + 1);
+                let idx = self.list.insert_at(i, x.clone());
+                let id = self.new_id(idx);
+                self.model.insert(i, (id, x));
+            }
+            Op::RemoveAt(i) => {
+                let i = i % (len + 1);
+                let found = self.list.remove_at(i);
+                let expected = self.model.remove(i).map(|x| self.remove_id(x));
+                if found != expected {
+                    return fail(DivergenceKind::Output);
+                }
+            }
+            Op::InsertNextTo(i, x) if len > 0 => {
+                let i = i % len;
+                let idx = self.list.insert_next_to(self.idx_at(i), x.clone());
+                let id = self.new_id(idx);
+                self.model.insert(i + 1, (id, x));
+            }
+            Op::InsertPrevTo(i, x) if len > 0 => {
+                let i = i % len;
+                let idx = self.list.insert_prev_to(self.idx_at(i), x.clone());
+                let id = self.new_id(idx);
+                self.model.insert(i, (id, x));
+            }
+            Op::Remove(i) if len > 0 => {
+                let i = i % len;
+                let found = self.list.remove(self.idx_at(i));
+                let expected = self.model.remove(i).map(|x| self.remove_id(x));
+                if Some(found) != expected {
+                    return fail(DivergenceKind::Output);
+                }
+            }
+            Op::MoveNextTo(i, j) if len > 0 => {
+                let (i, j) = (i % len, j % len);
+                let found = self.list.try_move_next_to(self.idx_at(i), self.idx_at(j));
+                let expected = self.move_in_model(i, j, 1);
+                if found != expected {
+                    return fail(DivergenceKind::MoveResult { expected, found });
+                }
+            }
+            Op::MovePrevTo(i, j) if len > 0 => {
+                let (i, j) = (i % len, j % len);
+                let found = self.list.try_move_prev_to(self.idx_at(i), self.idx_at(j));
+                let expected = self.move_in_model(i, j, 0);
+                if found != expected {
+                    return fail(DivergenceKind::MoveResult { expected, found });
+                }
+            }
+            Op::MoveToFront(i) if len > 0 => {
+                let i = i % len;
+                let found = self.list.try_move_to_front(self.idx_at(i));
+                if let Some(x) = self.model.remove(i) {
+                    self.model.push_front(x);
+                }
+                if found.is_err() {
+                    return fail(DivergenceKind::MoveResult {
+                        expected: Ok(()),
+                        found,
+                    });
+                }
+            }
+            Op::MoveToBack(i) if len > 0 => {
+                let i = i % len;
+                let found = self.list.try_move_to_back(self.idx_at(i));
+                if let Some(x) = self.model.remove(i) {
+                    self.model.push_back(x);
+                }
+                if found.is_err() {
+                    return fail(DivergenceKind::MoveResult {
+                        expected: Ok(()),
+                        found,
+                    });
+                }
+            }
+            Op::Swap(i, j) if len > 0 => {
+                let (i, j) = (i % len, j % len);
+                let found = self.list.try_swap(self.idx_at(i), self.idx_at(j));
+                let expected = match i == j {
+                    true => Err(MoveError::IdenticalNodes),
+                    false => {
+                        self.model.swap(i, j);
+                        Ok(())
+                    }
+                };
+                if found != expected {
+                    return fail(DivergenceKind::MoveResult { expected, found });
+                }
+            }
+            Op::Reverse => {
+                self.list.reverse();
+                self.model.make_contiguous().reverse();
+            }
+            Op::Slice(i, j) if len > 0 => {
+                let (a, b) = ordered(i % len, j % len);
+                let slice = self.list.slice(self.idx_at(a)..=self.idx_at(b));
+                let expected = self.model.range(a..=b).map(|x| &x.1);
+                if !slice.iter().eq(expected.clone()) || !slice.iter().rev().eq(expected.rev()) {
+                    return fail(DivergenceKind::Contents);
+                }
+            }
+            Op::ReverseSlice(i, j) if len > 0 => {
+                let (a, b) = ordered(i % len, j % len);
+                let (idx_a, idx_b) = (self.idx_at(a), self.idx_at(b));
+                self.list.slice_mut(idx_a..=idx_b).reverse();
+                self.model.make_contiguous()[a..=b].reverse();
+            }
+            Op::AppendFront(values) => {
+                let num_appended = values.len();
+                let appended = P::append_front(&mut self.list, values.clone());
+                if appended {
+                    let indices: Vec<_> = self.list.indices().take(num_appended).collect();
+                    for (idx, x) in indices.into_iter().zip(values).rev() {
+                        let id = self.new_id(idx);
+                        self.model.push_front((id, x));
+                    }
+                }
+            }
+            Op::AppendBack(values) => {
+                let appended = P::append_back(&mut self.list, values.clone());
+                if appended {
+                    let indices: Vec<_> = self.list.indices().skip(len).collect();
+                    for (idx, x) in indices.into_iter().zip(values) {
+                        let id = self.new_id(idx);
+                        self.model.push_back((id, x));
+                    }
+                }
+            }
+            Op::Reclaim => _ = self.list.reclaim_closed_nodes(),
+            _ => {}
+        }
+
+        self.verify(num_prior_ids)
+            .map_err(|kind| Divergence { step, kind })
+    }
+
+    fn new_id(&mut self, idx: DoublyIdx<T>) -> usize {
+        self.indices.push(idx);
+        self.indices.len() - 1
+    }
+
+    fn remove_id(&mut self, (id, value): (usize, T)) -> T {
+        self.removed.push(id);
+        value
+    }
+
+    fn idx_at(&self, position: usize) -> DoublyIdx<T> {
+        self.indices[self.model[position].0]
+    }
+
+    /// Moves the element at position `i` next to (`offset = 1`) or prev to (`offset = 0`)
+    /// the element at position `j` in the model; returns the result expected from the list.
+    fn move_in_model(&mut self, i: usize, j: usize, offset: usize) -> Result<(), MoveError> {
+        match (i == j, self.model.remove(i)) {
+            (false, Some(x)) => {
+                let j = if j > i { j - 1 } else { j };
+                self.model.insert(j + offset, x);
+                Ok(())
+            }
+            (_, x) => {
+                if let Some(x) = x {
+                    self.model.insert(i, x);
+                }
+                Err(MoveError::IdenticalNodes)
+            }
+        }
+    }
+
+    fn verify(&mut self, num_prior_ids: usize) -> Result<(), DivergenceKind> {
+        self.list
+            .check_integrity()
+            .map_err(DivergenceKind::Integrity)?;
+
+        let (expected, found) = (self.model.len(), self.list.len());
+        if expected != found {
+            return Err(DivergenceKind::Length { expected, found });
+        }
+
+        let values = self.model.iter().map(|x| &x.1);
+        if !self.list.iter().eq(values.clone()) || !self.list.iter().rev().eq(values.rev()) {
+            return Err(DivergenceKind::Contents);
+        }
+
+        let state = self.list.memory_state();
+        if state != self.state {
+            // nodes are reorganized; all indices obtained before the last step must be invalidated
+            let prior = &self.indices[..num_prior_ids];
+            if prior.iter().any(|idx| self.list.is_valid(*idx)) {
+                return Err(DivergenceKind::StaleIndex);
+            }
+            for (idx, (id, _)) in self.list.indices().zip(&self.model) {
+                self.indices[*id] = idx;
+            }
+            self.state = state;
+        }
+
+        for (position, (id, x)) in self.model.iter().enumerate() {
+            if self.list.get(self.indices[*id]) != Some(x) {
+                return Err(DivergenceKind::InvalidIndex { position });
+            }
+        }
+
+        match self
+            .removed
+            .iter()
+            .any(|id| self.list.is_valid(self.indices[*id]))
+        {
+            true => Err(DivergenceKind::StaleIndex),
+            false => Ok(()),
+        }
+    }
+}
+
+fn ordered(a: usize, b: usize) -> (usize, usize) {
+    match a <= b {
+        true => (a, b),
+        false => (b, a),
+    }
+}
+
+/// Simple splitmix64 generator to create scripts without additional dependencies.
+struct SplitMix(u64);
+
+impl SplitMix {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn pos(&mut self) -> usize {
+        self.next() as usize
+    }
+}
+
+/// Creates a random script of `num_ops` operations deterministically from the `seed`.
+///
+/// New elements are created by the `value` function from a random number.
+/// All variants of [`Op`] are included; operations adding elements are slightly more frequent
+/// than those removing elements so that the list tends to grow.
+pub fn random_script<T, F>(seed: u64, num_ops: usize, mut value: F) -> Vec<Op<T>>
+where
+    F: FnMut(u64) -> T,
+{
+    let mut r = SplitMix(seed);
+    let mut value = |r: &mut SplitMix| value(r.next());
+    (0..num_ops)
+        .map(|_| match r.next() % 24 {
+            0 | 1 => Op::PushFront(value(&mut r)),
+            2 | 3 => Op::PushBack(value(&mut r)),
+            4 => Op::PopFront,
+            5 => Op::PopBack,
+            6 | 7 => Op::InsertAt(r.pos(), value(&mut r)),
+            8 => Op::RemoveAt(r.pos()),
+            9 | 10 => Op::InsertNextTo(r.pos(), value(&mut r)),
+            11 => Op::InsertPrevTo(r.pos(), value(&mut r)),
+            12 => Op::Remove(r.pos()),
+            13 => Op::MoveNextTo(r.pos(), r.pos()),
+            14 => Op::MovePrevTo(r.pos(), r.pos()),
+            15 => Op::MoveToFront(r.pos()),
+            16 => Op::MoveToBack(r.pos()),
+            17 => Op::Swap(r.pos(), r.pos()),
+            18 => Op::Reverse,
+            19 => Op::Slice(r.pos(), r.pos()),
+            20 => Op::ReverseSlice(r.pos(), r.pos()),
+            21 => Op::AppendFront((0..r.next() % 4).map(|_| value(&mut r)).collect()),
+            22 => Op::AppendBack((0..r.next() % 4).map(|_| value(&mut r)).collect()),
+            _ => Op::Reclaim,
+        })
+        .collect()
+}
+
+/// Runs a [`DifferentialChecker`] on the `list` with a random script of `num_ops` operations
+/// created by [`random_script`]; returns the resulting list, or the first divergence, if any.
+pub fn check_random<T, M, P, F>(
+    list: List<Doubly<T>, M, P>,
+    seed: u64,
+    num_ops: usize,
+    value: F,
+) -> Result<List<Doubly<T>, M, P>, Divergence>
+where
+    T: Clone + PartialEq,
+    M: MemoryPolicy<Doubly<T>>,
+    P: CheckedStorage<T>,
+    F: FnMut(u64) -> T,
+{
+    let mut checker = DifferentialChecker::new(list);
+    checker.run(random_script(seed, num_ops, value))?;
+    Ok(checker.into_list())
+}
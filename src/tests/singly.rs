@@ -20,6 +20,8 @@ where
     #[cfg(feature = "validation")]
     #[allow(clippy::unwrap_used)]
     pub fn validate(&self) {
+        assert_eq!(self.check_integrity(), Ok(()));
+
         let num_active_nodes = self.0.nodes().iter().filter(|x| x.is_active()).count();
 
         assert_eq!(num_active_nodes, self.len());
@@ -1,5 +1,5 @@
 use crate::{
-    ListSlice, ListSliceMut,
+    IdxMap, ListSlice, ListSliceMut, MemoryReclaimIncremental,
     list::List,
     variant::{Doubly, ListVariant, Singly},
 };
@@ -138,12 +138,40 @@ pub type DoublyListThreshold<const D: usize, T, P = DefaultPinVec<Doubly<T>>> =
     P,
 >;
 
+/// A doubly linked list with incremental memory reclaim policy:
+/// * nodes hold a reference to the next element, and a reference to the previous;
+/// * memory of removed nodes is reclaimed gradually once utilization falls below 75%;
+///   each removal moves at most `K` nodes while trimming the end of the storage is amortized,
+///   hence, its cost does not grow with the length of the list;
+/// * indices are invalidated only by removals which move nodes or truncate the storage;
+///   see [`MemoryReclaimIncremental`] for details.
+///
+/// Importantly note that methods of the the following traits are also available:
+/// * [`DoublyEnds`]
+/// * [`DoublyEndsMut`]
+/// * [`DoublyIterable`]
+/// * [`DoublyIterableMut`]
+///
+/// [`MemoryReclaimIncremental`]: crate::MemoryReclaimIncremental
+/// [`DoublyEnds`]: crate::DoublyEnds
+/// [`DoublyEndsMut`]: crate::DoublyEndsMut
+/// [`DoublyIterable`]: crate::DoublyIterable
+/// [`DoublyIterableMut`]: crate::DoublyIterableMut
+pub type DoublyListIncremental<const K: usize, T, P = DefaultPinVec<Doubly<T>>> =
+    List<Doubly<T>, MemoryReclaimIncremental<2, K>, P>;
+
 /// An index to an element on a singly linked list which allows safe and constant time access.
 pub type SinglyIdx<T> = NodeIdx<Singly<T>>;
 
 /// An index to an element on a doubly linked list which allows safe and constant time access.
 pub type DoublyIdx<T> = NodeIdx<Doubly<T>>;
 
+/// Translation of indices of a singly linked list into indices of the doubly linked list it is converted into.
+pub type SinglyToDoublyIdxMap<T> = IdxMap<Singly<T>, Doubly<T>>;
+
+/// Translation of indices of a doubly linked list into indices of the singly linked list it is converted into.
+pub type DoublyToSinglyIdxMap<T> = IdxMap<Doubly<T>, Singly<T>>;
+
 /// A slice of a singly linked list with default memory reclaim policy:
 /// * nodes hold a reference to the next element, but not to the previous;
 /// * memory of removed nodes are automatically reclaimed when utilization falls below 75%.
@@ -228,7 +256,7 @@ pub type SinglyListSliceLazy<'a, T> = ListSlice<'a, Singly<T>, MemoryReclaimNeve
 /// [`SinglyEndsMut`]: crate::SinglyEndsMut
 /// [`SinglyIterable`]: crate::SinglyIterable
 /// [`SinglyIterableMut`]: crate::SinglyIterableMut
-pub type SinglyListSliceMutLazy<'a, T> = ListSlice<'a, Singly<T>, MemoryReclaimNever>;
+pub type SinglyListSliceMutLazy<'a, T> = ListSliceMut<'a, Singly<T>, MemoryReclaimNever>;
 
 /// A slice of a doubly linked list with lazy memory reclaim policy:
 /// * nodes hold a reference to the next element, and a reference to the previous;
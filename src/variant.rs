@@ -1,9 +1,15 @@
 use crate::memory::{DoublyReclaimer, SinglyReclaimer};
 use core::marker::PhantomData;
-use orx_selfref_col::{MemoryReclaimer, RefsArray, RefsNone, RefsSingle, Variant};
+use orx_selfref_col::{MemoryReclaimer, Node, NodePtr, RefsArray, RefsNone, RefsSingle, Variant};
 
 pub trait ListVariant: Variant {
     type Reclaimer: MemoryReclaimer<Self>;
+
+    /// Replaces each reference `p` that the `node` holds to other nodes by `relocate(p)`.
+    fn relocate_node_refs(node: &mut Node<Self>, relocate: impl Fn(NodePtr<Self>) -> NodePtr<Self>);
+
+    /// Replaces each reference `p` that the `ends` hold by `relocate(p)`.
+    fn relocate_end_refs(ends: &mut Self::Ends, relocate: impl Fn(NodePtr<Self>) -> NodePtr<Self>);
 }
 
 /// A self referential collection variant representing a singly linked list
@@ -29,6 +35,18 @@ impl<T> Variant for Singly<T> {
 
 impl<T> ListVariant for Singly<T> {
     type Reclaimer = SinglyReclaimer;
+
+    fn relocate_node_refs(
+        node: &mut Node<Self>,
+        relocate: impl Fn(NodePtr<Self>) -> NodePtr<Self>,
+    ) {
+        let next = node.next().get().map(&relocate);
+        node.next_mut().set(next);
+    }
+
+    fn relocate_end_refs(ends: &mut Self::Ends, relocate: impl Fn(NodePtr<Self>) -> NodePtr<Self>) {
+        ends.set(ends.get().map(relocate));
+    }
 }
 
 /// A self referential collection variant representing a doubly linked list
@@ -54,4 +72,20 @@ impl<T> Variant for Doubly<T> {
 
 impl<T> ListVariant for Doubly<T> {
     type Reclaimer = DoublyReclaimer;
+
+    fn relocate_node_refs(
+        node: &mut Node<Self>,
+        relocate: impl Fn(NodePtr<Self>) -> NodePtr<Self>,
+    ) {
+        let next = node.next().get().map(&relocate);
+        node.next_mut().set(next);
+        let prev = node.prev().get().map(&relocate);
+        node.prev_mut().set(prev);
+    }
+
+    fn relocate_end_refs(ends: &mut Self::Ends, relocate: impl Fn(NodePtr<Self>) -> NodePtr<Self>) {
+        for i in 0..2 {
+            ends.set(i, ends.get(i).map(&relocate));
+        }
+    }
 }
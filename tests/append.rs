@@ -52,3 +52,115 @@ fn append_back_doubly<M: MemoryPolicy<Doubly<char>>>(mut list: List<Doubly<char>
     assert_eq!(list.get(a), Some(&'a'));
     assert_eq!(list.get(g), Some(&'g'));
 }
+
+#[test_matrix(
+    [SinglyList::new(), SinglyListLazy::new()]
+)]
+fn append_front_singly<M: MemoryPolicy<Singly<char>>>(mut list: List<Singly<char>, M>) {
+    list.extend_front(['c', 'd', 'e']);
+    list.push_front('b');
+    let a = list.push_front('a');
+
+    let mut other = SinglyList::new();
+    other.push_front('j');
+    other.push_front('i');
+    other.push_front('h');
+    let g = other.push_front('g');
+    other.push_front('f');
+
+    list.append_front(other);
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(['f', 'g', 'h', 'i', 'j', 'a', 'b', 'c', 'd', 'e']));
+
+    assert_eq!(list.get(a), Some(&'a'));
+    assert_eq!(list.get(g), Some(&'g'));
+}
+
+#[test_matrix(
+    [SinglyList::new(), SinglyListLazy::new()]
+)]
+fn append_back_singly<M: MemoryPolicy<Singly<char>>>(mut list: List<Singly<char>, M>) {
+    list.extend_front(['c', 'd', 'e']);
+    list.push_front('b');
+    let a = list.push_front('a');
+
+    let mut other = SinglyList::new();
+    other.extend_front(['f', 'g', 'h', 'i', 'j']);
+    let g = other.indices().nth(1).unwrap();
+
+    list.append_back(other);
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j']));
+
+    assert_eq!(list.get(a), Some(&'a'));
+    assert_eq!(list.get(g), Some(&'g'));
+}
+
+#[test]
+fn append_singly_with_empty() {
+    let mut list = SinglyList::new();
+    list.append_back(SinglyList::from_iter(['a', 'b']));
+    list.append_front(SinglyList::new());
+    list.append_back(SinglyList::new());
+    assert!(list.eq_to_iter_vals(['a', 'b']));
+
+    let mut list = SinglyList::new();
+    list.append_front(SinglyList::from_iter(['a', 'b']));
+    assert!(list.eq_to_iter_vals(['a', 'b']));
+}
+
+#[test]
+fn append_empty_then_reclaim_singly() {
+    let mut list: SinglyListLazy<_> = (0..4).collect();
+    list.append_back(SinglyList::new());
+    list.append_back(SinglyList::from_iter([4, 5, 6]));
+    list.append_front(SinglyListLazy::new());
+    list.append_front(SinglyList::from_iter([-2, -1]));
+
+    for position in [1, 1, 4, 4] {
+        list.remove_at(position);
+    }
+    list.reclaim_closed_nodes();
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert_eq!(list.len(), 5);
+    assert!(list.eq_to_iter_vals([-2, 1, 2, 3, 6]));
+}
+
+#[test_matrix(
+    [DoublyList::new(), DoublyListLazy::new()]
+)]
+fn append_to_empty_doubly<M: MemoryPolicy<Doubly<char>>>(list: List<Doubly<char>, M>) {
+    let mut front = list.clone();
+    front.append_front(DoublyList::from_iter(['a', 'b']));
+    assert_eq!(front.check_integrity(), Ok(()));
+    assert_eq!(front.back(), Some(&'b'));
+    assert!(front.iter().rev().eq(&['b', 'a']));
+
+    let mut back = list;
+    back.append_back(DoublyList::from_iter(['a', 'b']));
+    assert_eq!(back.check_integrity(), Ok(()));
+    assert_eq!(back.front(), Some(&'a'));
+    assert!(back.iter().eq(&['a', 'b']));
+}
+
+#[test]
+fn append_empty_then_reclaim_doubly() {
+    let mut list: DoublyListLazy<_> = (0..4).collect();
+    list.append_back(DoublyList::new());
+    list.append_back(DoublyList::from_iter([4, 5]));
+    list.append_front(DoublyListLazy::new());
+    list.append_front(DoublyList::from_iter([-2, -1]));
+
+    let idx: Vec<_> = list.indices().collect();
+    for i in [2, 3, 4] {
+        list.remove(idx[i]);
+    }
+    list.reclaim_closed_nodes();
+
+    assert_eq!(list.check_integrity(), Ok(()));
+    assert!(list.eq_to_iter_vals([-2, -1, 3, 4, 5]));
+}
@@ -0,0 +1,113 @@
+use orx_linked_list::*;
+
+#[test]
+fn branded_insert_move_swap() {
+    let mut list: DoublyList<_> = (0..6).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    list.scope(|mut list| {
+        let idx: Vec<_> = list.indices().collect();
+        assert_eq!(list.len(), 6);
+
+        list.move_next_to(idx[4], idx[1]);
+        assert!(list.iter().eq(&[0, 1, 4, 2, 3, 5]));
+
+        list.move_prev_to(idx[0], idx[5]);
+        assert!(list.iter().eq(&[1, 4, 2, 3, 0, 5]));
+
+        list.move_to_front(idx[3]);
+        list.move_to_back(idx[1]);
+        assert!(list.iter().eq(&[3, 4, 2, 0, 5, 1]));
+
+        list.swap(idx[3], idx[1]);
+        list.swap(idx[2], idx[0]);
+        assert!(list.iter().eq(&[1, 4, 0, 2, 5, 3]));
+
+        let x = list.insert_next_to(idx[5], 50);
+        let y = list.insert_prev_to(idx[1], 10);
+        let z = list.push_front(100);
+        let w = list.push_back(200);
+        assert!(list.iter().eq(&[100, 10, 1, 4, 0, 2, 5, 50, 3, 200]));
+        assert!(list.iter().rev().eq(&[200, 3, 50, 5, 2, 0, 4, 1, 10, 100]));
+
+        assert_eq!(list.front_idx(), Some(z));
+        assert_eq!(list.back_idx(), Some(w));
+        assert_eq!(list.next_idx_of(idx[5]), Some(x));
+        assert_eq!(list.prev_idx_of(idx[1]), Some(y));
+        assert_eq!(list.prev_idx_of(z), None);
+        assert_eq!(list.next_idx_of(w), None);
+
+        *list.get_mut(x) += 5;
+        assert_eq!(list.get(x), &55);
+        assert_eq!(list.list().len(), 10);
+    });
+
+    assert!(list.eq_to_iter_vals([100, 10, 1, 4, 0, 2, 5, 55, 3, 200]));
+    assert!(list.iter().rev().eq(&[200, 3, 55, 5, 2, 0, 4, 1, 10, 100]));
+    for (i, idx) in idx.iter().enumerate() {
+        assert_eq!(list.get(*idx), Some(&i));
+    }
+}
+
+#[test]
+fn branded_brand_and_unbrand() {
+    let mut list: DoublyList<_> = (0..4).collect();
+    let mut other: DoublyList<_> = (0..4).collect();
+    let idx: Vec<_> = list.indices().collect();
+    let removed = list.push_back(9);
+    list.remove(removed);
+
+    let new_idx = list.scope(|mut branded| {
+        assert!(branded.brand(removed).is_none());
+        assert!(branded.brand(other.idx_of(&0).unwrap()).is_none());
+
+        let b = branded.brand(idx[2]).unwrap();
+        branded.move_to_front(b);
+        assert_eq!(branded.unbrand(b), idx[2]);
+
+        let x = branded.insert_next_to(b, 20);
+        branded.unbrand(x)
+    });
+
+    assert!(list.eq_to_iter_vals([2, 20, 0, 1, 3]));
+    assert_eq!(list.get(new_idx), Some(&20));
+    assert_eq!(list.remove(new_idx), 20);
+
+    let mut empty = DoublyList::<i32>::new();
+    empty.scope(|mut list| {
+        assert!(list.is_empty());
+        assert_eq!(list.front_idx(), None);
+        assert_eq!(list.indices().count(), 0);
+        let a = list.push_back(1);
+        list.move_to_front(a);
+        list.move_to_back(a);
+        list.swap(a, a);
+        list.move_next_to(a, a);
+        assert!(list.iter().eq(&[1]));
+    });
+
+    other.scope(|other| assert!(other.iter().eq(&[0, 1, 2, 3])));
+}
+
+#[test]
+fn branded_lazy_list_with_closed_nodes() {
+    let mut list: DoublyListLazy<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    for i in (0..10).step_by(2) {
+        list.remove(idx[i]);
+    }
+
+    list.scope(|mut list| {
+        let idx: Vec<_> = list.indices().collect();
+        for x in idx.iter().rev() {
+            list.move_to_back(*x);
+        }
+        for _ in 0..100 {
+            list.push_back(0);
+        }
+    });
+
+    assert!(list.iter().take(5).eq(&[9, 7, 5, 3, 1]));
+    assert_eq!(list.len(), 105);
+    assert!(list.iter().rev().skip(100).eq(&[1, 3, 5, 7, 9]));
+}
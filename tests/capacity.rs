@@ -0,0 +1,223 @@
+mod doubly;
+mod singly;
+
+use orx_linked_list::*;
+
+#[test]
+fn with_capacity() {
+    let mut list = SinglyList::with_capacity(64);
+    assert!(list.is_empty());
+    assert!(list.capacity() >= 64);
+
+    for i in 0..100 {
+        list.push_front(i);
+    }
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals((0..100).rev()));
+
+    let list = DoublyListLazy::<char>::with_capacity(0);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn shrink_to_fit_singly() {
+    for seed in 0..10 {
+        let mut r = doubly::rng_with_seed(seed);
+        let mut list = singly::new_singly_lazy(&mut r, 40, 100);
+        let values: Vec<_> = list.iter().cloned().collect();
+        let indices: Vec<_> = list.indices().collect();
+        let had_closed_nodes = list.node_utilization().num_closed_nodes > 0;
+
+        let (old, new) = list.shrink_to_fit();
+        assert_eq!(old != new, had_closed_nodes);
+        assert_eq!(list.node_utilization().num_closed_nodes, 0);
+
+        #[cfg(feature = "validation")]
+        list.validate();
+        assert!(list.eq_to_iter_refs(&values));
+        if had_closed_nodes {
+            assert!(indices.iter().all(|i| list.idx_err(*i).is_some()));
+        }
+
+        let (old, new) = list.shrink_to_fit();
+        assert_eq!(old, new);
+
+        list.push_front("x".to_string());
+        #[cfg(feature = "validation")]
+        list.validate();
+        assert_eq!(list.len(), values.len() + 1);
+    }
+}
+
+#[test]
+fn shrink_to_fit_doubly() {
+    for seed in 0..10 {
+        let mut r = doubly::rng_with_seed(seed);
+        let mut list = doubly::new_doubly(&mut r, 40, 100);
+        let values: Vec<_> = list.iter().cloned().collect();
+
+        list.shrink_to_fit();
+        assert_eq!(list.node_utilization().num_closed_nodes, 0);
+
+        #[cfg(feature = "validation")]
+        list.validate();
+        assert!(list.eq_to_iter_refs(&values));
+        assert!(list.iter().rev().eq(values.iter().rev()));
+
+        let b = list.push_back("b".to_string());
+        let a = list.push_front("a".to_string());
+        #[cfg(feature = "validation")]
+        list.validate();
+        assert_eq!(list.get(a).map(|x| x.as_str()), Some("a"));
+        assert_eq!(list.get(b).map(|x| x.as_str()), Some("b"));
+    }
+}
+
+#[test]
+fn shrink_to_fit_releases_capacity() {
+    let mut list = DoublyListLazy::new();
+    for i in 0..10000 {
+        list.push_back(i);
+    }
+    list.reserve(10000);
+    for _ in 0..9990 {
+        list.pop_back();
+    }
+    assert!(list.capacity() >= 20000);
+
+    list.shrink_to_fit();
+    assert!(list.capacity() < 20);
+    assert!(list.eq_to_iter_vals(0..10));
+
+    while list.pop_front().is_some() {}
+    list.shrink_to_fit();
+    assert_eq!(list.capacity(), 0);
+
+    list.push_back(42);
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals([42]));
+}
+
+#[test]
+fn shrink_to_fit_keeps_indices_without_closed_nodes() {
+    let mut list = DoublyList::new();
+    let indices: Vec<_> = (0..28).map(|i| list.push_back(i)).collect();
+    list.reserve(100);
+    let capacity = list.capacity();
+
+    let (old, new) = list.shrink_to_fit();
+    assert_eq!(old, new);
+    assert!(list.capacity() < capacity);
+    assert_eq!(list.capacity(), 28);
+    assert!(
+        indices
+            .iter()
+            .enumerate()
+            .all(|(i, x)| list.get(*x) == Some(&i))
+    );
+
+    for i in 28..50 {
+        list.push_back(i);
+    }
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(0..50));
+}
+
+#[test]
+fn shrink_to_fit_fixed() {
+    let mut list = DoublyListLazy::with_fixed_capacity(10);
+    let indices: Vec<_> = (0..5).map(|i| list.push_back(i)).collect();
+    assert_eq!(list.remaining_capacity(), 5);
+
+    let (old, new) = list.shrink_to_fit();
+    assert_eq!(old, new);
+    assert_eq!(list.capacity(), 10);
+    assert_eq!(list.remaining_capacity(), 5);
+    assert_eq!(list.get(indices[2]), Some(&2));
+
+    list.pop_front();
+    list.pop_front();
+    let (old, new) = list.shrink_to_fit();
+    assert_ne!(old, new);
+    assert_eq!(list.capacity(), 10);
+    assert_eq!(list.remaining_capacity(), 7);
+
+    for i in 5..12 {
+        list.push_back(i);
+    }
+    assert_eq!(list.remaining_capacity(), 0);
+    assert_eq!(list.try_push_back(12), Err(CapacityError(12)));
+    assert!(list.eq_to_iter_vals(2..12));
+}
+
+#[test]
+fn shrink_to_fit_collected() {
+    let mut list: DoublyList<_> = (0..1000).collect();
+    for _ in 0..990 {
+        list.pop_front();
+    }
+
+    list.shrink_to_fit();
+    assert!(list.capacity() < 20);
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(990..1000));
+    assert!(list.iter().rev().copied().eq((990..1000).rev()));
+
+    let mut list: SinglyList<_> = (0..1000).collect();
+    for _ in 0..990 {
+        list.pop_front();
+    }
+
+    list.shrink_to_fit();
+    assert!(list.capacity() < 20);
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(990..1000));
+}
+
+#[test]
+fn shrink_to_fit_with_capacity() {
+    let mut list = DoublyList::with_capacity(1000);
+    let indices: Vec<_> = (0..10).map(|i| list.push_back(i)).collect();
+    list.push_front(-1);
+    assert_eq!(list.capacity(), 1000);
+
+    let (old, new) = list.shrink_to_fit();
+    assert_ne!(old, new);
+    assert_eq!(list.capacity(), 11);
+    assert!(indices.iter().all(|i| list.idx_err(*i).is_some()));
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(-1..10));
+    assert!(list.iter().rev().copied().eq((-1..10).rev()));
+
+    let (old, new) = list.shrink_to_fit();
+    assert_eq!(old, new);
+
+    for i in 10..100 {
+        list.push_back(i);
+    }
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(-1..100));
+
+    let mut list = SinglyListLazy::with_capacity(1000);
+    for i in 0..10 {
+        list.push_front(i);
+    }
+
+    let (old, new) = list.shrink_to_fit();
+    assert_ne!(old, new);
+    assert_eq!(list.capacity(), 10);
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals((0..10).rev()));
+
+    list.push_front(10);
+    assert!(list.eq_to_iter_vals((0..11).rev()));
+}
@@ -0,0 +1,166 @@
+use orx_linked_list::*;
+use test_case::test_matrix;
+
+#[test_matrix([0, 1, 2, 5, 6, 7, 20], [1, 2, 3, 6, 7, 30])]
+fn chunks_doubly(n: usize, chunk_size: usize) {
+    let mut list = DoublyList::new();
+    for i in 0..n {
+        match i % 2 == 0 {
+            true => list.push_back(i),
+            false => list.push_front(i),
+        };
+    }
+    let vec: Vec<_> = list.iter().copied().collect();
+
+    let expected: Vec<Vec<_>> = vec.chunks(chunk_size).map(|x| x.to_vec()).collect();
+
+    let chunks: Vec<Vec<_>> = list
+        .chunks(chunk_size)
+        .map(|x| x.iter().copied().collect())
+        .collect();
+    assert_eq!(chunks, expected);
+
+    let chunks: Vec<Vec<_>> = list
+        .chunks(chunk_size)
+        .map(|x| x.iter().rev().copied().rev().collect())
+        .collect();
+    assert_eq!(chunks, expected);
+
+    let chunks: Vec<Vec<_>> = list
+        .chunk_indices(chunk_size)
+        .map(|(a, b)| list.slice(a..=b).iter().copied().collect())
+        .collect();
+    assert_eq!(chunks, expected);
+}
+
+#[test_matrix([0, 1, 2, 5, 6, 7, 20], [1, 2, 3, 6, 7, 30])]
+fn chunks_singly(n: usize, chunk_size: usize) {
+    let mut list = SinglyList::new();
+    for i in 0..n {
+        list.push_front(i);
+    }
+    let vec: Vec<_> = list.iter().copied().collect();
+
+    let expected: Vec<Vec<_>> = vec.chunks(chunk_size).map(|x| x.to_vec()).collect();
+
+    let chunks: Vec<Vec<_>> = list
+        .chunks(chunk_size)
+        .map(|x| x.iter().copied().collect())
+        .collect();
+    assert_eq!(chunks, expected);
+
+    let chunks: Vec<Vec<_>> = list
+        .chunk_indices(chunk_size)
+        .map(|(a, b)| {
+            let mut values = vec![];
+            for x in list.iter_from(a) {
+                values.push(*x);
+                if list.get(b) == Some(x) {
+                    break;
+                }
+            }
+            values
+        })
+        .collect();
+    assert_eq!(chunks, expected);
+}
+
+#[test_matrix([0, 1, 2, 5, 6, 7, 20], [1, 2, 3, 6, 7, 30])]
+fn windows_doubly(n: usize, window_size: usize) {
+    let list: DoublyList<_> = (0..n).collect();
+    let vec: Vec<_> = list.iter().copied().collect();
+
+    let expected: Vec<Vec<_>> = vec.windows(window_size).map(|x| x.to_vec()).collect();
+    let windows: Vec<Vec<_>> = list
+        .windows(window_size)
+        .map(|x| x.iter().copied().collect())
+        .collect();
+    assert_eq!(windows, expected);
+}
+
+#[test_matrix([0, 1, 2, 5, 6, 7, 20], [1, 2, 3, 6, 7, 30])]
+fn windows_singly(n: usize, window_size: usize) {
+    let list: SinglyList<_> = (0..n).collect();
+    let vec: Vec<_> = list.iter().copied().collect();
+
+    let expected: Vec<Vec<_>> = vec.windows(window_size).map(|x| x.to_vec()).collect();
+    let windows: Vec<Vec<_>> = list
+        .windows(window_size)
+        .map(|x| x.iter().copied().collect())
+        .collect();
+    assert_eq!(windows, expected);
+}
+
+#[test_matrix([0, 1, 2, 5, 6, 7, 20], [1, 2, 3, 6, 7, 30])]
+fn chunks_mut_doubly(n: usize, chunk_size: usize) {
+    let mut list: DoublyList<_> = (0..n).collect();
+    let mut vec: Vec<_> = (0..n).collect();
+
+    let mut chunks = list.chunks_mut(chunk_size);
+    while let Some(mut chunk) = chunks.next_chunk() {
+        chunk.reverse();
+        for x in chunk.iter_mut() {
+            *x += 100;
+        }
+    }
+
+    for chunk in vec.chunks_mut(chunk_size) {
+        chunk.reverse();
+        for x in chunk.iter_mut() {
+            *x += 100;
+        }
+    }
+
+    #[cfg(feature = "validation")]
+    list.validate();
+
+    assert!(list.eq_to_iter_refs(&vec));
+}
+
+#[test_matrix([0, 1, 2, 5, 6, 7, 20], [1, 2, 3, 6, 7, 30])]
+fn chunks_mut_singly(n: usize, chunk_size: usize) {
+    let mut list: SinglyList<_> = (0..n).collect();
+    let mut vec: Vec<_> = (0..n).collect();
+
+    let mut chunks = list.chunks_mut(chunk_size);
+    while let Some(mut chunk) = chunks.next_chunk() {
+        let len = chunk.iter().count();
+        for x in chunk.iter_mut() {
+            *x += 100 * len;
+        }
+    }
+
+    for chunk in vec.chunks_mut(chunk_size) {
+        let len = chunk.len();
+        for x in chunk.iter_mut() {
+            *x += 100 * len;
+        }
+    }
+
+    assert!(list.eq_to_iter_refs(&vec));
+}
+
+#[test]
+fn chunks_mut_of_slice() {
+    let mut list: DoublyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let mut slice = list.slice_mut(idx[2]..=idx[8]);
+    let mut chunks = slice.chunks_mut(3);
+
+    let mut num_chunks = 0;
+    while let Some(mut chunk) = chunks.next_chunk() {
+        num_chunks += 1;
+        chunk.reverse();
+    }
+    assert_eq!(num_chunks, 3);
+
+    assert!(list.eq_to_iter_vals([0, 1, 4, 3, 2, 7, 6, 5, 8, 9]));
+}
+
+#[test]
+#[should_panic]
+fn chunks_with_zero_size() {
+    let list: DoublyList<_> = (0..10).collect();
+    let _ = list.chunks(0);
+}
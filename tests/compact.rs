@@ -0,0 +1,137 @@
+mod doubly;
+mod singly;
+
+use orx_linked_list::*;
+
+#[test]
+fn compact_singly() {
+    for seed in 0..10 {
+        let mut r = doubly::rng_with_seed(seed);
+        let mut list = singly::new_singly_lazy(&mut r, 40, 100);
+        let values: Vec<_> = list.iter().cloned().collect();
+        let indices: Vec<_> = list.indices().collect();
+        let capacity = list.capacity();
+
+        let (old, new) = list.compact_in_list_order();
+        assert_ne!(old, new);
+
+        #[cfg(feature = "validation")]
+        list.validate();
+        assert!(list.eq_to_iter_refs(&values));
+        assert!(list.iter_x().eq(list.iter()));
+        assert!(list.capacity() <= capacity);
+        assert!(indices.iter().all(|i| list.idx_err(*i).is_some()));
+
+        let (old, new) = list.compact_in_list_order();
+        assert_eq!(old, new);
+
+        let idx = list.push_front("x".to_string());
+        #[cfg(feature = "validation")]
+        list.validate();
+        assert_eq!(list.get(idx).map(|x| x.as_str()), Some("x"));
+    }
+}
+
+#[test]
+fn compact_doubly() {
+    for seed in 0..10 {
+        let mut r = doubly::rng_with_seed(seed);
+        let mut list = doubly::new_doubly(&mut r, 40, 100);
+        let values: Vec<_> = list.iter().cloned().collect();
+        let indices: Vec<_> = list.indices().collect();
+
+        list.compact_in_list_order();
+
+        #[cfg(feature = "validation")]
+        list.validate();
+        assert!(list.eq_to_iter_refs(&values));
+        assert!(list.iter().rev().eq(values.iter().rev()));
+        assert!(list.iter_x().eq(list.iter()));
+        assert!(indices.iter().all(|i| list.idx_err(*i).is_some()));
+
+        let a = list.push_front("a".to_string());
+        let b = list.push_back("b".to_string());
+        #[cfg(feature = "validation")]
+        list.validate();
+        assert_eq!(list.get(a).map(|x| x.as_str()), Some("a"));
+        assert_eq!(list.get(b).map(|x| x.as_str()), Some("b"));
+    }
+}
+
+#[test]
+fn compact_doubly_fixed_after_moves() {
+    let mut list = DoublyListLazy::with_fixed_capacity(16);
+    let idx: Vec<_> = (0..10).map(|i| list.push_back(i)).collect();
+    list.move_to_front(idx[9]);
+    list.move_next_to(idx[0], idx[5]);
+    list.remove_at(3);
+    list.remove_at(7);
+
+    let expected = [9, 1, 2, 4, 5, 0, 6, 8];
+    assert!(list.eq_to_iter_vals(expected));
+    assert!(list.iter_x().ne(list.iter()));
+
+    list.compact_in_list_order();
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(expected));
+    assert!(list.iter_x().copied().eq(expected));
+    assert_eq!(list.capacity(), 16);
+    assert_eq!(list.remaining_capacity(), 8);
+}
+
+#[test]
+fn compact_empty() {
+    let mut list = DoublyListLazy::new();
+    let (old, new) = list.compact_in_list_order();
+    assert_eq!(old, new);
+
+    list.push_back('a');
+    list.pop_back();
+    let (old, new) = list.compact_in_list_order();
+    assert_ne!(old, new);
+    assert!(list.is_empty());
+    assert_eq!(list.iter_x().count(), 0);
+
+    list.push_back('b');
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(['b']));
+}
+
+#[test]
+fn compact_after_append() {
+    // appended storages leave partially filled fragments in the middle of the storage
+    let mut list = DoublyListLazy::new();
+    list.push_back(1);
+    list.append_back(DoublyList::from_iter([2, 3]));
+    list.append_front(DoublyList::from_iter([-1, 0]));
+    list.push_back(4);
+    list.push_front(-2);
+
+    list.compact_in_list_order();
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(-2..5));
+    assert!(
+        list.iter()
+            .rev()
+            .eq((-2..5).rev().collect::<Vec<_>>().iter())
+    );
+    assert!(list.iter_x().eq(list.iter()));
+
+    let mut list = SinglyListLazy::new();
+    list.push_front(1);
+    list.append_back(SinglyList::from_iter([2, 3]));
+    list.append_front(SinglyList::from_iter([-1, 0]));
+    list.push_front(-2);
+
+    list.compact_in_list_order();
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(-2..4));
+    assert!(list.iter_x().eq(list.iter()));
+}
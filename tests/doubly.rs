@@ -148,6 +148,16 @@ pub fn new_doubly_with<const D: usize>(
     list
 }
 
+pub fn new_doubly_incremental<const K: usize>(
+    r: &mut R,
+    grow_len: usize,
+    num_mutations: usize,
+) -> DoublyListIncremental<K, String> {
+    let mut list = DoublyList::with_incremental_reclaimer::<K>();
+    Actions::apply_all(r, grow_len, num_mutations, &mut list);
+    list
+}
+
 pub fn new_doubly_lazy(r: &mut R, grow_len: usize, num_mutations: usize) -> DoublyListLazy<String> {
     let mut list = DoublyListLazy::new();
     Actions::apply_all(r, grow_len, num_mutations, &mut list);
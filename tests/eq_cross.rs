@@ -0,0 +1,88 @@
+use orx_linked_list::*;
+
+#[test]
+fn eq_std_sequences() {
+    let singly: SinglyList<_> = [1, 2, 3].into_iter().collect();
+    let doubly: DoublyList<_> = [1, 2, 3].into_iter().collect();
+
+    assert_eq!(singly, [1, 2, 3]);
+    assert_eq!(doubly, [1, 2, 3]);
+    assert_eq!(doubly, vec![1, 2, 3]);
+    assert_eq!(doubly, &[1, 2, 3][..]);
+    assert_eq!(doubly, *[1, 2, 3].as_slice());
+
+    assert_eq!([1, 2, 3], singly);
+    assert_eq!(vec![1, 2, 3], doubly);
+    assert_eq!(&[1, 2, 3][..], doubly);
+
+    assert_ne!(doubly, [1, 2]);
+    assert_ne!(doubly, [1, 2, 3, 4]);
+    assert_ne!(vec![3, 2, 1], singly);
+
+    let empty = DoublyList::<i32>::new();
+    assert_eq!(empty, []);
+    assert_eq!(empty, Vec::<i32>::new());
+}
+
+#[test]
+fn eq_across_memory_policies_and_pinned_vecs() {
+    let default: DoublyList<_> = (0..5).collect();
+
+    let mut lazy = DoublyListLazy::new();
+    lazy.extend(0..6);
+    lazy.pop_back();
+
+    let mut fixed: DoublyList<_, _> = DoublyList::with_fixed_capacity(8);
+    fixed.extend(0..5);
+
+    let threshold: DoublyListThreshold<4, _> = (0..5).collect();
+
+    assert_eq!(default, lazy);
+    assert_eq!(lazy, default);
+    assert!(default == fixed);
+    assert!(fixed == threshold);
+
+    lazy.push_back(5);
+    assert_ne!(default, lazy);
+
+    let singly: SinglyList<_> = (0..5).collect();
+    let singly_lazy: SinglyListLazy<_> = (0..5).collect();
+    assert_eq!(singly, singly_lazy);
+}
+
+#[test]
+fn eq_lists_and_slices() {
+    let mut list: DoublyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let other: DoublyListLazy<_> = (3..6).collect();
+
+    assert!(list.slice(..) == list);
+    assert!(list == list.slice(..));
+    assert!(list.slice(idx[3]..idx[6]) == other);
+    assert!(other == list.slice(idx[3]..idx[6]));
+    assert!(list.slice(idx[3]..idx[6]) == [3, 4, 5]);
+    assert!(vec![3, 4, 5] == list.slice(idx[3]..idx[6]));
+
+    let full: DoublyList<_> = (0..10).collect();
+    let slice_mut = list.slice_mut(idx[3]..idx[6]);
+    assert!(slice_mut == other);
+    assert!(slice_mut == [3, 4, 5]);
+    let full_idx: Vec<_> = full.indices().collect();
+    assert!(slice_mut == full.slice(full_idx[3]..full_idx[6]));
+    assert!(other == slice_mut);
+}
+
+#[test]
+fn eq_singly_slices() {
+    let list: SinglyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let slice = list.slice(idx[7]..);
+    assert!(slice == [7, 8, 9]);
+    assert!([7, 8, 9] == slice);
+
+    let other: SinglyListLazy<_> = (7..10).collect();
+    assert!(slice == other);
+    assert!(other == slice);
+}
@@ -1,4 +1,5 @@
 mod doubly;
+mod singly;
 
 use orx_linked_list::*;
 
@@ -17,3 +18,38 @@ fn extend_doubly() {
 
     assert!(list.eq_to_iter_vals(expected));
 }
+
+#[test]
+fn extend_singly() {
+    let mut list = singly::new_singly(&mut doubly::rng(), 20, 30);
+    let mut expected: Vec<_> = list.iter().cloned().collect();
+
+    let mut second: Vec<_> = (0..11).map(|i| i.to_string()).collect();
+    list.extend(second.clone());
+    expected.append(&mut second);
+
+    let mut third: Vec<_> = (0..7).map(|i| (42 + i).to_string()).collect();
+    list.extend(&third);
+    expected.append(&mut third);
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(expected));
+}
+
+#[test]
+fn extend_front_singly() {
+    let mut list = SinglyList::new();
+    list.extend_front(Vec::<usize>::new());
+    assert!(list.is_empty());
+
+    list.extend_front([3, 4]);
+    list.extend_front([0, 1, 2]);
+    list.extend_front(Vec::<usize>::new());
+    list.extend([5, 6]);
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(0..7));
+    assert_eq!(list.len(), 7);
+}
@@ -0,0 +1,235 @@
+use orx_linked_list::*;
+
+#[test]
+fn singly_try_push_front() {
+    let mut list = SinglyList::with_fixed_capacity(8);
+    for i in 0..8 {
+        assert_eq!(list.remaining_capacity(), 8 - i);
+        assert!(list.try_push_front(i).is_ok());
+    }
+
+    assert_eq!(list.remaining_capacity(), 0);
+    assert_eq!(list.try_push_front(42), Err(CapacityError(42)));
+    assert_eq!(
+        list.try_insert_at(3, 42),
+        Err(InsertError::NoCapacity(CapacityError(42)))
+    );
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals((0..8).rev()));
+}
+
+#[test]
+fn doubly_try_push() {
+    let mut list = DoublyList::with_fixed_capacity(8);
+    let mut vec = std::collections::VecDeque::new();
+    for i in 0..8 {
+        match i % 2 == 0 {
+            true => {
+                assert!(list.try_push_back(i).is_ok());
+                vec.push_back(i);
+            }
+            false => {
+                assert!(list.try_push_front(i).is_ok());
+                vec.push_front(i);
+            }
+        }
+    }
+
+    assert_eq!(list.remaining_capacity(), 0);
+    assert_eq!(list.try_push_back(42), Err(CapacityError(42)));
+    assert_eq!(list.try_push_front(42), Err(CapacityError(42)));
+    assert_eq!(
+        list.try_insert_at(8, 42),
+        Err(InsertError::NoCapacity(CapacityError(42)))
+    );
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_refs(&vec));
+}
+
+#[test]
+fn doubly_try_insert_next_prev_to() {
+    let mut list = DoublyList::with_fixed_capacity(5);
+    let b = list.push_back('b');
+    let d = list.push_back('d');
+
+    assert!(list.try_insert_prev_to_within_capacity(b, 'a').is_ok());
+    assert!(list.try_insert_next_to_within_capacity(b, 'c').is_ok());
+    let e = list
+        .try_insert_next_to_within_capacity(d, 'e')
+        .expect("has room");
+    assert_eq!(list.back(), Some(&'e'));
+    assert_eq!(list.get(e), Some(&'e'));
+
+    assert_eq!(
+        list.try_insert_next_to_within_capacity(d, 'x'),
+        Err(InsertError::NoCapacity(CapacityError('x')))
+    );
+    assert_eq!(
+        list.try_insert_prev_to_within_capacity(b, 'y'),
+        Err(InsertError::NoCapacity(CapacityError('y')))
+    );
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(['a', 'b', 'c', 'd', 'e']));
+}
+
+#[test]
+fn try_insert_errors_give_back_the_value() {
+    let mut list = SinglyList::with_fixed_capacity(4);
+    list.push_front(1);
+    list.push_front(0);
+
+    let error = list.try_insert_at(3, 42);
+    assert_eq!(error, Err(InsertError::OutOfBounds(42)));
+    assert_eq!(error.map_err(|e| e.into_inner()), Err(42));
+
+    let mut list = DoublyListLazy::with_fixed_capacity(4);
+    let a = list.push_back('a');
+    let b = list.push_back('b');
+    list.remove(b);
+
+    assert_eq!(
+        list.try_insert_at(2, 'x'),
+        Err(InsertError::OutOfBounds('x'))
+    );
+    assert_eq!(
+        list.try_insert_next_to_within_capacity(b, 'x'),
+        Err(InsertError::InvalidIdx(NodeIdxError::RemovedNode, 'x'))
+    );
+    assert_eq!(
+        list.try_insert_prev_to_within_capacity(b, 'y'),
+        Err(InsertError::InvalidIdx(NodeIdxError::RemovedNode, 'y'))
+    );
+
+    let mut other = DoublyList::with_fixed_capacity(8);
+    let other_idx = (0..8).map(|_| other.push_back('o')).last().unwrap();
+    let error = list.try_insert_next_to_within_capacity(other_idx, 'z');
+    assert!(matches!(error, Err(InsertError::InvalidIdx(_, 'z'))));
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert_eq!(list.check_integrity(), Ok(()));
+    assert!(list.eq_to_iter_vals(['a']));
+    assert_eq!(list.get(a), Some(&'a'));
+}
+
+#[test]
+fn closed_nodes_occupy_capacity() {
+    let mut list = DoublyListLazy::with_fixed_capacity(4);
+    for i in 0..4 {
+        list.push_back(i);
+    }
+    assert_eq!(list.pop_front(), Some(0));
+    assert_eq!(list.remaining_capacity(), 0);
+    assert_eq!(list.try_push_back(4), Err(CapacityError(4)));
+
+    list.reclaim_closed_nodes();
+    assert_eq!(list.remaining_capacity(), 1);
+    assert!(list.try_push_back(4).is_ok());
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals([1, 2, 3, 4]));
+}
+
+#[test]
+fn capacity_error_into_inner() {
+    let mut list = SinglyList::with_fixed_capacity(0);
+    let error = list.try_push_front(String::from("x")).unwrap_err();
+    assert_eq!(error.into_inner(), String::from("x"));
+    assert!(list.is_empty());
+}
+
+#[test]
+fn reserve_keeps_indices() {
+    let mut list = DoublyListLazy::new();
+    let mut indices: Vec<_> = (0..10).map(|i| list.push_back(i)).collect();
+
+    list.reserve(100);
+    list.reserve(50);
+    indices.extend((10..200).map(|i| list.push_back(i)));
+
+    list.reserve(1000);
+    list.reserve(1000);
+    indices.extend((200..300).map(|i| list.push_front(i)));
+
+    #[cfg(feature = "validation")]
+    list.validate();
+
+    assert_eq!(list.len(), 300);
+    for (i, idx) in indices.iter().enumerate() {
+        assert_eq!(list.get(*idx), Some(&i));
+    }
+
+    for idx in indices.iter().step_by(2) {
+        list.remove(*idx);
+    }
+    list.reclaim_closed_nodes();
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert_eq!(list.len(), 150);
+    assert!(list.iter().all(|x| x % 2 == 1));
+}
+
+#[test]
+fn try_reserve_singly() {
+    let mut list = SinglyList::new();
+    assert!(list.try_reserve(10).is_ok());
+    for i in 0..20 {
+        list.push_front(i);
+    }
+    assert!(list.try_reserve(usize::MAX).is_err());
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals((0..20).rev()));
+}
+
+#[test]
+fn reserve_allocates_additional() {
+    let mut list = DoublyList::new();
+    assert_eq!(list.capacity(), 4);
+
+    list.reserve(10);
+    assert_eq!(list.capacity(), 10);
+
+    let a = list.push_back(0);
+    list.reserve(9);
+    assert_eq!(list.capacity(), 10);
+
+    list.reserve(20);
+    assert_eq!(list.capacity(), 30);
+
+    for i in 1..21 {
+        list.push_back(i);
+    }
+    assert_eq!(list.capacity(), 30);
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_vals(0..21));
+    assert_eq!(list.get(a), Some(&0));
+}
+
+#[test]
+fn reserve_then_push_additional_does_not_grow() {
+    for additional in [1, 3, 10, 100] {
+        let mut list = SinglyList::new();
+        list.push_front(0);
+
+        list.reserve(additional);
+        let capacity = list.capacity();
+        assert!(capacity - list.len() >= additional);
+
+        for i in 0..additional {
+            list.push_front(i);
+        }
+        assert_eq!(list.capacity(), capacity);
+    }
+}
@@ -1,4 +1,6 @@
 use orx_linked_list::*;
+use orx_selfref_col::Node;
+use orx_split_vec::{Doubling, SplitVec};
 use test_case::test_matrix;
 
 #[test_matrix([0, 17, 200])]
@@ -52,3 +54,28 @@ fn from_iter_doubly(len: usize) {
         }
     }
 }
+
+#[test]
+fn from_iter_grows_as_push() {
+    let collected: DoublyList<_> = (0..1000).collect();
+
+    let mut pushed = DoublyList::new();
+    for i in 0..1000 {
+        pushed.push_back(i);
+    }
+
+    assert_eq!(collected.capacity(), pushed.capacity());
+    assert!(collected.eq_to_iter_vals(0..1000));
+}
+
+#[test]
+fn from_iter_non_default_pinned_vec() {
+    let list: DoublyList<_, SplitVec<Node<Doubly<_>>, Doubling>> = (0..100).collect();
+    assert!(list.eq_to_iter_vals(0..100));
+    assert!(list.iter().rev().copied().eq((0..100).rev()));
+    assert_eq!(list.check_integrity(), Ok(()));
+
+    let list: SinglyListLazy<_, SplitVec<Node<Singly<_>>, Doubling>> = (0..100).collect();
+    assert!(list.eq_to_iter_vals(0..100));
+    assert_eq!(list.check_integrity(), Ok(()));
+}
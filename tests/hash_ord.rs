@@ -0,0 +1,92 @@
+use orx_linked_list::*;
+use std::collections::{BTreeMap, HashSet};
+use std::hash::{BuildHasher, Hash, RandomState};
+
+fn hash_of<H: Hash>(state: &RandomState, x: &H) -> u64 {
+    state.hash_one(x)
+}
+
+#[test]
+fn hash_list_and_full_slice() {
+    let state = RandomState::new();
+
+    let mut doubly: DoublyList<_> = (0..10).collect();
+    let h = hash_of(&state, &doubly);
+    assert_eq!(h, hash_of(&state, &doubly.slice(..)));
+    assert_eq!(h, hash_of(&state, &doubly.slice_mut(..)));
+
+    let mut singly: SinglyList<_> = (0..10).collect();
+    let h = hash_of(&state, &singly);
+    assert_eq!(h, hash_of(&state, &singly.slice(..)));
+    assert_eq!(h, hash_of(&state, &singly.slice_mut(..)));
+}
+
+#[test]
+fn hash_independent_of_layout() {
+    let state = RandomState::new();
+
+    let a: DoublyList<_> = (0..5).collect();
+
+    let mut b = DoublyListLazy::new();
+    b.push_back(3);
+    b.push_back(4);
+    b.push_front(9);
+    b.pop_front();
+    b.push_front(2);
+    b.push_front(1);
+    b.push_front(0);
+
+    assert!(b.iter().eq(a.iter()));
+    assert_eq!(hash_of(&state, &a), hash_of(&state, &b));
+}
+
+#[test]
+fn hash_set_of_lists() {
+    let mut set: HashSet<DoublyList<_>> = HashSet::new();
+    assert!(set.insert(DoublyList::from_iter([1, 2, 3])));
+    assert!(set.insert(DoublyList::from_iter([1, 2])));
+    assert!(set.insert(DoublyList::from_iter([])));
+    assert!(!set.insert(DoublyList::from_iter([1, 2, 3])));
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn ord_lexicographic() {
+    let a: SinglyList<_> = [1, 2, 3].into_iter().collect();
+    let b: SinglyList<_> = [1, 2, 4].into_iter().collect();
+    let c: SinglyList<_> = [1, 2].into_iter().collect();
+
+    assert!(a < b);
+    assert!(c < a);
+    assert!(c < b);
+    assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+
+    let x: DoublyList<f64> = [1.0, f64::NAN].into_iter().collect();
+    let y: DoublyList<f64> = [1.0, 2.0].into_iter().collect();
+    assert_eq!(x.partial_cmp(&y), None);
+}
+
+#[test]
+fn ord_slices() {
+    let list: DoublyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let a = list.slice(idx[2]..idx[5]);
+    let b = list.slice(idx[3]..idx[4]);
+    assert!(a < b);
+    assert_eq!(
+        a.cmp(&list.slice(idx[2]..idx[5])),
+        std::cmp::Ordering::Equal
+    );
+}
+
+#[test]
+fn btree_map_keys() {
+    let mut map: BTreeMap<DoublyList<_>, _> = BTreeMap::new();
+    map.insert(DoublyList::from_iter(['b']), 2);
+    map.insert(DoublyList::from_iter(['a', 'z']), 1);
+    map.insert(DoublyList::from_iter(['b', 'a']), 3);
+
+    let values: Vec<_> = map.values().copied().collect();
+    assert_eq!(values, [1, 2, 3]);
+}
@@ -0,0 +1,127 @@
+mod doubly;
+
+use orx_linked_list::*;
+
+fn max_moved_per_removal<const K: usize>(list: &mut DoublyListIncremental<K, usize>) -> usize {
+    let mut max_moved = 0;
+    while !list.is_empty() {
+        let positions_before: Vec<_> = list.iter_ptr().collect();
+        list.remove_at(list.len() / 2);
+
+        #[cfg(feature = "validation")]
+        list.validate();
+
+        let positions_after: Vec<_> = list.iter_ptr().collect();
+        let mut before = positions_before.clone();
+        before.remove(positions_before.len() / 2);
+        let moved = before
+            .iter()
+            .zip(positions_after.iter())
+            .filter(|(a, b)| a != b)
+            .count();
+        max_moved = max_moved.max(moved);
+    }
+    max_moved
+}
+
+#[test]
+fn incremental_moves_at_most_k_nodes() {
+    let mut list = DoublyList::with_incremental_reclaimer::<1>();
+    list.extend(0..1000);
+    assert!(max_moved_per_removal(&mut list) <= 1);
+
+    let mut list = DoublyList::with_incremental_reclaimer::<4>();
+    list.extend(0..1000);
+    assert!(max_moved_per_removal(&mut list) <= 4);
+}
+
+#[test]
+fn incremental_keeps_utilization_bounded() {
+    let mut list = DoublyList::with_incremental_reclaimer::<4>();
+    list.extend(0..1000);
+
+    for i in 0..990 {
+        match i % 3 {
+            0 => _ = list.pop_front(),
+            1 => _ = list.pop_back(),
+            _ => _ = list.remove_at(list.len() / 2),
+        }
+
+        let utilization = list.node_utilization();
+        let used = utilization.num_active_nodes + utilization.num_closed_nodes;
+        assert!(utilization.num_closed_nodes * 3 <= used);
+    }
+
+    assert_eq!(list.len(), 10);
+}
+
+#[test]
+fn incremental_indices_valid_until_threshold() {
+    let mut list = DoublyList::with_incremental_reclaimer::<2>();
+    let idx: Vec<_> = (0..100).map(|i| list.push_back(i)).collect();
+
+    // 25 closed nodes out of 100 is within the threshold
+    for i in (0..100).step_by(4) {
+        let state = list.memory_state();
+        list.remove(idx[i]);
+        assert_eq!(state, list.memory_state());
+    }
+    assert_eq!(list.node_utilization().num_closed_nodes, 25);
+    assert!(
+        idx.iter()
+            .enumerate()
+            .filter(|(i, _)| i % 4 != 0)
+            .all(|(_, x)| list.idx_err(*x).is_none())
+    );
+
+    // exceeding the threshold moves nodes, which invalidates indices
+    let state = list.memory_state();
+    list.remove(idx[1]);
+    assert_ne!(state, list.memory_state());
+    assert!(list.idx_err(idx[2]).is_some());
+
+    // insertions never invalidate indices
+    let state = list.memory_state();
+    let a = list.push_front(1000);
+    let b = list.push_back(1001);
+    assert_eq!(state, list.memory_state());
+    assert_eq!(list.get(a), Some(&1000));
+    assert_eq!(list.get(b), Some(&1001));
+}
+
+#[test]
+fn incremental_random_mutations() {
+    for seed in 0..10 {
+        let mut r = doubly::rng_with_seed(seed);
+        let mut list = doubly::new_doubly_incremental::<3>(&mut r, 50, 200);
+
+        let values: Vec<_> = list.iter().cloned().collect();
+        list.reclaim_closed_nodes();
+
+        #[cfg(feature = "validation")]
+        list.validate();
+        assert_eq!(list.node_utilization().num_closed_nodes, 0);
+        assert!(list.eq_to_iter_refs(&values));
+    }
+}
+
+#[test]
+fn incremental_trimming_invalidates_removed_indices() {
+    let mut list = DoublyList::with_incremental_reclaimer::<1>();
+    list.extend(0..8);
+    let idx: Vec<_> = list.indices().collect();
+
+    // removals from the back only trim the storage without moving any node
+    let state = list.memory_state();
+    for _ in 0..3 {
+        list.pop_back();
+    }
+    assert_ne!(list.memory_state(), state);
+    assert!(idx[..5].iter().all(|i| list.idx_err(*i).is_some()));
+
+    // trimmed positions are reused by later pushes
+    let new_idx: Vec<_> = (5..8).map(|i| list.push_back(i * 10)).collect();
+    assert!(idx[5..].iter().all(|i| list.get(*i).is_none()));
+    assert!(new_idx.iter().all(|i| list.get(*i).is_some()));
+    assert!(list.eq_to_iter_vals([0, 1, 2, 3, 4, 50, 60, 70]));
+}
@@ -25,7 +25,7 @@ fn insert_at_singly<M: MemoryPolicy<Singly<char>>>(mut list: List<Singly<char>,
 #[should_panic]
 #[test]
 fn insert_at_singly_oob() {
-    let mut list = SinglyList::from_iter(['b', 'c', 'd']);
+    let mut list: SinglyList<_> = SinglyList::from_iter(['b', 'c', 'd']);
     let _ = list.insert_at(4, 'x');
 }
 
@@ -53,7 +53,7 @@ fn insert_at_doubly<M: MemoryPolicy<Doubly<char>>>(mut list: List<Doubly<char>,
 #[should_panic]
 #[test]
 fn insert_at_doubly_oob() {
-    let mut list = DoublyList::from_iter(['b', 'c', 'd']);
+    let mut list: DoublyList<_> = DoublyList::from_iter(['b', 'c', 'd']);
     let _ = list.insert_at(4, 'x');
 }
 
@@ -81,6 +81,6 @@ fn insert_at_from_back_doubly<M: MemoryPolicy<Doubly<char>>>(mut list: List<Doub
 #[should_panic]
 #[test]
 fn insert_at_from_back_doubly_oob() {
-    let mut list = DoublyList::from_iter(['b', 'c', 'd']);
+    let mut list: DoublyList<_> = DoublyList::from_iter(['b', 'c', 'd']);
     let _ = list.insert_at_from_back(4, 'x');
 }
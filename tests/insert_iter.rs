@@ -0,0 +1,99 @@
+use orx_linked_list::*;
+use test_case::test_matrix;
+
+#[test_matrix([0, 1, 4], [0, 1, 3])]
+fn push_iter(n: usize, m: usize) {
+    let new_values = || (100..(100 + m)).collect::<Vec<_>>();
+
+    let mut list: DoublyList<_> = (0..n).collect();
+    let idx = list.push_back_iter(new_values());
+    let mut vec: Vec<_> = (0..n).collect();
+    vec.extend(new_values());
+
+    #[cfg(feature = "validation")]
+    list.validate();
+
+    assert!(list.eq_to_iter_refs(&vec));
+    assert_eq!(idx.len(), m);
+    for (i, x) in idx.iter().enumerate() {
+        assert_eq!(list.get(*x), Some(&(100 + i)));
+    }
+    assert_eq!(list.back(), vec.last());
+
+    let mut list: DoublyList<_> = (0..n).collect();
+    let idx = list.push_front_iter(new_values());
+    let mut vec = new_values();
+    vec.extend(0..n);
+
+    #[cfg(feature = "validation")]
+    list.validate();
+
+    assert!(list.eq_to_iter_refs(&vec));
+    assert!(list.iter().rev().eq(vec.iter().rev()));
+    assert_eq!(idx.len(), m);
+    for (i, x) in idx.iter().enumerate() {
+        assert_eq!(list.get(*x), Some(&(100 + i)));
+    }
+    assert_eq!(list.front(), vec.first());
+}
+
+#[test_matrix([1, 2, 5], [0, 1, 3])]
+fn insert_iter(n: usize, m: usize) {
+    let new_values = || (100..(100 + m)).collect::<Vec<_>>();
+
+    for i in 0..n {
+        let mut list: DoublyList<_> = (0..n).collect();
+        let indices: Vec<_> = list.indices().collect();
+        let idx = list.insert_iter_next_to(indices[i], new_values());
+
+        let mut vec: Vec<_> = (0..n).collect();
+        vec.splice((i + 1)..(i + 1), new_values());
+
+        #[cfg(feature = "validation")]
+        list.validate();
+
+        assert!(list.eq_to_iter_refs(&vec));
+        assert!(list.iter().rev().eq(vec.iter().rev()));
+        assert_eq!(idx.len(), m);
+        for (j, x) in idx.iter().enumerate() {
+            assert_eq!(list.get(*x), Some(&(100 + j)));
+        }
+
+        let mut list: DoublyList<_> = (0..n).collect();
+        let indices: Vec<_> = list.indices().collect();
+        let idx = list.insert_iter_prev_to(indices[i], new_values());
+
+        let mut vec: Vec<_> = (0..n).collect();
+        vec.splice(i..i, new_values());
+
+        #[cfg(feature = "validation")]
+        list.validate();
+
+        assert!(list.eq_to_iter_refs(&vec));
+        assert!(list.iter().rev().eq(vec.iter().rev()));
+        assert_eq!(idx.len(), m);
+        for (j, x) in idx.iter().enumerate() {
+            assert_eq!(list.get(*x), Some(&(100 + j)));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn insert_iter_with_removed_idx() {
+    let mut list: DoublyList<_> = (0..5).collect();
+    let idx = list.push_back(5);
+    list.remove(idx);
+    let _ = list.insert_iter_next_to(idx, [6, 7]);
+}
+
+#[test_matrix([0, 1, 10])]
+fn from_iter_with_indices(n: usize) {
+    let (list, idx) = DoublyList::from_iter_with_indices(0..n);
+    assert!(list.eq_to_iter_vals(0..n));
+    assert_eq!(idx, list.indices().collect::<Vec<_>>());
+
+    let (list, idx) = SinglyList::from_iter_with_indices(0..n);
+    assert!(list.eq_to_iter_vals(0..n));
+    assert_eq!(idx, list.indices().collect::<Vec<_>>());
+}
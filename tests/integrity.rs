@@ -0,0 +1,124 @@
+mod doubly;
+mod singly;
+
+use orx_linked_list::*;
+
+#[test]
+fn integrity_random_lists() {
+    for seed in 0..10 {
+        let mut r = doubly::rng_with_seed(seed);
+
+        let list = doubly::new_doubly(&mut r, 30, 60);
+        assert_eq!(list.check_integrity(), Ok(()));
+        let idx: Vec<_> = list.indices().collect();
+        if idx.len() > 3 {
+            let slice = list.slice(idx[1]..idx[idx.len() - 1]);
+            assert_eq!(slice.check_integrity(), Ok(()));
+        }
+
+        let list = singly::new_singly_lazy(&mut r, 30, 60);
+        assert_eq!(list.check_integrity(), Ok(()));
+        let idx: Vec<_> = list.indices().collect();
+        if idx.len() > 3 {
+            let slice = list.slice(idx[1]..idx[idx.len() - 1]);
+            assert_eq!(slice.check_integrity(), Ok(()));
+        }
+    }
+}
+
+#[test]
+fn integrity_empty() {
+    let list = DoublyList::<String>::new();
+    assert_eq!(list.check_integrity(), Ok(()));
+
+    let list = SinglyList::<String>::new();
+    assert_eq!(list.check_integrity(), Ok(()));
+}
+
+#[test]
+fn integrity_broken_link() {
+    let mut list = DoublyListLazy::new();
+    let idx: Vec<_> = (0..5).map(|x| list.push_back(x)).collect();
+
+    unsafe { list.remove_link(idx[1], idx[2]) };
+    assert_eq!(
+        list.check_integrity(),
+        Err(IntegrityError::EndsMismatch {
+            front: Some(0),
+            back: Some(4)
+        })
+    );
+
+    unsafe { list.add_link(idx[1], idx[3]) };
+    assert_eq!(
+        list.check_integrity(),
+        Err(IntegrityError::LengthMismatch {
+            expected: 5,
+            found: 4
+        })
+    );
+
+    unsafe { list.add_link(idx[2], idx[3]) };
+    // idx[1] links to idx[3]; however, idx[3] links back to idx[2]
+    assert_eq!(
+        list.check_integrity(),
+        Err(IntegrityError::BrokenLink {
+            from: 1,
+            to: Some(3)
+        })
+    );
+}
+
+#[test]
+fn integrity_ends_mismatch() {
+    let mut list = DoublyListLazy::new();
+    let idx: Vec<_> = (0..5).map(|x| list.push_back(x)).collect();
+
+    unsafe { list.set_front(idx[1]) };
+    assert_eq!(
+        list.check_integrity(),
+        Err(IntegrityError::EndsMismatch {
+            front: Some(1),
+            back: Some(4)
+        })
+    );
+
+    unsafe { list.set_front(idx[0]) };
+    unsafe { list.set_back(idx[3]) };
+    assert_eq!(
+        list.check_integrity(),
+        Err(IntegrityError::EndsMismatch {
+            front: Some(0),
+            back: Some(3)
+        })
+    );
+
+    unsafe { list.set_back(idx[4]) };
+    assert_eq!(list.check_integrity(), Ok(()));
+}
+
+#[test]
+fn integrity_cycle() {
+    let mut list = DoublyListLazy::new();
+    let idx: Vec<_> = (0..5).map(|x| list.push_back(x)).collect();
+
+    unsafe {
+        list.remove_link(idx[3], idx[4]);
+        list.remove_link(idx[0], idx[1]);
+        list.add_link(idx[3], idx[1]);
+    }
+
+    assert_eq!(
+        list.check_integrity(),
+        Err(IntegrityError::EndsMismatch {
+            front: Some(0),
+            back: Some(4)
+        })
+    );
+
+    unsafe { list.add_link(idx[0], idx[1]) };
+    assert_eq!(
+        list.check_integrity(),
+        Err(IntegrityError::Cycle { position: 1 })
+    );
+}
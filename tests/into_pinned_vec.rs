@@ -0,0 +1,147 @@
+mod doubly;
+mod singly;
+
+use orx_fixed_vec::FixedVec;
+use orx_linked_list::*;
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::{MemoryPolicy, Node};
+use orx_split_vec::{Doubling, Linear, Recursive, SplitVec};
+
+fn assert_singly_into<M, P, P2>(list: List<Singly<String>, M, P>)
+where
+    M: MemoryPolicy<Singly<String>>,
+    P: PinnedVec<Node<Singly<String>>>,
+    P2: PinnedVec<Node<Singly<String>>> + From<Vec<Node<Singly<String>>>>,
+{
+    let values: Vec<_> = list.iter().cloned().collect();
+    let indices: Vec<_> = list.indices().collect();
+
+    let (list, idx_map) = list.into_pinned_vec::<P2>();
+
+    #[cfg(feature = "validation")]
+    list.validate();
+
+    assert!(list.eq_to_iter_refs(&values));
+    assert_eq!(idx_map.len(), values.len());
+    for (idx, value) in indices.iter().zip(&values) {
+        let idx = idx_map.get(*idx).expect("must be translated");
+        assert_eq!(list.get(idx), Some(value));
+    }
+}
+
+fn assert_doubly_into<M, P, P2>(list: List<Doubly<String>, M, P>)
+where
+    M: MemoryPolicy<Doubly<String>>,
+    P: PinnedVec<Node<Doubly<String>>>,
+    P2: PinnedVec<Node<Doubly<String>>> + From<Vec<Node<Doubly<String>>>>,
+{
+    let values: Vec<_> = list.iter().cloned().collect();
+    let indices: Vec<_> = list.indices().collect();
+
+    let (list, idx_map) = list.into_pinned_vec::<P2>();
+
+    #[cfg(feature = "validation")]
+    list.validate();
+
+    assert!(list.eq_to_iter_refs(&values));
+    assert_eq!(idx_map.len(), values.len());
+    for (i, idx) in indices.iter().enumerate() {
+        let idx = idx_map.get(*idx).expect("must be translated");
+        assert_eq!(list.get(idx), Some(&values[i]));
+        let prev = i.checked_sub(1).map(|p| &values[p]);
+        assert_eq!(list.prev_of(idx), prev);
+    }
+}
+
+#[test]
+fn singly_into_pinned_vec() {
+    for seed in 0..5 {
+        let mut r = doubly::rng_with_seed(seed);
+
+        let list = singly::new_singly(&mut r, 40, 100);
+        assert_singly_into::<_, _, FixedVec<_>>(list);
+
+        let list = singly::new_singly_lazy(&mut r, 40, 100);
+        assert_singly_into::<_, _, SplitVec<_, Doubling>>(list);
+
+        let list = singly::new_singly(&mut r, 40, 100);
+        assert_singly_into::<_, _, SplitVec<_, Recursive>>(list);
+
+        let list = singly::new_singly_lazy(&mut r, 40, 100);
+        assert_singly_into::<_, _, SplitVec<_, Linear>>(list);
+    }
+}
+
+#[test]
+fn doubly_into_pinned_vec() {
+    for seed in 0..5 {
+        let mut r = doubly::rng_with_seed(seed);
+
+        let list = doubly::new_doubly(&mut r, 40, 100);
+        assert_doubly_into::<_, _, FixedVec<_>>(list);
+
+        let list = doubly::new_doubly_lazy(&mut r, 40, 100);
+        assert_doubly_into::<_, _, SplitVec<_, Doubling>>(list);
+
+        let list = doubly::new_doubly(&mut r, 40, 100);
+        assert_doubly_into::<_, _, SplitVec<_, Recursive>>(list);
+
+        let list = doubly::new_doubly_lazy(&mut r, 40, 100);
+        assert_doubly_into::<_, _, SplitVec<_, Linear>>(list);
+    }
+}
+
+#[test]
+fn fixed_to_growable_and_back() {
+    let mut list = DoublyList::with_fixed_capacity(4);
+    let idx: Vec<_> = (0..4).map(|x| list.push_back(x)).collect();
+
+    let (mut list, idx_map) = list.into_pinned_vec::<SplitVec<_, Linear>>();
+    let idx: Vec<_> = idx
+        .iter()
+        .map(|i| idx_map.get(*i).expect("exists"))
+        .collect();
+    for x in 4..100 {
+        list.push_back(x);
+    }
+    assert!(list.eq_to_iter_vals(0..100));
+    assert_eq!(list.get(idx[3]), Some(&3));
+    assert_eq!(list.next_of(idx[3]), Some(&4));
+
+    let (mut list, idx_map) = list.into_pinned_vec::<FixedVec<_>>();
+    let idx: Vec<_> = idx
+        .iter()
+        .map(|i| idx_map.get(*i).expect("exists"))
+        .collect();
+    assert!(list.eq_to_iter_vals(0..100));
+    assert_eq!(list.pop_front(), Some(0));
+    assert_eq!(list.get(idx[2]), Some(&2));
+    assert_eq!(list.len(), 99);
+
+    #[cfg(feature = "validation")]
+    list.validate();
+}
+
+#[test]
+fn collect_into_non_default_pinned_vec() {
+    let (list, _) = (0..10)
+        .collect::<SinglyListLazy<_>>()
+        .into_pinned_vec::<FixedVec<_>>();
+    assert!(list.eq_to_iter_vals(0..10));
+
+    let (list, _) = (0..10)
+        .collect::<SinglyList<_>>()
+        .into_pinned_vec::<SplitVec<_, Linear>>();
+    assert!(list.eq_to_iter_vals(0..10));
+
+    let (list, _) = (0..10)
+        .collect::<DoublyList<_>>()
+        .into_pinned_vec::<FixedVec<_>>();
+    assert!(list.eq_to_iter_vals(0..10));
+
+    let (list, _) = (0..10)
+        .collect::<DoublyListLazy<_>>()
+        .into_pinned_vec::<SplitVec<_, Doubling>>();
+    assert!(list.iter().rev().copied().eq((0..10).rev()));
+    assert!(list.clone().iter().rev().copied().eq((0..10).rev()));
+}
@@ -0,0 +1,134 @@
+mod doubly;
+mod singly;
+
+use orx_linked_list::*;
+
+fn assert_singly_to_doubly<M>(
+    singly: List<Singly<String>, M>,
+    convert: impl FnOnce(
+        List<Singly<String>, M>,
+    ) -> (DoublyList<String>, IdxMap<Singly<String>, Doubly<String>>),
+) where
+    M: MemoryPolicy<Singly<String>>,
+{
+    let values: Vec<_> = singly.iter().cloned().collect();
+    let indices: Vec<_> = singly.indices().collect();
+
+    let (doubly, idx_map) = convert(singly);
+
+    #[cfg(feature = "validation")]
+    doubly.validate();
+
+    assert!(doubly.eq_to_iter_refs(&values));
+    assert_eq!(idx_map.len(), values.len());
+    for (idx, value) in indices.iter().zip(&values) {
+        let idx = idx_map.get(*idx).expect("must be translated");
+        assert_eq!(doubly.get(idx), Some(value));
+    }
+}
+
+fn assert_doubly_to_singly<M>(
+    doubly: List<Doubly<String>, M>,
+    convert: impl FnOnce(
+        List<Doubly<String>, M>,
+    ) -> (
+        SinglyListLazy<String>,
+        IdxMap<Doubly<String>, Singly<String>>,
+    ),
+) where
+    M: MemoryPolicy<Doubly<String>>,
+{
+    let values: Vec<_> = doubly.iter().cloned().collect();
+    let indices: Vec<_> = doubly.indices().collect();
+
+    let (singly, idx_map) = convert(doubly);
+
+    #[cfg(feature = "validation")]
+    singly.validate();
+
+    assert!(singly.eq_to_iter_refs(&values));
+    assert_eq!(idx_map.len(), values.len());
+    for (idx, value) in indices.iter().zip(&values) {
+        let idx = idx_map.get(*idx).expect("must be translated");
+        assert_eq!(singly.get(idx), Some(value));
+    }
+}
+
+#[test]
+fn singly_into_doubly() {
+    for seed in 0..10 {
+        let mut r = doubly::rng_with_seed(seed);
+        let list = singly::new_singly(&mut r, 40, 100);
+        assert_singly_to_doubly(list, |x| x.into_doubly());
+
+        let list = singly::new_singly_lazy(&mut r, 40, 100);
+        assert_singly_to_doubly(list, |x| x.into_doubly());
+    }
+}
+
+#[test]
+fn doubly_into_singly() {
+    for seed in 0..10 {
+        let mut r = doubly::rng_with_seed(seed);
+        let list = doubly::new_doubly(&mut r, 40, 100);
+        assert_doubly_to_singly(list, |x| x.into_singly());
+
+        let list = doubly::new_doubly_lazy(&mut r, 40, 100);
+        assert_doubly_to_singly(list, |x| x.into_singly());
+    }
+}
+
+#[test]
+fn idx_map_rejects_invalid_indices() {
+    let mut list: DoublyListLazy<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    list.remove(idx[3]);
+    list.remove(idx[7]);
+
+    let other: DoublyList<_> = (0..10).collect();
+    let other_idx: Vec<_> = other.indices().collect();
+
+    let (singly, idx_map): (SinglyList<_>, _) = list.into_singly();
+    assert_eq!(idx_map.len(), 8);
+    assert!(singly.eq_to_iter_vals([0, 1, 2, 4, 5, 6, 8, 9]));
+
+    assert_eq!(idx_map.get(idx[3]), None);
+    assert_eq!(idx_map.get(idx[7]), None);
+    assert_eq!(idx_map.get(idx[4]).and_then(|i| singly.get(i)), Some(&4));
+    assert!(other_idx.iter().all(|i| idx_map.get(*i).is_none()));
+
+    let mut list: DoublyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    for _ in 0..8 {
+        list.pop_front();
+    }
+    let (_, idx_map): (SinglyList<_>, _) = list.into_singly();
+    assert!(idx.iter().all(|i| idx_map.get(*i).is_none()));
+}
+
+#[test]
+fn into_variant_empty() {
+    let (doubly, idx_map): (DoublyList<char>, _) = SinglyList::new().into_doubly();
+    assert!(doubly.is_empty());
+    assert!(idx_map.is_empty());
+
+    let (singly, idx_map): (SinglyList<char>, _) = DoublyList::new().into_singly();
+    assert!(singly.is_empty());
+    assert!(idx_map.is_empty());
+}
+
+#[test]
+fn idx_map_over_fragmented_storage() {
+    let mut list = DoublyList::new();
+    let idx: Vec<_> = (0..100).map(|i| list.push_back(i)).collect();
+    list.remove(idx[42]);
+
+    let (singly, idx_map): (SinglyList<_>, _) = list.into_singly();
+    assert_eq!(idx_map.len(), 99);
+    for (i, idx) in idx.iter().enumerate() {
+        match i {
+            42 => assert_eq!(idx_map.get(*idx), None),
+            _ => assert_eq!(idx_map.get(*idx).and_then(|x| singly.get(x)), Some(&i)),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use orx_iterable::{Collection, CollectionMut, Iterable};
+use orx_linked_list::*;
+
+fn sum<'a>(numbers: impl Iterable<Item = &'a i32>) -> i32 {
+    numbers.iter().sum()
+}
+
+fn collect<X: Collection<Item = i32>>(numbers: &X) -> Vec<i32> {
+    Collection::iter(numbers).copied().collect()
+}
+
+fn double_all<X: CollectionMut<Item = i32>>(numbers: &mut X) {
+    for x in CollectionMut::iter_mut(numbers) {
+        *x *= 2;
+    }
+}
+
+fn new_list<L: FromIterator<i32>>() -> L {
+    [3, 1, 4, 1, 5].into_iter().collect()
+}
+
+#[test]
+fn list_collection() {
+    let mut list: DoublyList<i32> = new_list();
+    list.push_front(0);
+    assert_eq!(collect(&list), [0, 3, 1, 4, 1, 5]);
+    assert_eq!(sum(&list), 14);
+
+    double_all(&mut list);
+    assert_eq!(collect(&list), [0, 6, 2, 8, 2, 10]);
+
+    let mut list: SinglyList<i32> = new_list();
+    assert_eq!(collect(&list), [3, 1, 4, 1, 5]);
+    double_all(&mut list);
+    assert_eq!(sum(&list), 28);
+
+    let mut list: DoublyListLazy<i32> = new_list();
+    list.pop_front();
+    assert_eq!(collect(&list), [1, 4, 1, 5]);
+}
+
+#[test]
+fn into_iter_references() {
+    let mut list: DoublyList<i32> = new_list();
+
+    let mut values = vec![];
+    for x in &list {
+        values.push(*x);
+    }
+    assert_eq!(values, [3, 1, 4, 1, 5]);
+
+    for x in &mut list {
+        *x += 1;
+    }
+    assert_eq!(list, [4, 2, 5, 2, 6]);
+}
+
+#[test]
+fn slice_collection() {
+    let mut list: DoublyList<i32> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let slice = list.slice(idx[2]..idx[5]);
+    assert_eq!(collect(&slice), [2, 3, 4]);
+    assert_eq!(sum(&slice), 9);
+
+    let mut slice = list.slice_mut(idx[2]..idx[5]);
+    assert_eq!(collect(&slice), [2, 3, 4]);
+    double_all(&mut slice);
+    assert_eq!(sum(&slice), 18);
+    assert_eq!(list, [0, 1, 4, 6, 8, 5, 6, 7, 8, 9]);
+
+    let mut list: SinglyList<i32> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    let mut slice = list.slice_mut(idx[7]..);
+    double_all(&mut slice);
+    assert_eq!(collect(&slice), [14, 16, 18]);
+}
+
+#[test]
+fn iterable_x() {
+    let mut list = DoublyListLazy::new();
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+    list.push_front(0);
+    list.pop_back();
+
+    let iterable = list.iterable_x();
+    assert_eq!(sum(iterable), 3);
+
+    let mut values: Vec<_> = iterable.iter().copied().collect();
+    assert!(values.iter().ne([0, 1, 2].iter()));
+    values.sort();
+    assert_eq!(values, [0, 1, 2]);
+
+    assert!(iterable.iter().eq(list.iter_x()));
+}
@@ -0,0 +1,231 @@
+use orx_linked_list::*;
+
+#[test]
+fn keyed_list_insert_and_get() {
+    let mut list = KeyedDoublyList::new();
+    assert!(list.is_empty());
+
+    let b = list.push_back('b', 2).unwrap();
+    list.push_front('a', 1).unwrap();
+    list.insert_next_to_key(&'b', 'd', 4).unwrap();
+    list.insert_prev_to_key(&'d', 'c', 3).unwrap();
+
+    assert_eq!(list.len(), 4);
+    assert!(list.eq_to_iter_vals([1, 2, 3, 4]));
+    assert!(list.iter().rev().eq(&[4, 3, 2, 1]));
+    assert_eq!(list.front(), Some(&1));
+    assert_eq!(list.back(), Some(&4));
+
+    assert_eq!(list.idx_of_key(&'b'), Some(b));
+    assert_eq!(list.get(b), Some(&2));
+    assert_eq!(list.get_by_key(&'c'), Some(&3));
+    assert_eq!(list.get_by_key(&'x'), None);
+    assert!(list.contains_key(&'a'));
+    assert!(!list.contains_key(&'x'));
+
+    *list.get_mut_by_key(&'c').unwrap() = 30;
+    assert!(list.eq_to_iter_vals([1, 2, 30, 4]));
+}
+
+#[test]
+fn keyed_list_errors_leave_list_unchanged() {
+    let mut list = KeyedDoublyList::new();
+    list.push_back(1, "a").unwrap();
+    list.push_back(2, "b").unwrap();
+
+    assert_eq!(list.push_back(1, "x"), Err(KeyedListError::DuplicateKey));
+    assert_eq!(list.push_front(2, "x"), Err(KeyedListError::DuplicateKey));
+    assert_eq!(
+        list.insert_next_to_key(&1, 2, "x"),
+        Err(KeyedListError::DuplicateKey)
+    );
+    assert_eq!(
+        list.insert_prev_to_key(&7, 3, "x"),
+        Err(KeyedListError::KeyNotFound)
+    );
+    assert_eq!(list.move_key_to_front(&7), Err(KeyedListError::KeyNotFound));
+    assert_eq!(list.move_key_to_back(&7), Err(KeyedListError::KeyNotFound));
+    assert_eq!(list.remove_by_key(&7), None);
+
+    assert_eq!(list.len(), 2);
+    assert!(list.eq_to_iter_vals(["a", "b"]));
+}
+
+#[test]
+fn keyed_list_move_and_remove() {
+    let mut list = KeyedDoublyList::new();
+    for i in 0..5 {
+        list.push_back(i, i * 10).unwrap();
+    }
+
+    list.move_key_to_front(&3).unwrap();
+    assert!(list.eq_to_iter_vals([30, 0, 10, 20, 40]));
+
+    list.move_key_to_back(&0).unwrap();
+    assert!(list.eq_to_iter_vals([30, 10, 20, 40, 0]));
+
+    let idx = list.idx_of_key(&2).unwrap();
+    list.move_to_front(idx);
+    assert!(list.eq_to_iter_vals([20, 30, 10, 40, 0]));
+
+    assert_eq!(list.remove_by_key(&1), Some(10));
+    assert_eq!(list.remove_by_key(&1), None);
+    assert!(!list.contains_key(&1));
+    assert!(list.eq_to_iter_vals([20, 30, 40, 0]));
+
+    list.clear();
+    assert!(list.is_empty());
+    assert!(!list.contains_key(&0));
+    list.push_back(0, 1).unwrap();
+    assert!(list.eq_to_iter_vals([1]));
+}
+
+#[test]
+fn keyed_list_keys_remain_consistent_after_reclaim() {
+    let mut list = KeyedDoublyList::new();
+    let n = 200;
+    for i in 0..n {
+        match i % 2 {
+            0 => list.push_back(i, i.to_string()).unwrap(),
+            _ => list.push_front(i, i.to_string()).unwrap(),
+        };
+    }
+
+    let initial_state = list.memory_state();
+    for i in (0..n).filter(|i| i % 3 != 0) {
+        assert_eq!(list.remove_by_key(&i), Some(i.to_string()));
+    }
+    assert_ne!(list.memory_state(), initial_state);
+
+    let mut expected: Vec<_> = list.iter().cloned().collect();
+    for i in 0..n {
+        match i % 3 {
+            0 => {
+                let idx = list.idx_of_key(&i).unwrap();
+                assert_eq!(list.get(idx), Some(&i.to_string()));
+                assert_eq!(list.get_by_key(&i), Some(&i.to_string()));
+            }
+            _ => assert_eq!(list.get_by_key(&i), None),
+        }
+    }
+
+    list.insert_next_to_key(&0, n, n.to_string()).unwrap();
+    let pos = expected.iter().position(|x| x == "0").unwrap();
+    expected.insert(pos + 1, n.to_string());
+    assert!(list.iter().eq(expected.iter()));
+
+    list.remove_by_key(&3).unwrap();
+    let state = list.memory_state();
+    list.reclaim_closed_nodes();
+    assert_ne!(list.memory_state(), state);
+    assert_eq!(list.node_utilization().num_closed_nodes, 0);
+    for i in (0..n).filter(|i| i % 3 == 0 && *i != 3) {
+        assert_eq!(list.get_by_key(&i), Some(&i.to_string()));
+    }
+}
+
+#[test]
+fn keyed_list_remove_by_key_invalidates_indices_on_reclaim() {
+    let mut list = KeyedDoublyList::new();
+    let n = 100;
+    let indices: Vec<_> = (0..n).map(|i| list.push_back(i, i).unwrap()).collect();
+
+    let state = list.memory_state();
+    let held = indices[n - 1];
+
+    // closed nodes are not yet the majority
+    for i in 0..(n / 2) {
+        list.remove_by_key(&i).unwrap();
+        assert_eq!(list.memory_state(), state);
+        assert_eq!(list.get(held), Some(&(n - 1)));
+    }
+
+    // this removal makes the closed nodes outnumber the elements
+    list.remove_by_key(&(n / 2)).unwrap();
+    assert_ne!(list.memory_state(), state);
+    assert_eq!(list.node_utilization().num_closed_nodes, 0);
+    assert_eq!(list.get(held), None);
+    assert!(list.idx_err(held).is_some());
+
+    let idx = list.idx_of_key(&(n - 1)).unwrap();
+    assert_eq!(list.get(idx), Some(&(n - 1)));
+    assert!(list.eq_to_iter_vals((n / 2 + 1)..n));
+}
+
+#[test]
+fn keyed_list_lookup_by_borrowed_key() {
+    let mut list: KeyedDoublyList<String, usize> = KeyedDoublyList::new();
+    for (i, key) in ["a", "b", "c", "d"].into_iter().enumerate() {
+        list.push_back(key.to_string(), i).unwrap();
+    }
+
+    assert!(list.contains_key("a"));
+    assert!(!list.contains_key("x"));
+    assert_eq!(list.get_by_key("b"), Some(&1));
+    *list.get_mut_by_key("b").unwrap() = 10;
+    assert_eq!(
+        list.idx_of_key("b").and_then(|idx| list.get(idx)),
+        Some(&10)
+    );
+
+    list.insert_next_to_key("a", "ab".to_string(), 5).unwrap();
+    list.insert_prev_to_key("d", "cd".to_string(), 6).unwrap();
+    list.move_key_to_front("d").unwrap();
+    list.move_key_to_back("a").unwrap();
+    assert_eq!(list.remove_by_key("c"), Some(2));
+    assert_eq!(list.remove_by_key("c"), None);
+
+    assert!(list.eq_to_iter_vals([3, 5, 10, 6, 0]));
+    assert_eq!(
+        list.move_key_to_front("x"),
+        Err(KeyedListError::KeyNotFound)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn keyed_list_with_hash_map() {
+    use std::collections::HashMap;
+
+    let mut list = KeyedDoublyList::with_key_index(HashMap::new());
+    for i in 0..100 {
+        list.push_back(format!("k{i}"), i).unwrap();
+    }
+    for i in 0..90 {
+        assert_eq!(list.remove_by_key(&format!("k{i}")), Some(i));
+    }
+    list.move_key_to_front(&"k95".to_string()).unwrap();
+    assert!(list.eq_to_iter_vals([95, 90, 91, 92, 93, 94, 96, 97, 98, 99]));
+    for i in 90..100 {
+        assert_eq!(list.get_by_key(&format!("k{i}")), Some(&i));
+    }
+
+    assert!(list.contains_key("k99"));
+    assert_eq!(list.get_by_key("k91"), Some(&91));
+    assert_eq!(list.remove_by_key("k91"), Some(91));
+    assert!(!list.contains_key("k91"));
+}
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn keyed_list_with_hashbrown_map() {
+    use hashbrown::HashMap;
+
+    let mut list = KeyedDoublyList::with_key_index(HashMap::new());
+    for i in 0..100 {
+        list.push_back(format!("k{i}"), i).unwrap();
+    }
+    for i in 0..90 {
+        assert_eq!(list.remove_by_key(&format!("k{i}")), Some(i));
+    }
+    list.move_key_to_front(&"k95".to_string()).unwrap();
+    assert!(list.eq_to_iter_vals([95, 90, 91, 92, 93, 94, 96, 97, 98, 99]));
+    for i in 90..100 {
+        assert_eq!(list.get_by_key(&format!("k{i}")), Some(&i));
+    }
+
+    assert!(list.contains_key("k99"));
+    assert_eq!(list.get_by_key("k91"), Some(&91));
+    assert_eq!(list.remove_by_key("k91"), Some(91));
+    assert!(!list.contains_key("k91"));
+}
@@ -0,0 +1,303 @@
+use orx_linked_list::arena::{ArenaIdx, ArenaList, ListArena};
+use std::collections::VecDeque;
+
+fn assert_list<T: PartialEq + core::fmt::Debug + Clone>(
+    arena: &ListArena<T>,
+    list: ArenaList,
+    expected: &[T],
+) {
+    assert_eq!(arena.list_len(list), expected.len());
+    assert_eq!(arena.is_list_empty(list), expected.is_empty());
+    assert!(arena.iter(list).eq(expected.iter()));
+    assert!(arena.iter(list).rev().eq(expected.iter().rev()));
+    assert_eq!(arena.front(list), expected.first());
+    assert_eq!(arena.back(list), expected.last());
+    for idx in arena.indices(list) {
+        assert_eq!(arena.list_of(idx), Some(list));
+    }
+}
+
+#[test]
+fn arena_push_pop() {
+    let mut arena = ListArena::new();
+    assert!(arena.is_empty());
+    let a = arena.new_list();
+    let b = arena.new_list();
+    assert_eq!(arena.num_lists(), 2);
+    assert!(arena.lists().eq([a, b]));
+
+    arena.push_back(a, 1);
+    arena.push_front(a, 0);
+    arena.push_back(b, 10);
+    arena.push_back(a, 2);
+    assert_list(&arena, a, &[0, 1, 2]);
+    assert_list(&arena, b, &[10]);
+    assert_eq!(arena.len(), 4);
+
+    assert_eq!(arena.pop_front(a), Some(0));
+    assert_eq!(arena.pop_back(a), Some(2));
+    assert_eq!(arena.pop_back(b), Some(10));
+    assert_eq!(arena.pop_back(b), None);
+    assert_list(&arena, a, &[1]);
+    assert_list(&arena, b, &[]);
+
+    for x in arena.iter_mut(a) {
+        *x *= 100;
+    }
+    assert_list(&arena, a, &[100]);
+
+    arena.clear();
+    assert!(arena.is_empty());
+    assert_eq!(arena.num_lists(), 2);
+    assert_list(&arena, a, &[]);
+    assert_list(&arena, b, &[]);
+}
+
+#[test]
+fn arena_clear_keeps_list_handles() {
+    let mut arena = ListArena::new();
+    let a = arena.new_list();
+    let b = arena.new_list();
+    arena.push_back(a, 'a');
+    arena.push_back(b, 'b');
+
+    arena.clear();
+    assert!(arena.lists().eq([a, b]));
+
+    // a new list does not alias the handles created before clear
+    let c = arena.new_list();
+    assert_ne!(c, a);
+    assert_ne!(c, b);
+    arena.push_back(c, 'c');
+    arena.push_back(a, 'x');
+    assert_list(&arena, a, &['x']);
+    assert_list(&arena, b, &[]);
+    assert_list(&arena, c, &['c']);
+
+    arena.move_to_front(arena.idx_of_front(c).unwrap(), b);
+    assert_list(&arena, b, &['c']);
+    assert_list(&arena, c, &[]);
+}
+
+#[test]
+fn arena_transfer_keeps_index() {
+    let mut arena = ListArena::new();
+    let a = arena.new_list();
+    let b = arena.new_list();
+
+    let idx: Vec<_> = (0..5).map(|i| arena.push_back(a, i)).collect();
+
+    arena.move_to_back(idx[0], b);
+    arena.move_to_front(idx[4], b);
+    arena.move_next_to(idx[2], idx[4]);
+    arena.move_prev_to(idx[3], idx[0]);
+    assert_list(&arena, a, &[1]);
+    assert_list(&arena, b, &[4, 2, 3, 0]);
+
+    for (i, idx) in idx.iter().enumerate() {
+        assert_eq!(arena.get(*idx), Some(&i));
+    }
+    assert_eq!(arena.list_of(idx[1]), Some(a));
+    assert_eq!(arena.list_of(idx[3]), Some(b));
+    assert_eq!(arena.idx_of_front(b), Some(idx[4]));
+    assert_eq!(arena.idx_of_back(b), Some(idx[0]));
+
+    arena.move_next_to(idx[1], idx[1]);
+    arena.move_to_back(idx[1], a);
+    assert_list(&arena, a, &[1]);
+
+    arena.move_next_to(idx[1], idx[0]);
+    assert_list(&arena, a, &[]);
+    assert_list(&arena, b, &[4, 2, 3, 0, 1]);
+
+    *arena.get_mut(idx[2]).unwrap() = 20;
+    assert_eq!(arena.remove(idx[2]), 20);
+    assert_eq!(arena.get(idx[2]), None);
+    assert!(arena.try_remove(idx[2]).is_err());
+    assert_list(&arena, b, &[4, 3, 0, 1]);
+
+    arena.append_back(a, b);
+    assert_list(&arena, a, &[4, 3, 0, 1]);
+    assert_list(&arena, b, &[]);
+    arena.push_back(b, 7);
+    arena.append_back(a, b);
+    assert_list(&arena, a, &[4, 3, 0, 1, 7]);
+    assert_eq!(arena.list_of(idx[0]), Some(a));
+
+    arena.clear_list(a);
+    assert!(arena.is_empty());
+    assert_list(&arena, a, &[]);
+}
+
+#[test]
+fn arena_reclaim_closed_nodes() {
+    let mut arena = ListArena::new();
+    let lists: Vec<_> = (0..4).map(|_| arena.new_list()).collect();
+    for i in 0..100 {
+        arena.push_back(lists[i % 4], i);
+    }
+    for i in 0..100 {
+        if i % 3 == 0 {
+            let idx = arena.idx_of_front(lists[i % 4]).unwrap();
+            arena.remove(idx);
+        }
+    }
+    let removed = arena.pop_back(lists[1]).unwrap();
+    let stale = arena.idx_of_front(lists[2]).unwrap();
+
+    let expected: Vec<Vec<_>> = lists
+        .iter()
+        .map(|l| arena.iter(*l).copied().collect())
+        .collect();
+    assert!(arena.node_utilization().num_closed_nodes > 0);
+
+    let (old, new) = arena.reclaim_closed_nodes();
+    assert_ne!(old, new);
+    assert_eq!(arena.memory_state(), new);
+    assert_eq!(arena.node_utilization().num_closed_nodes, 0);
+    assert_eq!(arena.get(stale), None);
+    assert!(!arena.iter(lists[1]).any(|x| *x == removed));
+
+    for (l, expected) in lists.iter().zip(&expected) {
+        assert_list(&arena, *l, expected);
+    }
+
+    let idx = arena.idx_of_back(lists[0]).unwrap();
+    arena.move_to_front(idx, lists[3]);
+    assert_eq!(arena.front(lists[3]), expected[0].last());
+}
+
+#[test]
+fn arena_push_to_foreign_list_leaves_arena_unchanged() {
+    let mut other = ListArena::<i32>::new();
+    _ = other.new_list();
+    let foreign = other.new_list();
+
+    let mut arena = ListArena::new();
+    let a = arena.new_list();
+    arena.push_back(a, 0);
+
+    let push_back = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arena.push_back(foreign, 1);
+    }));
+    let push_front = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arena.push_front(foreign, 1);
+    }));
+
+    let idx = arena.idx_of_front(a).unwrap();
+    let move_to_back = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arena.move_to_back(idx, foreign);
+    }));
+    let append_back = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        arena.append_back(foreign, a);
+    }));
+
+    assert!(push_back.is_err());
+    assert!(push_front.is_err());
+    assert!(move_to_back.is_err());
+    assert!(append_back.is_err());
+    assert_eq!(arena.len(), 1);
+    assert_list(&arena, a, &[0]);
+}
+
+#[test]
+#[should_panic(expected = "list does not belong to the arena")]
+fn arena_rejects_list_of_another_arena_with_same_position() {
+    let mut other = ListArena::<i32>::new();
+    let foreign = other.new_list();
+    other.push_back(foreign, 1);
+
+    let mut arena = ListArena::<i32>::new();
+    let a = arena.new_list();
+    arena.push_back(a, 0);
+
+    _ = arena.list_len(foreign);
+}
+
+#[test]
+fn arena_random_against_vec_deques() {
+    let num_lists = 5;
+    let mut arena = ListArena::new();
+    let lists: Vec<_> = (0..num_lists).map(|_| arena.new_list()).collect();
+    let mut model: Vec<VecDeque<(ArenaIdx<u64>, u64)>> = vec![VecDeque::new(); num_lists];
+
+    let mut seed = 42u64;
+    let mut rand = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        seed >> 33
+    };
+
+    for step in 0..3000u64 {
+        let l = rand() as usize % num_lists;
+        let total: usize = model.iter().map(|m| m.len()).sum();
+        let pick = |model: &Vec<VecDeque<_>>, r: usize| {
+            let mut r = r % total;
+            for (l, m) in model.iter().enumerate() {
+                match r < m.len() {
+                    true => return (l, r),
+                    false => r -= m.len(),
+                }
+            }
+            unreachable!()
+        };
+
+        match (rand() % 7, total) {
+            (0, _) | (_, 0) => {
+                let idx = arena.push_back(lists[l], step);
+                model[l].push_back((idx, step));
+            }
+            (1, _) => {
+                let idx = arena.push_front(lists[l], step);
+                model[l].push_front((idx, step));
+            }
+            (2, _) => {
+                let (m, p) = pick(&model, rand() as usize);
+                let (idx, value) = model[m].remove(p).unwrap();
+                assert_eq!(arena.remove(idx), value);
+            }
+            (3, _) => {
+                let (m, p) = pick(&model, rand() as usize);
+                let entry = model[m].remove(p).unwrap();
+                arena.move_to_front(entry.0, lists[l]);
+                model[l].push_front(entry);
+            }
+            (4, _) => {
+                let (m, p) = pick(&model, rand() as usize);
+                let entry = model[m].remove(p).unwrap();
+                arena.move_to_back(entry.0, lists[l]);
+                model[l].push_back(entry);
+            }
+            (5, _) => {
+                let (m, p) = pick(&model, rand() as usize);
+                let (a, q) = pick(&model, rand() as usize);
+                if (m, p) != (a, q) {
+                    let anchor = model[a][q].0;
+                    let entry = model[m].remove(p).unwrap();
+                    let q = model[a].iter().position(|x| x.0 == anchor).unwrap();
+                    arena.move_next_to(entry.0, anchor);
+                    model[a].insert(q + 1, entry);
+                }
+            }
+            _ => {
+                let (old, new) = arena.reclaim_closed_nodes();
+                if old != new {
+                    for (l, m) in lists.iter().zip(model.iter_mut()) {
+                        for (entry, idx) in m.iter_mut().zip(arena.indices(*l)) {
+                            entry.0 = idx;
+                        }
+                    }
+                }
+            }
+        }
+
+        for (l, m) in lists.iter().zip(&model) {
+            let values: Vec<_> = m.iter().map(|x| x.1).collect();
+            assert_list(&arena, *l, &values);
+            for (idx, value) in m {
+                assert_eq!(arena.get(*idx), Some(value));
+            }
+        }
+    }
+}
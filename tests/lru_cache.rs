@@ -0,0 +1,115 @@
+#![cfg(any(feature = "std", feature = "hashbrown"))]
+
+use orx_linked_list::cache::LruCache;
+use std::{collections::hash_map::DefaultHasher, hash::BuildHasherDefault};
+
+#[test]
+fn lru_cache_get_updates_recency() {
+    let mut cache = LruCache::new(3);
+    cache.put(1, 'a');
+    cache.put(2, 'b');
+    cache.put(3, 'c');
+    assert!(cache.keys().eq(&[3, 2, 1]));
+
+    assert_eq!(cache.get(&1), Some(&'a'));
+    assert!(cache.keys().eq(&[1, 3, 2]));
+
+    assert_eq!(cache.peek(&2), Some(&'b'));
+    assert!(cache.keys().eq(&[1, 3, 2]));
+    assert_eq!(cache.peek_lru(), Some((&2, &'b')));
+
+    *cache.get_mut(&2).unwrap() = 'x';
+    assert!(cache.iter().eq([(&2, &'x'), (&1, &'a'), (&3, &'c')]));
+    assert!(cache.iter().rev().eq([(&3, &'c'), (&1, &'a'), (&2, &'x')]));
+
+    assert_eq!(cache.get(&4), None);
+}
+
+#[test]
+fn lru_cache_put_evicts_lru() {
+    let mut cache = LruCache::new(2);
+    assert_eq!(cache.put("a", 1), None);
+    assert_eq!(cache.put("b", 2), None);
+    assert_eq!(cache.put("a", 10), Some(1));
+    assert_eq!(cache.len(), 2);
+
+    assert_eq!(cache.put("c", 3), None);
+    assert_eq!(cache.len(), 2);
+    assert!(!cache.contains(&"b"));
+    assert!(cache.iter().eq([(&"c", &3), (&"a", &10)]));
+
+    assert_eq!(cache.put("d", 4), None);
+    assert!(cache.iter().eq([(&"d", &4), (&"c", &3)]));
+}
+
+#[test]
+fn lru_cache_zero_capacity() {
+    let mut cache = LruCache::new(0);
+    assert_eq!(cache.put(1, 1), None);
+    assert!(cache.is_empty());
+    assert_eq!(cache.get(&1), None);
+    assert_eq!(cache.pop_lru(), None);
+}
+
+#[test]
+fn lru_cache_pop_lru_and_remove() {
+    let mut cache = LruCache::new(4);
+    for i in 0..4 {
+        cache.put(i, i * 10);
+    }
+
+    assert_eq!(cache.pop_lru(), Some((0, 0)));
+    assert_eq!(cache.remove(&2), Some(20));
+    assert_eq!(cache.remove(&2), None);
+    assert!(cache.keys().eq(&[3, 1]));
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.peek_lru(), None);
+}
+
+#[test]
+fn lru_cache_resize() {
+    let mut cache = LruCache::new(5);
+    for i in 0..5 {
+        cache.put(i, i);
+    }
+
+    cache.resize(2);
+    assert_eq!(cache.capacity(), 2);
+    assert!(cache.keys().eq(&[4, 3]));
+
+    cache.resize(3);
+    cache.put(5, 5);
+    assert!(cache.keys().eq(&[5, 4, 3]));
+}
+
+#[test]
+fn lru_cache_reclaim_keeps_indices_valid() {
+    let n = 200;
+    let mut cache = LruCache::with_hasher(n, BuildHasherDefault::<DefaultHasher>::default());
+    for i in 0..n {
+        cache.put(i, i.to_string());
+    }
+
+    // remove every other entry, leaving many closed nodes behind
+    for i in (0..n).step_by(2) {
+        assert_eq!(cache.remove(&i), Some(i.to_string()));
+    }
+    for i in (0..n).step_by(4) {
+        cache.put(i, i.to_string());
+    }
+    while cache.len() > n / 4 {
+        cache.pop_lru();
+    }
+
+    let mut expected: Vec<_> = cache.keys().copied().collect();
+    for k in expected.clone().iter() {
+        assert_eq!(cache.get(k), Some(&k.to_string()));
+    }
+    expected.reverse();
+    assert!(cache.keys().eq(expected.iter()));
+    for (k, v) in cache.iter() {
+        assert_eq!(&k.to_string(), v);
+    }
+}
@@ -0,0 +1,85 @@
+use orx_linked_list::*;
+
+#[test]
+fn memory_report_empty() {
+    let list: SinglyList<u32> = SinglyList::new();
+    let report = list.memory_report();
+
+    assert_eq!(report.num_active_nodes, 0);
+    assert_eq!(report.num_closed_nodes, 0);
+    assert_eq!(report.num_fragments(), 0);
+    assert_eq!(report.reclaimable_bytes, 0);
+    assert_eq!(list.fragment_capacities(), vec![report.capacity]);
+}
+
+#[test]
+fn memory_report_split_vec_fragments() {
+    let mut list = DoublyListLazy::with_doubling_growth();
+    list.extend(0..100u32);
+
+    let report = list.memory_report();
+    assert_eq!(report.num_active_nodes, 100);
+    assert!(report.num_fragments() > 1);
+    assert_eq!(report.fragment_lengths.iter().sum::<usize>(), 100);
+    assert_eq!(
+        list.fragment_capacities().iter().sum::<usize>(),
+        report.capacity
+    );
+    assert!(report.capacity >= 100);
+    assert_eq!(
+        report.allocated_bytes,
+        report.capacity * report.node_size_bytes
+    );
+    assert_eq!(report.element_size_bytes, 4);
+    assert_eq!(
+        report.link_overhead_bytes,
+        report.node_size_bytes - report.element_size_bytes
+    );
+}
+
+#[test]
+fn memory_report_reclaimable() {
+    let mut list = DoublyListLazy::new();
+    list.extend(0..50u64);
+    for _ in 0..20 {
+        list.pop_front();
+    }
+
+    let report = list.memory_report();
+    assert_eq!(report.num_active_nodes, 30);
+    assert_eq!(report.num_closed_nodes, 20);
+    assert_eq!(report.reclaimable_bytes, 20 * report.node_size_bytes);
+
+    list.reclaim_closed_nodes();
+
+    let report = list.memory_report();
+    assert_eq!(report.num_closed_nodes, 0);
+    assert_eq!(report.reclaimable_bytes, 0);
+    assert_eq!(report.fragment_lengths.iter().sum::<usize>(), 30);
+    assert_eq!(
+        list.fragment_capacities().iter().sum::<usize>(),
+        report.capacity
+    );
+}
+
+#[test]
+fn memory_report_singly_has_smaller_overhead() {
+    let singly: SinglyList<u64> = (0..10).collect();
+    let doubly: DoublyList<u64> = (0..10).collect();
+
+    let singly = singly.memory_report();
+    let doubly = doubly.memory_report();
+    assert_eq!(singly.element_size_bytes, doubly.element_size_bytes);
+    assert!(singly.link_overhead_bytes < doubly.link_overhead_bytes);
+}
+
+#[test]
+fn memory_report_any_pinned_vec() {
+    let mut list: DoublyList<u32, orx_fixed_vec::FixedVec<_>> = DoublyList::with_fixed_capacity(8);
+    list.extend(0..5);
+
+    let report = list.memory_report();
+    assert_eq!(report.capacity, 8);
+    assert_eq!(report.fragment_lengths, vec![5]);
+    assert_eq!(list.fragment_capacities(), vec![8]);
+}
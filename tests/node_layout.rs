@@ -0,0 +1,103 @@
+mod doubly;
+mod singly;
+
+use orx_linked_list::*;
+
+fn positions_in_link_order(layout: &[NodeLayout]) -> Vec<usize> {
+    let mut positions = vec![];
+    let mut current = layout.iter().find(|x| x.is_front);
+    while let Some(x) = current {
+        positions.push(x.position);
+        current = match x.is_back {
+            true => None,
+            false => x.next.map(|next| &layout[next]),
+        };
+    }
+    positions
+}
+
+#[test]
+fn node_layout_doubly_follows_links() {
+    for seed in 0..5 {
+        let mut r = doubly::rng_with_seed(seed);
+        let list = doubly::new_doubly_lazy(&mut r, 30, 60);
+
+        let layout: Vec<_> = list.node_layout().collect();
+
+        assert_eq!(
+            layout.len(),
+            list.node_utilization().num_active_nodes + list.node_utilization().num_closed_nodes
+        );
+        assert_eq!(layout.iter().filter(|x| x.is_active).count(), list.len());
+        assert!(layout.iter().enumerate().all(|(i, x)| x.position == i));
+
+        let order = positions_in_link_order(&layout);
+        assert_eq!(order.len(), list.len());
+        for w in order.windows(2) {
+            assert_eq!(layout[w[1]].prev, Some(w[0]));
+        }
+    }
+}
+
+#[test]
+fn node_layout_singly_follows_links() {
+    for seed in 0..5 {
+        let mut r = doubly::rng_with_seed(seed);
+        let list = singly::new_singly_lazy(&mut r, 30, 60);
+
+        let layout: Vec<_> = list.node_layout().collect();
+        assert!(layout.iter().all(|x| x.prev.is_none()));
+        assert!(
+            layout
+                .iter()
+                .filter(|x| !x.is_active)
+                .all(|x| x.next.is_none())
+        );
+
+        let order = positions_in_link_order(&layout);
+        assert_eq!(order.len(), list.len());
+    }
+}
+
+#[test]
+fn node_layout_slice_ends() {
+    let list: DoublyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    let slice = list.slice(idx[3]..=idx[6]);
+
+    let layout: Vec<_> = slice.node_layout().collect();
+    assert_eq!(layout.len(), 10);
+    assert_eq!(layout.iter().filter(|x| x.is_front).count(), 1);
+    assert!(layout[3].is_front);
+    assert!(layout[6].is_back);
+    assert_eq!(positions_in_link_order(&layout), [3, 4, 5, 6]);
+}
+
+#[test]
+fn node_layout_after_moves() {
+    let mut list: DoublyList<_> = (0..4).collect();
+    let idx: Vec<_> = list.indices().collect();
+    list.move_to_back(idx[0]);
+    list.swap(idx[1], idx[2]);
+    assert!(list.eq_to_iter_vals([2, 1, 3, 0]));
+
+    let layout: Vec<_> = list.node_layout().collect();
+    assert_eq!(positions_in_link_order(&layout), [2, 1, 3, 0]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_dot_escapes_labels() {
+    let mut list = DoublyListLazy::new();
+    list.push_back(String::from("say \"hi\""));
+    list.push_back(String::from("y"));
+    list.pop_back();
+
+    let dot = list.to_dot();
+    assert!(dot.contains(r#"s0 [label="0: say \"hi\"", style=solid];"#));
+    assert!(dot.contains(r#"s1 [label="1: x", style=dashed];"#));
+    assert!(dot.contains("front -> l0;"));
+    assert!(dot.contains("back -> l0;"));
+    assert!(!dot.contains("l1 "));
+    assert!(dot.trim_end().ends_with('}'));
+}
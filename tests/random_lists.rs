@@ -0,0 +1,115 @@
+#![cfg(any(feature = "arbitrary", feature = "proptest"))]
+
+use orx_linked_list::*;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_lists {
+    use super::*;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    fn bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn arbitrary_doubly() {
+        for seed in 1..64 {
+            let data = bytes(seed, 1024);
+            let mut u = Unstructured::new(&data);
+            let list = DoublyList::<u16>::arbitrary(&mut u).unwrap();
+            assert_eq!(list.check_integrity(), Ok(()));
+            assert_eq!(list.iter().count(), list.len());
+            assert_eq!(list.iter().rev().count(), list.len());
+        }
+    }
+
+    #[test]
+    fn arbitrary_doubly_lazy() {
+        let mut num_with_gaps = 0;
+        for seed in 1..64 {
+            let data = bytes(seed, 1024);
+            let mut u = Unstructured::new(&data);
+            let list = DoublyListLazy::<u16>::arbitrary(&mut u).unwrap();
+            assert_eq!(list.check_integrity(), Ok(()));
+            if list.node_utilization().num_closed_nodes > 0 {
+                num_with_gaps += 1;
+            }
+        }
+        assert!(num_with_gaps > 0);
+    }
+
+    #[test]
+    fn arbitrary_singly() {
+        let mut num_non_empty = 0;
+        for seed in 1..64 {
+            let data = bytes(seed, 1024);
+            let mut u = Unstructured::new(&data);
+            let list = SinglyList::<u16>::arbitrary(&mut u).unwrap();
+            assert_eq!(list.check_integrity(), Ok(()));
+            assert_eq!(list.iter().count(), list.len());
+            if !list.is_empty() {
+                num_non_empty += 1;
+            }
+        }
+        assert!(num_non_empty > 0);
+    }
+
+    #[test]
+    fn arbitrary_is_deterministic() {
+        let data = bytes(42, 512);
+        let a = DoublyList::<u8>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let b = DoublyList::<u8>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(a, b);
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_lists {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn doubly_list_with_valid_indices((list, idx) in strategies::doubly_list(any::<u8>(), 0..100)) {
+            prop_assert_eq!(list.check_integrity(), Ok(()));
+            prop_assert_eq!(idx.len(), list.len());
+            for (i, x) in idx.iter().zip(list.iter()) {
+                prop_assert!(list.is_valid(*i));
+                prop_assert_eq!(list.idx_err(*i), None);
+                prop_assert_eq!(list.get(*i), Some(x));
+            }
+        }
+
+        #[test]
+        fn doubly_list_lazy_with_valid_indices((list, idx) in strategies::doubly_list_lazy(any::<u8>(), 0..100)) {
+            prop_assert_eq!(list.check_integrity(), Ok(()));
+            prop_assert_eq!(idx.len(), list.len());
+            for (i, x) in idx.iter().zip(list.iter()) {
+                prop_assert_eq!(list.get(*i), Some(x));
+            }
+        }
+
+        #[test]
+        fn singly_list_with_valid_indices((list, idx) in strategies::singly_list(any::<u8>(), 0..100)) {
+            prop_assert_eq!(list.check_integrity(), Ok(()));
+            prop_assert_eq!(idx.len(), list.len());
+            for (i, x) in idx.iter().zip(list.iter()) {
+                prop_assert_eq!(list.get(*i), Some(x));
+            }
+        }
+
+        #[test]
+        fn singly_list_lazy_with_valid_indices((list, idx) in strategies::singly_list_lazy(any::<u8>(), 0..100)) {
+            prop_assert_eq!(list.check_integrity(), Ok(()));
+            prop_assert_eq!(idx.len(), list.len());
+        }
+    }
+}
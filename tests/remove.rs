@@ -74,3 +74,24 @@ fn remove_doubly_other_list<M: MemoryPolicy<Doubly<char>>>(mut other: List<Doubl
     #[cfg(feature = "validation")]
     other.validate();
 }
+
+#[test]
+fn removed_idx_remains_invalid_after_reclaim() {
+    let mut list = DoublyList::new();
+    let a = list.push_back('a');
+    list.pop_front();
+    let b = list.push_back('b');
+
+    assert!(!list.is_valid(a));
+    assert_eq!(list.get(a), None);
+    assert_eq!(list.get(b), Some(&'b'));
+
+    let mut list = SinglyList::new();
+    let a = list.push_front('a');
+    list.pop_front();
+    let b = list.push_front('b');
+
+    assert!(!list.is_valid(a));
+    assert_eq!(list.get(a), None);
+    assert_eq!(list.get(b), Some(&'b'));
+}
@@ -27,7 +27,7 @@ fn remove_at_singly<M: MemoryPolicy<Singly<char>>>(mut list: List<Singly<char>,
 
 #[test]
 fn remove_at_singly_oob() {
-    let mut list = SinglyList::from_iter(['b', 'c', 'd']);
+    let mut list: SinglyList<_> = SinglyList::from_iter(['b', 'c', 'd']);
     assert_eq!(list.remove_at(3), None);
 }
 
@@ -57,7 +57,7 @@ fn remove_at_doubly<M: MemoryPolicy<Doubly<char>>>(mut list: List<Doubly<char>,
 
 #[test]
 fn remove_at_doubly_oob() {
-    let mut list = DoublyList::from_iter(['b', 'c', 'd']);
+    let mut list: DoublyList<_> = DoublyList::from_iter(['b', 'c', 'd']);
     assert_eq!(list.remove_at(3), None);
 }
 
@@ -87,6 +87,6 @@ fn remove_at_from_back_doubly<M: MemoryPolicy<Doubly<char>>>(mut list: List<Doub
 
 #[test]
 fn remove_at_from_back_doubly_oob() {
-    let mut list = DoublyList::from_iter(['b', 'c', 'd']);
+    let mut list: DoublyList<_> = DoublyList::from_iter(['b', 'c', 'd']);
     assert_eq!(list.remove_at_from_back(3), None);
 }
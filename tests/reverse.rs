@@ -1,4 +1,5 @@
 mod doubly;
+mod singly;
 
 use orx_linked_list::*;
 
@@ -98,3 +99,74 @@ fn slice_reverse_middle() {
         }
     }
 }
+
+#[test]
+fn singly_list_reverse() {
+    let mut list = singly::new_singly(&mut doubly::rng(), 100, 300);
+    let mut expected: Vec<_> = list.iter().cloned().collect();
+    let idx: Vec<_> = list.indices().collect();
+    expected.reverse();
+
+    list.reverse();
+
+    #[cfg(feature = "validation")]
+    list.validate();
+    assert!(list.eq_to_iter_refs(&expected));
+    assert!(list.reversed_iter().eq(expected.iter().rev()));
+
+    let n = expected.len();
+    for (i, x) in idx.iter().enumerate() {
+        assert_eq!(list.get(*x), Some(&expected[n - 1 - i]));
+    }
+}
+
+#[test]
+fn singly_slice_reverse() {
+    let n = 12;
+    for i in 0..n {
+        for j in i..n {
+            let mut list: SinglyList<_> = (0..n).collect();
+            let idx: Vec<_> = list.indices().collect();
+
+            let mut expected: Vec<_> = (0..n).collect();
+            expected[i..=j].reverse();
+
+            let mut slice = list.slice_mut(idx[i]..=idx[j]);
+            slice.reverse();
+            assert!(slice.eq_to_iter_refs(&expected[i..=j]));
+
+            #[cfg(feature = "validation")]
+            list.validate();
+            assert!(list.eq_to_iter_refs(&expected));
+
+            let mut list: SinglyList<_> = (0..n).collect();
+            let idx: Vec<_> = list.indices().collect();
+
+            let mut expected: Vec<_> = (0..n).collect();
+            expected[i..].reverse();
+
+            let mut slice = list.slice_mut(idx[i]..);
+            slice.reverse();
+            assert!(slice.eq_to_iter_refs(&expected[i..]));
+
+            #[cfg(feature = "validation")]
+            list.validate();
+            assert!(list.eq_to_iter_refs(&expected));
+        }
+    }
+}
+
+#[test]
+fn singly_slice_reversed_iter() {
+    let n = 12;
+    let list: SinglyList<_> = (0..n).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    for i in 0..n {
+        for j in i..n {
+            let slice = list.slice(idx[i]..idx[j]);
+            assert!(slice.reversed_iter().copied().eq((i..j).rev()));
+            assert_eq!(slice.reversed_iter().len(), j - i);
+        }
+    }
+}
@@ -7,6 +7,7 @@ fn assert_empty_slice<'a, T: Debug + Eq>(slice: &DoublyListSlice<'a, T>) {
     assert_eq!(slice.iter_ptr().count(), 0);
     assert_eq!(slice.front(), None);
     assert_eq!(slice.back(), None);
+    assert!(slice.is_empty());
 }
 
 #[test]
@@ -59,6 +60,10 @@ fn full_slice() {
     assert!(list.eq_to_iter_refs(list.slice(..).iter()));
     assert!(list.eq_to_iter_refs(list.slice(&a..).iter()));
     assert!(list.eq_to_iter_refs(list.slice(..=&c).iter()));
+
+    assert!(!list.slice(..).is_empty());
+    assert!(!list.slice(&a..).is_empty());
+    assert!(!list.slice(..=&c).is_empty());
 }
 
 #[test]
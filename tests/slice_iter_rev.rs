@@ -11,7 +11,7 @@ fn iter_rev_on_slice() {
 
     let slice = list.slice(idx[3]..idx[2]);
     let rev: Vec<_> = slice.iter().copied().rev().collect();
-    assert_eq!(rev, [1, 0]);
+    assert_eq!(rev, [9, 8, 7, 6, 5, 4, 3]);
 
     let slice = list.slice(idx[0]..=idx[9]);
     let rev: Vec<_> = slice.iter().copied().rev().collect();
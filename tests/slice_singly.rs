@@ -0,0 +1,176 @@
+mod singly;
+use orx_linked_list::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::fmt::Debug;
+
+fn assert_empty_slice<'a, T: Debug + Eq>(slice: &SinglyListSlice<'a, T>) {
+    assert_eq!(slice.iter().count(), 0);
+    assert_eq!(slice.iter_ptr().count(), 0);
+    assert_eq!(slice.front(), None);
+}
+
+#[test]
+fn empty_from_empty() {
+    let list = SinglyList::<usize>::new();
+    assert_empty_slice(&list.slice(..));
+}
+
+#[test]
+fn empty_from_nonempty() {
+    let mut list = SinglyList::new();
+    let c = list.push_front('c');
+    let b = list.push_front('b');
+    let a = list.push_front('a');
+    assert!(list.eq_to_iter_vals(['a', 'b', 'c']));
+
+    let indices = [a, b, c];
+
+    for x in &indices {
+        assert_empty_slice(&list.slice(x..x));
+    }
+}
+
+#[test]
+fn singleton_slice() {
+    let mut list = SinglyList::new();
+    let c = list.push_front('c');
+    let b = list.push_front('b');
+    let a = list.push_front('a');
+
+    let expected = ['a', 'b', 'c'];
+    let indices = [a, b, c];
+
+    for (i, x) in indices.iter().enumerate() {
+        let slice = list.slice(x..=x);
+        assert!(slice.eq_to_iter_vals([expected[i]]));
+        assert_eq!(slice.front(), Some(&expected[i]));
+        assert_eq!(slice.get(*x), Some(&expected[i]));
+    }
+}
+
+#[test]
+fn full_slice() {
+    let mut list = SinglyList::new();
+    let c = list.push_front('c');
+    let _ = list.push_front('b');
+    let a = list.push_front('a');
+    assert!(list.eq_to_iter_vals(['a', 'b', 'c']));
+
+    assert!(list.eq_to_iter_refs(list.slice(..).iter()));
+    assert!(list.eq_to_iter_refs(list.slice(&a..).iter()));
+    assert!(list.eq_to_iter_refs(list.slice(..=&c).iter()));
+}
+
+#[test]
+fn wrong_direction() {
+    let list: SinglyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    assert!(list.slice(idx[6]..idx[2]).eq_to_iter_vals(6..10));
+    assert!(list.slice(idx[6]..=idx[2]).eq_to_iter_vals(6..10));
+    assert!(list.slice(..idx[0]).eq_to_iter_vals([]));
+}
+
+#[test]
+fn slice_eq() {
+    let a: SinglyList<_> = (0..10).collect();
+    let b: SinglyList<_> = (5..15).collect();
+    let idx_a: Vec<_> = a.indices().collect();
+    let idx_b: Vec<_> = b.indices().collect();
+
+    assert!(a.slice(idx_a[5]..) == b.slice(..idx_b[5]));
+    assert!(a.slice(idx_a[7]..idx_a[9]) == b.slice(idx_b[2]..=idx_b[3]));
+    assert!(a.slice(idx_a[7]..) != b.slice(idx_b[2]..));
+}
+
+#[test]
+fn singly_slice() {
+    let mut rng = ChaCha8Rng::seed_from_u64(56456);
+    let list = singly::new_singly(&mut rng, 50, 200);
+
+    let n = list.len();
+    let vec: Vec<_> = list.iter().cloned().collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    for id in idx.iter() {
+        assert_empty_slice(&list.slice(id..id));
+    }
+
+    assert!(list.slice(..).eq_to_iter_refs(&vec[..]));
+    assert!(list.slice(..=idx[n - 1]).eq_to_iter_refs(&vec[..]));
+    assert!(list.slice(..idx[n - 1]).eq_to_iter_refs(&vec[..(n - 1)]));
+
+    for i in 0..n {
+        assert!(list.slice(idx[i]..).eq_to_iter_refs(&vec[i..]));
+
+        for j in i..n {
+            let s = list.slice(idx[i]..=idx[j]);
+            assert!(s.eq_to_iter_refs(&vec[i..=j]));
+            assert_eq!(s.front(), Some(&vec[i]));
+
+            let s = list.slice(idx[i]..idx[j]);
+            assert!(s.eq_to_iter_refs(&vec[i..j]));
+        }
+    }
+}
+
+#[test]
+fn singly_slice_mut() {
+    let mut rng = ChaCha8Rng::seed_from_u64(6786);
+    let list = singly::new_singly(&mut rng, 30, 100);
+
+    let n = list.len();
+
+    for i in 0..n {
+        for j in i..n {
+            let mut list = list.clone();
+            let idx: Vec<_> = list.indices().collect();
+            let mut vec: Vec<_> = list.iter().cloned().collect();
+
+            let mut slice = list.slice_mut(idx[i]..idx[j]);
+            for x in slice.iter_mut() {
+                x.push('!');
+            }
+            if let Some(x) = slice.front_mut() {
+                x.push('?');
+            }
+
+            for x in vec[i..j].iter_mut() {
+                x.push('!');
+            }
+            if i < j {
+                vec[i].push('?');
+            }
+
+            assert!(list.eq_to_iter_refs(&vec));
+        }
+    }
+}
+
+#[test]
+fn chunks_mut_of_slice() {
+    let mut list: SinglyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let mut slice = list.slice_mut(idx[2]..=idx[8]);
+    let mut chunks = slice.chunks_mut(3);
+
+    let mut num_chunks = 0;
+    while let Some(mut chunk) = chunks.next_chunk() {
+        num_chunks += 1;
+        *chunk.front_mut().unwrap() = 100;
+    }
+    assert_eq!(num_chunks, 3);
+
+    assert!(list.eq_to_iter_vals([0, 1, 100, 3, 4, 100, 6, 7, 100, 9]));
+}
+
+#[test]
+#[should_panic]
+fn slice_with_invalid_idx() {
+    let mut list: SinglyList<_> = (0..10).collect();
+    let idx = list.indices().nth(3).unwrap();
+    list.clear();
+    let _ = list.slice(idx..);
+}
@@ -21,3 +21,37 @@ fn slice_in_wrong_direction() {
     let slice = list.slice(idx[4]..idx[4]);
     assert!(slice.eq_to_iter_vals([]));
 }
+
+#[test]
+fn reverse_slice_in_wrong_direction_doubly() {
+    let mut list: DoublyList<_> = (0..6).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let slice = list.slice(idx[4]..=idx[1]);
+    assert_eq!(slice.back(), Some(&5));
+
+    list.slice_mut(idx[4]..=idx[1]).reverse();
+    assert_eq!(list.check_integrity(), Ok(()));
+    assert!(list.eq_to_iter_vals([0, 1, 2, 3, 5, 4]));
+
+    list.slice_mut(idx[2]..idx[0]).reverse();
+    assert_eq!(list.check_integrity(), Ok(()));
+    assert!(list.eq_to_iter_vals([0, 1, 4, 5, 3, 2]));
+}
+
+#[test]
+fn reverse_slice_in_wrong_direction_singly() {
+    let mut list: SinglyList<_> = (0..6).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let slice = list.slice(idx[4]..=idx[1]);
+    assert!(slice.eq_to_iter_vals([4, 5]));
+
+    list.slice_mut(idx[4]..=idx[1]).reverse();
+    assert_eq!(list.check_integrity(), Ok(()));
+    assert!(list.eq_to_iter_vals([0, 1, 2, 3, 5, 4]));
+
+    list.slice_mut(idx[2]..idx[0]).reverse();
+    assert_eq!(list.check_integrity(), Ok(()));
+    assert!(list.eq_to_iter_vals([0, 1, 4, 5, 3, 2]));
+}
@@ -0,0 +1,224 @@
+use orx_linked_list::*;
+use test_case::test_matrix;
+
+#[test_matrix([0, 1, 2, 5, 10], [1, 2, 4, 8])]
+fn sub_slice_doubly(begin: usize, len: usize) {
+    let n = 12;
+    let list: DoublyList<_> = (0..n).collect();
+    let idx: Vec<_> = list.indices().collect();
+    let vec: Vec<_> = (0..n).collect();
+
+    let end = (begin + len).min(n);
+    let slice = list.slice(idx[begin]..idx[end - 1]);
+    let parent = &vec[begin..(end - 1)];
+
+    for i in 0..parent.len() {
+        let a = idx[begin + i];
+        assert!(slice.slice(a..).eq_to_iter_refs(&parent[i..]));
+        assert!(slice.slice(..=a).eq_to_iter_refs(&parent[..=i]));
+        assert!(slice.slice(..a).eq_to_iter_refs(&parent[..i]));
+
+        for j in i..parent.len() {
+            let b = idx[begin + j];
+            assert!(slice.slice(a..=b).eq_to_iter_refs(&parent[i..=j]));
+            assert!(slice.slice(a..b).eq_to_iter_refs(&parent[i..j]));
+            assert_eq!(slice.slice(a..b).is_empty(), i == j);
+        }
+    }
+
+    for (i, x) in idx.iter().enumerate() {
+        let inside = i >= begin && i < end - 1;
+        assert_eq!(slice.try_slice(x..).is_ok(), inside);
+        assert_eq!(slice.try_slice(..x).is_ok(), inside);
+        if !inside {
+            assert_eq!(slice.try_slice(x..).err(), Some(SliceError::OutOfSlice));
+        }
+    }
+}
+
+#[test_matrix([0, 1, 2, 5, 10], [1, 2, 4, 8])]
+fn sub_slice_singly(begin: usize, len: usize) {
+    let n = 12;
+    let list: SinglyList<_> = (0..n).collect();
+    let idx: Vec<_> = list.indices().collect();
+    let vec: Vec<_> = (0..n).collect();
+
+    let end = (begin + len).min(n);
+    let slice = list.slice(idx[begin]..idx[end - 1]);
+    let parent = &vec[begin..(end - 1)];
+
+    for i in 0..parent.len() {
+        let a = idx[begin + i];
+        assert!(slice.slice(a..).eq_to_iter_refs(&parent[i..]));
+        assert!(slice.slice(..=a).eq_to_iter_refs(&parent[..=i]));
+        assert!(slice.slice(..a).eq_to_iter_refs(&parent[..i]));
+
+        for j in i..parent.len() {
+            let b = idx[begin + j];
+            assert!(slice.slice(a..=b).eq_to_iter_refs(&parent[i..=j]));
+            assert!(slice.slice(a..b).eq_to_iter_refs(&parent[i..j]));
+            assert_eq!(slice.slice(a..b).is_empty(), i == j);
+        }
+    }
+
+    for (i, x) in idx.iter().enumerate() {
+        let inside = i >= begin && i < end - 1;
+        assert_eq!(slice.try_slice(x..).is_ok(), inside);
+        assert_eq!(slice.try_slice(..x).is_ok(), inside);
+        if !inside {
+            assert_eq!(slice.try_slice(x..).err(), Some(SliceError::OutOfSlice));
+        }
+    }
+}
+
+#[test]
+fn sub_slice_wrong_direction() {
+    let list: DoublyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    let slice = list.slice(idx[2]..idx[8]);
+    assert!(slice.slice(idx[6]..idx[3]).eq_to_iter_vals([6, 7]));
+    assert!(slice.slice(idx[6]..=idx[5]).eq_to_iter_vals([6, 7]));
+
+    let list: SinglyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    let slice = list.slice(idx[2]..idx[8]);
+    assert!(slice.slice(idx[6]..idx[3]).eq_to_iter_vals([6, 7]));
+    assert!(slice.slice(idx[6]..=idx[5]).eq_to_iter_vals([6, 7]));
+}
+
+#[test]
+fn sub_slice_mut() {
+    let mut list: DoublyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let mut slice = list.slice_mut(idx[2]..idx[8]);
+    assert!(slice.try_slice_mut(idx[1]..).is_err());
+    assert!(slice.try_slice_mut(..idx[8]).is_err());
+
+    let mut sub_slice = slice.slice_mut(idx[3]..=idx[6]);
+    sub_slice.reverse();
+    assert!(list.eq_to_iter_vals([0, 1, 2, 6, 5, 4, 3, 7, 8, 9]));
+
+    let mut list: SinglyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+
+    let mut slice = list.slice_mut(idx[2]..idx[8]);
+    assert!(slice.try_slice_mut(idx[1]..).is_err());
+    assert!(slice.try_slice_mut(..idx[8]).is_err());
+
+    let mut sub_slice = slice.slice_mut(idx[3]..=idx[6]);
+    for x in sub_slice.iter_mut() {
+        *x += 10;
+    }
+    assert!(list.eq_to_iter_vals([0, 1, 2, 13, 14, 15, 16, 7, 8, 9]));
+}
+
+#[test_matrix([0, 1, 2, 7], [0, 1, 3, 7])]
+fn slice_at(begin: usize, len: usize) {
+    let n = 10;
+    let vec: Vec<_> = (0..n).collect();
+    let end = (begin + len).min(n);
+
+    let mut doubly: DoublyList<_> = (0..n).collect();
+    let mut singly: SinglyList<_> = (0..n).collect();
+
+    assert!(
+        doubly
+            .slice_at(begin..end)
+            .eq_to_iter_refs(&vec[begin..end])
+    );
+    assert!(
+        singly
+            .slice_at(begin..end)
+            .eq_to_iter_refs(&vec[begin..end])
+    );
+    assert!(doubly.slice_at(begin..).eq_to_iter_refs(&vec[begin..]));
+    assert!(singly.slice_at(..end).eq_to_iter_refs(&vec[..end]));
+
+    let slice = doubly.slice_at(begin..end);
+    for i in 0..=slice.iter().count() {
+        assert!(slice.slice_at(i..).eq_to_iter_refs(&vec[(begin + i)..end]));
+        assert!(
+            slice
+                .slice_at(..i)
+                .eq_to_iter_refs(&vec[begin..(begin + i)])
+        );
+    }
+
+    let slice = singly.slice_at(begin..end);
+    for i in 0..=slice.iter().count() {
+        assert!(slice.slice_at(i..).eq_to_iter_refs(&vec[(begin + i)..end]));
+        assert!(
+            slice
+                .slice_at(..i)
+                .eq_to_iter_refs(&vec[begin..(begin + i)])
+        );
+    }
+
+    let mut expected = vec.clone();
+    expected[begin..end].iter_mut().for_each(|x| *x += 100);
+
+    doubly
+        .slice_mut_at(begin..end)
+        .iter_mut()
+        .for_each(|x| *x += 100);
+    assert!(doubly.eq_to_iter_refs(&expected));
+
+    singly
+        .slice_mut_at(begin..end)
+        .iter_mut()
+        .for_each(|x| *x += 100);
+    assert!(singly.eq_to_iter_refs(&expected));
+}
+
+#[test]
+#[should_panic]
+fn slice_at_out_of_bounds() {
+    let list: DoublyList<_> = (0..10).collect();
+    let _ = list.slice_at(3..11);
+}
+
+#[test]
+#[should_panic]
+fn slice_at_starts_after_end() {
+    let list: SinglyList<_> = (0..10).collect();
+    let (begin, end) = (5, 3);
+    let _ = list.slice_at(begin..end);
+}
+
+#[test]
+#[should_panic]
+fn sub_slice_out_of_parent() {
+    let list: DoublyList<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    let slice = list.slice(idx[2]..idx[8]);
+    let _ = slice.slice(idx[2]..=idx[8]);
+}
+
+#[test]
+fn sub_slice_invalid_idx() {
+    let mut list: DoublyListLazy<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    list.remove(idx[4]);
+
+    let slice = list.slice(idx[2]..idx[8]);
+    let removed = Some(SliceError::InvalidIdx(NodeIdxError::RemovedNode));
+    assert_eq!(slice.try_slice(idx[4]..).err(), removed);
+    assert_eq!(slice.try_slice(..=idx[4]).err(), removed);
+    assert_eq!(
+        slice.try_slice(idx[1]..).err(),
+        Some(SliceError::OutOfSlice)
+    );
+
+    let mut list: SinglyListLazy<_> = (0..10).collect();
+    let idx: Vec<_> = list.indices().collect();
+    list.remove_at(4);
+
+    let mut slice = list.slice_mut(idx[2]..idx[8]);
+    assert_eq!(slice.try_slice_mut(idx[4]..).err(), removed);
+    assert_eq!(slice.try_slice_mut(..=idx[4]).err(), removed);
+    assert_eq!(
+        slice.try_slice_mut(idx[1]..).err(),
+        Some(SliceError::OutOfSlice)
+    );
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "testing")]
+
+use orx_linked_list::testing::*;
+use orx_linked_list::*;
+
+fn check<M, P>(new_list: impl Fn() -> List<Doubly<u32>, M, P>)
+where
+    M: MemoryPolicy<Doubly<u32>>,
+    P: CheckedStorage<u32>,
+{
+    for seed in 0..SEEDS {
+        let result = check_random(new_list(), seed, 500, |x| (x % 1000) as u32);
+        assert_eq!(result.err(), None, "seed = {seed}");
+    }
+}
+
+const SEEDS: u64 = 16;
+
+#[test]
+fn differential_doubly() {
+    check(DoublyList::new);
+    check(|| DoublyList::with_capacity(16));
+    check(|| DoublyList::<_>::from_iter(0..20));
+}
+
+#[test]
+fn differential_doubly_lazy() {
+    check(DoublyListLazy::new);
+    check(|| DoublyListLazy::<_>::from_iter(0..20));
+}
+
+#[test]
+fn differential_doubly_threshold() {
+    check(DoublyList::with_threshold_reclaimer::<1>);
+    check(DoublyList::with_threshold_reclaimer::<3>);
+}
+
+#[test]
+fn differential_doubly_incremental() {
+    check(DoublyList::with_incremental_reclaimer::<1>);
+    check(DoublyList::with_incremental_reclaimer::<4>);
+}
+
+#[test]
+fn differential_doubly_other_storages() {
+    check(DoublyList::with_doubling_growth);
+    check(|| DoublyList::with_linear_growth(4));
+    check(|| DoublyList::with_fixed_capacity(1000));
+    check(DoublyListLazy::with_doubling_growth);
+    check(|| DoublyListLazy::with_fixed_capacity(1000));
+}
@@ -0,0 +1,140 @@
+use orx_linked_list::*;
+use test_case::test_matrix;
+
+fn list_and_indices(n: usize) -> (DoublyList<usize>, Vec<DoublyIdx<usize>>) {
+    let list: DoublyList<_> = (0..n).collect();
+    let indices: Vec<_> = list.indices().collect();
+    (list, indices)
+}
+
+fn values(list: &DoublyList<usize>) -> Vec<usize> {
+    list.iter().copied().collect()
+}
+
+#[test_matrix([0, 1, 4, 7], [0, 3, 6, 7])]
+fn try_move_on_list_matches_move(i: usize, j: usize) {
+    let n = 8;
+
+    type Move = fn(&mut DoublyList<usize>, DoublyIdx<usize>, DoublyIdx<usize>);
+    type TryMove =
+        fn(&mut DoublyList<usize>, DoublyIdx<usize>, DoublyIdx<usize>) -> Result<(), MoveError>;
+    let moves: [(Move, TryMove); 3] = [
+        (
+            |l, a, b| l.move_next_to(a, b),
+            |l, a, b| l.try_move_next_to(a, b),
+        ),
+        (
+            |l, a, b| l.move_prev_to(a, b),
+            |l, a, b| l.try_move_prev_to(a, b),
+        ),
+        (|l, a, b| l.swap(a, b), |l, a, b| l.try_swap(a, b)),
+    ];
+
+    for (mv, try_mv) in moves {
+        let (mut expected, idx) = list_and_indices(n);
+        mv(&mut expected, idx[i], idx[j]);
+
+        let (mut list, idx) = list_and_indices(n);
+        let result = try_mv(&mut list, idx[i], idx[j]);
+
+        #[cfg(feature = "validation")]
+        list.validate();
+
+        match i == j {
+            true => assert_eq!(result, Err(MoveError::IdenticalNodes)),
+            false => assert_eq!(result, Ok(())),
+        }
+        assert_eq!(values(&list), values(&expected));
+    }
+
+    let (mut expected, idx) = list_and_indices(n);
+    expected.move_to_front(idx[i]);
+    let (mut list, idx) = list_and_indices(n);
+    assert_eq!(list.try_move_to_front(idx[i]), Ok(()));
+    assert_eq!(values(&list), values(&expected));
+
+    let (mut expected, idx) = list_and_indices(n);
+    expected.move_to_back(idx[i]);
+    let (mut list, idx) = list_and_indices(n);
+    assert_eq!(list.try_move_to_back(idx[i]), Ok(()));
+    assert_eq!(values(&list), values(&expected));
+}
+
+#[test]
+fn try_move_invalid_idx() {
+    let (mut list, idx) = list_and_indices(8);
+    let (other, other_idx) = list_and_indices(8);
+    let removed = list.remove(idx[3]);
+    assert_eq!(removed, 3);
+    let vec = values(&list);
+
+    let removed_err = Err(MoveError::InvalidIdx(NodeIdxError::RemovedNode));
+    let oob_err = Err(MoveError::InvalidIdx(NodeIdxError::OutOfBounds));
+
+    assert_eq!(list.try_move_next_to(idx[3], idx[0]), removed_err);
+    assert_eq!(list.try_move_next_to(idx[0], idx[3]), removed_err);
+    assert_eq!(list.try_move_prev_to(idx[3], idx[0]), removed_err);
+    assert_eq!(list.try_move_prev_to(idx[0], idx[3]), removed_err);
+    assert_eq!(list.try_move_to_front(idx[3]), removed_err);
+    assert_eq!(list.try_move_to_back(idx[3]), removed_err);
+    assert_eq!(list.try_swap(idx[3], idx[0]), removed_err);
+    assert_eq!(list.try_swap(idx[0], idx[3]), removed_err);
+
+    assert_eq!(list.try_move_next_to(other_idx[1], idx[0]), oob_err);
+    assert_eq!(list.try_move_prev_to(idx[0], other_idx[1]), oob_err);
+    assert_eq!(list.try_move_to_front(other_idx[1]), oob_err);
+    assert_eq!(list.try_swap(idx[0], other_idx[1]), oob_err);
+
+    assert_eq!(values(&list), vec);
+    assert_eq!(other.len(), 8);
+
+    list.clear();
+    assert!(list.try_move_to_front(idx[0]).is_err());
+    assert!(list.try_move_to_back(idx[0]).is_err());
+}
+
+#[test]
+fn try_move_out_of_slice() {
+    let n = 10;
+    let (a, b) = (2, 6);
+    let (mut list, idx) = list_and_indices(n);
+
+    let mut slice = list.slice_mut(idx[a]..=idx[b]);
+    for i in 0..n {
+        for j in 0..n {
+            let inside = |x: usize| x >= a && x <= b;
+            let expected = match (inside(i), inside(j)) {
+                (true, true) => continue,
+                _ => Err(MoveError::OutOfSlice),
+            };
+            assert_eq!(slice.try_move_next_to(idx[i], idx[j]), expected);
+            assert_eq!(slice.try_move_prev_to(idx[i], idx[j]), expected);
+            assert_eq!(slice.try_swap(idx[i], idx[j]), expected);
+        }
+
+        if !(a..=b).contains(&i) {
+            assert_eq!(slice.try_move_to_front(idx[i]), Err(MoveError::OutOfSlice));
+            assert_eq!(slice.try_move_to_back(idx[i]), Err(MoveError::OutOfSlice));
+        }
+    }
+
+    assert!(list.eq_to_iter_vals(0..n));
+}
+
+#[test]
+fn try_move_within_slice() {
+    let n = 10;
+    let (mut list, idx) = list_and_indices(n);
+
+    let mut slice = list.slice_mut(idx[2]..=idx[6]);
+    assert_eq!(slice.try_move_to_front(idx[5]), Ok(()));
+    assert_eq!(slice.try_move_to_back(idx[2]), Ok(()));
+    assert_eq!(slice.try_swap(idx[3], idx[6]), Ok(()));
+    assert_eq!(slice.try_move_next_to(idx[4], idx[5]), Ok(()));
+    assert!(slice.eq_to_iter_vals([5, 4, 6, 3, 2]));
+
+    #[cfg(feature = "validation")]
+    list.validate();
+
+    assert!(list.eq_to_iter_vals([0, 1, 5, 4, 6, 3, 2, 7, 8, 9]));
+}